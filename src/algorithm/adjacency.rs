@@ -0,0 +1,85 @@
+use crate::graph::{Edge, Graph, Node};
+use std::collections::HashMap;
+
+/// Index-based snapshot of a graph's structure.
+///
+/// Nodes are indexed `0..len` by increasing ID so that algorithms can use
+/// vectors instead of maps and produce deterministic results. Edges leading
+/// to or leaving unknown nodes are dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct Adjacency<W> {
+    /// Node IDs, sorted
+    pub ids: Vec<u32>,
+    pub index: HashMap<u32, usize>,
+    /// Outgoing connections of each node as `(target index, weight)`
+    pub successors: Vec<Vec<(usize, W)>>,
+}
+
+impl<W: Copy> Adjacency<W> {
+    /// Builds the adjacency of a graph.
+    pub fn from_graph<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Self
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = W>,
+    {
+        let mut ids: Vec<u32> = graph.nodes.keys().copied().collect();
+        ids.sort_unstable();
+        let index: HashMap<u32, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut successors = vec![Vec::new(); ids.len()];
+        for (i, id) in ids.iter().enumerate() {
+            for edge in graph.edges.get(id).into_iter().flatten() {
+                if let Some(&j) = index.get(&edge.to()) {
+                    successors[i].push((j, edge.weight()));
+                }
+            }
+        }
+
+        Adjacency {
+            ids,
+            index,
+            successors,
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns the adjacency with every connection reversed.
+    pub fn reversed(&self) -> Self {
+        let mut successors = vec![Vec::new(); self.len()];
+        for (i, targets) in self.successors.iter().enumerate() {
+            for &(j, weight) in targets {
+                successors[j].push((i, weight));
+            }
+        }
+
+        Adjacency {
+            ids: self.ids.clone(),
+            index: self.index.clone(),
+            successors,
+        }
+    }
+
+    /// Returns the neighbors of each node, ignoring edge directions.
+    ///
+    /// Lists are sorted and deduplicated, and self loops are dropped.
+    pub fn undirected_neighbors(&self) -> Vec<Vec<usize>> {
+        let mut neighbors = vec![Vec::new(); self.len()];
+        for (i, targets) in self.successors.iter().enumerate() {
+            for &(j, _) in targets {
+                if i != j {
+                    neighbors[i].push(j);
+                    neighbors[j].push(i);
+                }
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+            list.dedup();
+        }
+        neighbors
+    }
+}
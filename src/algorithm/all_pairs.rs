@@ -0,0 +1,454 @@
+use crate::frontier::{MinHeap, Queue};
+use crate::graph::{Cost, Edge, Graph, Node};
+use crate::preset::policies::traversal::NoTermination;
+use crate::preset::visitors::{
+    BellmanFordVisitor, NegativeCycle, TrackCost, TrackParent, WeightedVisitor,
+};
+use crate::preset::{EmptyNode, WeightedEdge};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Sub;
+
+/// Shortest distances between every pair of nodes of a graph.
+///
+/// Also stores the predecessor of each node on every shortest path, so any
+/// path can be reconstructed without running a new search.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::floyd_warshall;
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, WeightedEdge};
+///
+/// let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+/// for id in 0..3 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// graph.add_edge(WeightedEdge::new(0, 1, Some(1.0)));
+/// graph.add_edge(WeightedEdge::new(1, 2, Some(1.0)));
+/// graph.add_edge(WeightedEdge::new(0, 2, Some(5.0)));
+///
+/// let matrix = floyd_warshall(&graph).unwrap();
+/// assert_eq!(matrix.distance(0, 2), Some(2.0));
+/// assert_eq!(matrix.path(0, 2), Some(vec![0, 1, 2]));
+/// assert_eq!(matrix.distance(2, 0), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<C = f64> {
+    /// Node IDs, sorted, giving the row and column order
+    ids: Vec<u32>,
+    index: HashMap<u32, usize>,
+    distances: Vec<Option<C>>,
+    /// Index of the node preceding the column node on the path from the row node
+    predecessors: Vec<Option<usize>>,
+}
+
+impl<C: Cost> DistanceMatrix<C> {
+    /// Creates a matrix where every node only reaches itself.
+    fn new(mut ids: Vec<u32>) -> Self {
+        ids.sort_unstable();
+        let n = ids.len();
+        let index = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut distances = vec![None; n * n];
+        for i in 0..n {
+            distances[i * n + i] = Some(C::zero());
+        }
+
+        DistanceMatrix {
+            ids,
+            index,
+            distances,
+            predecessors: vec![None; n * n],
+        }
+    }
+
+    /// Returns the IDs of the nodes covered by the matrix, sorted.
+    pub fn nodes(&self) -> &[u32] {
+        &self.ids
+    }
+
+    /// Returns the shortest distance between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    ///
+    /// # Returns
+    ///
+    /// `None` if the target is unreachable or either node is unknown
+    pub fn distance(&self, from: u32, to: u32) -> Option<C> {
+        let (i, j) = (*self.index.get(&from)?, *self.index.get(&to)?);
+        self.distances[self.cell(i, j)]
+    }
+
+    /// Reconstructs the shortest path between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    ///
+    /// # Returns
+    ///
+    /// The node IDs from source to target, or `None` if the target is unreachable
+    pub fn path(&self, from: u32, to: u32) -> Option<Vec<u32>> {
+        let (i, mut j) = (*self.index.get(&from)?, *self.index.get(&to)?);
+        self.distances[self.cell(i, j)]?;
+
+        let mut path = vec![self.ids[j]];
+        while j != i {
+            j = self.predecessors[self.cell(i, j)]?;
+            path.push(self.ids[j]);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    fn cell(&self, i: usize, j: usize) -> usize {
+        i * self.ids.len() + j
+    }
+
+    /// Records a distance if it improves the current one.
+    fn relax(&mut self, i: usize, j: usize, distance: C, predecessor: Option<usize>) {
+        let cell = self.cell(i, j);
+        let improves =
+            self.distances[cell].is_none_or(|current| distance.compare(&current) == Ordering::Less);
+
+        if improves {
+            self.distances[cell] = Some(distance);
+            self.predecessors[cell] = predecessor;
+        }
+    }
+
+    /// Extracts a cycle from the predecessor chain of a row.
+    fn cycle_from(&self, i: usize) -> NegativeCycle {
+        let mut sequence = vec![i];
+        let mut current = i;
+
+        while let Some(previous) = self.predecessors[self.cell(i, current)] {
+            if let Some(position) = sequence.iter().position(|&node| node == previous) {
+                let mut cycle: Vec<u32> = sequence[position..]
+                    .iter()
+                    .map(|&node| self.ids[node])
+                    .collect();
+                cycle.reverse();
+                return NegativeCycle { cycle };
+            }
+            sequence.push(previous);
+            current = previous;
+        }
+
+        NegativeCycle {
+            cycle: vec![self.ids[i]],
+        }
+    }
+}
+
+/// Returns the cheapest edge weight between each connected pair of nodes.
+///
+/// Edges leading to or leaving unknown nodes are ignored.
+pub(crate) fn cheapest_edges<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> BTreeMap<(u32, u32), TEdge::Weight>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let mut weights: BTreeMap<(u32, u32), TEdge::Weight> = BTreeMap::new();
+
+    for edge in graph.edges.values().flatten() {
+        if !graph.nodes.contains_key(&edge.from()) || !graph.nodes.contains_key(&edge.to()) {
+            continue;
+        }
+        let weight = edge.weight();
+        weights
+            .entry((edge.from(), edge.to()))
+            .and_modify(|current| {
+                if weight.compare(current) == Ordering::Less {
+                    *current = weight;
+                }
+            })
+            .or_insert(weight);
+    }
+
+    weights
+}
+
+/// Computes all-pairs shortest paths with the Floyd–Warshall algorithm.
+///
+/// Runs in `O(n³)` time and `O(n²)` memory regardless of the number of edges,
+/// which suits dense graphs. Negative weights are supported.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+///
+/// # Errors
+///
+/// Returns a `NegativeCycle` if the graph contains a cycle of negative weight.
+pub fn floyd_warshall<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> Result<DistanceMatrix<TEdge::Weight>, NegativeCycle>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let mut matrix = DistanceMatrix::new(graph.nodes.keys().copied().collect());
+    let n = matrix.ids.len();
+
+    for ((from, to), weight) in cheapest_edges(graph) {
+        let (i, j) = (matrix.index[&from], matrix.index[&to]);
+        matrix.relax(i, j, weight, Some(i));
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(through) = matrix.distances[matrix.cell(i, k)] else {
+                continue;
+            };
+            for j in 0..n {
+                if let Some(remaining) = matrix.distances[matrix.cell(k, j)] {
+                    let predecessor = matrix.predecessors[matrix.cell(k, j)];
+                    matrix.relax(i, j, through.plus(remaining), predecessor);
+                }
+            }
+        }
+    }
+
+    let zero = TEdge::Weight::zero();
+    for i in 0..n {
+        if matrix.distances[matrix.cell(i, i)].is_some_and(|d| d.compare(&zero) == Ordering::Less) {
+            return Err(matrix.cycle_from(i));
+        }
+    }
+
+    Ok(matrix)
+}
+
+/// Computes all-pairs shortest paths with Johnson's algorithm.
+///
+/// Bellman-Ford (`BellmanFordVisitor` over a `Queue`) first computes a
+/// potential for each node from a virtual source linked to every node. Edges
+/// are reweighted with these potentials so that none is negative, then
+/// Dijkstra (`WeightedVisitor` over a `MinHeap`) runs from every node.
+///
+/// Runs in `O(n·m·log n)` time, which outperforms Floyd–Warshall on sparse
+/// graphs. Distances are summed from the original weights along each path,
+/// so reweighting introduces no rounding error.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+///
+/// # Errors
+///
+/// Returns a `NegativeCycle` if the graph contains a cycle of negative weight.
+pub fn johnson<TNode, TEdge, C>(
+    graph: &Graph<TNode, TEdge>,
+) -> Result<DistanceMatrix<C>, NegativeCycle>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = C>,
+    C: Cost + Sub<Output = C>,
+{
+    let mut matrix = DistanceMatrix::new(graph.nodes.keys().copied().collect());
+    let weights = cheapest_edges(graph);
+
+    // Potentials are shortest distances from a virtual source linked to every node
+    let source = (0..=u32::MAX)
+        .find(|id| !graph.nodes.contains_key(id))
+        .expect("Graph must leave at least one free node ID");
+
+    let mut extended = Graph::<EmptyNode, WeightedEdge<C>>::new();
+    extended.add_node(EmptyNode::new(source, None));
+    for &id in &matrix.ids {
+        extended.add_node(EmptyNode::new(id, None));
+        extended.add_edge(WeightedEdge::new(source, id, Some(C::zero())));
+    }
+    for (&(from, to), &weight) in &weights {
+        extended.add_edge(WeightedEdge::new(from, to, Some(weight)));
+    }
+
+    let mut bellman_ford = BellmanFordVisitor::new(NoTermination);
+    extended.traverse(source, &mut Queue::default(), &mut bellman_ford);
+    bellman_ford.result()?;
+
+    let potential = |id: u32| bellman_ford.cost_to(id).unwrap_or(C::zero());
+
+    let mut reweighted = Graph::<EmptyNode, WeightedEdge<C>>::new();
+    for &id in &matrix.ids {
+        reweighted.add_node(EmptyNode::new(id, None));
+    }
+    // Self loops never shorten a path, and one on the start would be taken as its distance
+    for (&(from, to), &weight) in weights.iter().filter(|((from, to), _)| from != to) {
+        let mut adjusted = weight.plus(potential(from)) - potential(to);
+        // Guards against rounding slightly below zero with floating point costs
+        if adjusted.compare(&C::zero()) == Ordering::Less {
+            adjusted = C::zero();
+        }
+        reweighted.add_edge(WeightedEdge::new(from, to, Some(adjusted)));
+    }
+
+    for i in 0..matrix.ids.len() {
+        let start = matrix.ids[i];
+        let mut dijkstra = WeightedVisitor::new(NoTermination);
+        reweighted.traverse(start, &mut MinHeap::default(), &mut dijkstra);
+
+        // Original distances are accumulated along the shortest path tree
+        let mut distances: HashMap<u32, C> = HashMap::from([(start, C::zero())]);
+        for j in 0..matrix.ids.len() {
+            let target = matrix.ids[j];
+            if dijkstra.cost_to(target).is_none() {
+                continue;
+            }
+
+            let mut chain = vec![target];
+            while !distances.contains_key(chain.last().unwrap()) {
+                let parent = dijkstra
+                    .get_parent(*chain.last().unwrap())
+                    .expect("Reached nodes must lead back to the start node");
+                chain.push(parent);
+            }
+            for pair in chain.windows(2).rev() {
+                let (node, parent) = (pair[0], pair[1]);
+                let distance = distances[&parent].plus(weights[&(parent, node)]);
+                distances.insert(node, distance);
+            }
+
+            if target != start {
+                let parent = dijkstra.get_parent(target).map(|p| matrix.index[&p]);
+                matrix.relax(i, j, distances[&target], parent);
+            }
+        }
+    }
+
+    Ok(matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+
+    fn sample() -> Graph<EmptyNode, WeightedEdge> {
+        graph(
+            5,
+            &[
+                (0, 1, 3.0),
+                (0, 2, 8.0),
+                (0, 4, -4.0),
+                (1, 3, 1.0),
+                (1, 4, 7.0),
+                (2, 1, 4.0),
+                (3, 0, 2.0),
+                (3, 2, -5.0),
+                (4, 3, 6.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn floyd_warshall_handles_negative_weights() {
+        let matrix = floyd_warshall(&sample()).unwrap();
+
+        assert_eq!(matrix.distance(0, 1), Some(1.0));
+        assert_eq!(matrix.distance(0, 2), Some(-3.0));
+        assert_eq!(matrix.distance(2, 0), Some(7.0));
+        assert_eq!(matrix.path(0, 1), Some(vec![0, 4, 3, 2, 1]));
+        assert_eq!(matrix.path(3, 3), Some(vec![3]));
+    }
+
+    #[test]
+    fn johnson_matches_floyd_warshall() {
+        let graph = sample();
+        let expected = floyd_warshall(&graph).unwrap();
+        let matrix = johnson(&graph).unwrap();
+
+        assert_eq!(matrix.nodes(), expected.nodes());
+        for &from in matrix.nodes() {
+            for &to in matrix.nodes() {
+                assert_eq!(matrix.distance(from, to), expected.distance(from, to));
+                assert_eq!(matrix.path(from, to), expected.path(from, to));
+            }
+        }
+    }
+
+    #[test]
+    fn johnson_ignores_self_loops() {
+        let graph = graph(
+            4,
+            &[
+                (2, 2, 4.0),
+                (2, 0, 0.0),
+                (0, 3, 4.0),
+                (2, 3, 2.0),
+                (3, 3, 1.0),
+            ],
+        );
+        let expected = floyd_warshall(&graph).unwrap();
+        let matrix = johnson(&graph).unwrap();
+
+        assert_eq!(matrix.distance(2, 3), Some(2.0));
+        for &from in matrix.nodes() {
+            for &to in matrix.nodes() {
+                assert_eq!(matrix.distance(from, to), expected.distance(from, to));
+                assert_eq!(matrix.path(from, to), expected.path(from, to));
+            }
+        }
+    }
+
+    #[test]
+    fn unreachable_pairs_have_no_distance() {
+        let graph = graph(3, &[(0, 1, 1u64)]);
+
+        for matrix in [floyd_warshall(&graph).unwrap(), johnson(&graph).unwrap()] {
+            assert_eq!(matrix.distance(1, 0), None);
+            assert_eq!(matrix.path(0, 2), None);
+            assert_eq!(matrix.distance(2, 2), Some(0));
+            assert_eq!(matrix.distance(0, 9), None);
+        }
+    }
+
+    #[test]
+    fn keeps_cheapest_parallel_edge() {
+        let graph = graph(2, &[(0, 1, 4i64), (0, 1, -2)]);
+
+        assert_eq!(floyd_warshall(&graph).unwrap().distance(0, 1), Some(-2));
+        assert_eq!(johnson(&graph).unwrap().distance(0, 1), Some(-2));
+    }
+
+    #[test]
+    fn ignores_dangling_edges() {
+        let graph = graph(2, &[(0, 1, 1.0), (1, 7, 1.0)]);
+
+        let matrix = floyd_warshall(&graph).unwrap();
+
+        assert_eq!(matrix.nodes(), &[0, 1]);
+        assert_eq!(matrix.distance(1, 7), None);
+    }
+
+    #[test]
+    fn reports_negative_cycles() {
+        let graph = graph(4, &[(0, 1, 1.0), (1, 2, -1.0), (2, 3, -1.0), (3, 1, 1.0)]);
+
+        for error in [
+            floyd_warshall(&graph).unwrap_err(),
+            johnson(&graph).unwrap_err(),
+        ] {
+            let mut cycle = error.cycle;
+            cycle.sort();
+            assert_eq!(cycle, vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn reports_negative_self_loop() {
+        let graph = graph(2, &[(0, 1, 1.0), (1, 1, -0.5)]);
+
+        assert_eq!(floyd_warshall(&graph).unwrap_err().cycle, vec![1]);
+        assert_eq!(johnson(&graph).unwrap_err().cycle, vec![1]);
+    }
+}
@@ -0,0 +1,485 @@
+use crate::frontier::{Frontier, MinHeap};
+use crate::graph::{Graph, Node};
+use crate::policy::Policy;
+use crate::preset::samplers::Grid2D;
+use crate::preset::{DataNode, UnweightedEdge};
+
+use super::RankedPath;
+
+/// Result of an any-angle search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnyAnglePath {
+    /// The waypoints of the path, from start to goal, and its Euclidean length
+    pub path: RankedPath,
+    /// Number of cells expanded by the search
+    pub expanded: usize,
+    /// Number of line-of-sight checks performed by the search
+    pub sight_checks: usize,
+}
+
+/// Finds a short any-angle path on a grid with Theta*.
+///
+/// Cells are the points at their centers: cell `(row, col)` has ID
+/// `row * width + col`, as in a graph sampled with `Grid2DSampler`, and
+/// distances are Euclidean. Cells rejected by the node policy are blocked.
+///
+/// The search expands the eight neighbors of each cell like A*, but
+/// connects a neighbor directly to the parent of the expanded cell
+/// whenever the segment between both centers only crosses unblocked cells.
+/// Paths are therefore not restricted to eight directions and are
+/// usually, though not always, the true shortest paths.
+///
+/// Segments may pass between two diagonally adjacent blocked cells, as
+/// diagonal moves cut corners on 8-connected sampled graphs.
+///
+/// # Arguments
+///
+/// * `grid` - Rectangular grid of cells
+/// * `policy` - Node policy allowing the cells that can be crossed, such
+///   as the one used to build the sampled graph
+/// * `start` - ID of the start cell
+/// * `goal` - ID of the goal cell
+///
+/// # Returns
+///
+/// The path, or `None` if either cell is outside the grid, blocked, or the
+/// goal cannot be reached
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::theta_star;
+/// use hodos::preset::policies::value::DenyNodeValue;
+///
+/// let grid = vec![
+///     vec!['.', '.', '.', '.', '.'],
+///     vec!['.', '.', '.', '.', '.'],
+///     vec!['.', '.', '.', '#', '.'],
+/// ];
+/// let blocked = DenyNodeValue::with_denied_values(vec!['#']);
+///
+/// // A straight segment instead of one diagonal and three straight moves
+/// let found = theta_star(&grid, &blocked, 0, 9).unwrap();
+/// assert_eq!(found.path.nodes, vec![0, 9]);
+/// assert_eq!(found.path.cost, 17f64.sqrt());
+/// ```
+pub fn theta_star<T, P>(grid: &Grid2D<T>, policy: &P, start: u32, goal: u32) -> Option<AnyAnglePath>
+where
+    T: Clone,
+    P: Policy<DataNode<T>, Graph<DataNode<T>, UnweightedEdge>>,
+{
+    Search::new(grid, policy)?.run(start, goal, false)
+}
+
+/// Finds a short any-angle path on a grid with Lazy Theta*.
+///
+/// Same model and paths of the same quality as `theta_star`, but a
+/// neighbor is optimistically connected to the parent of the expanded cell
+/// and the line of sight is only checked once the neighbor is expanded.
+/// When it fails, the neighbor falls back to its best expanded neighbor.
+/// This performs far fewer line-of-sight checks on large open maps.
+///
+/// # Arguments
+///
+/// * `grid` - Rectangular grid of cells
+/// * `policy` - Node policy allowing the cells that can be crossed
+/// * `start` - ID of the start cell
+/// * `goal` - ID of the goal cell
+///
+/// # Returns
+///
+/// The path, or `None` if either cell is outside the grid, blocked, or the
+/// goal cannot be reached
+pub fn lazy_theta_star<T, P>(
+    grid: &Grid2D<T>,
+    policy: &P,
+    start: u32,
+    goal: u32,
+) -> Option<AnyAnglePath>
+where
+    T: Clone,
+    P: Policy<DataNode<T>, Graph<DataNode<T>, UnweightedEdge>>,
+{
+    Search::new(grid, policy)?.run(start, goal, true)
+}
+
+/// Grid position as `(row, col)`.
+type Cell = (i64, i64);
+
+const NEIGHBORS: [Cell; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Euclidean distance between two cell centers.
+fn euclidean(a: Cell, b: Cell) -> f64 {
+    (((a.0 - b.0).pow(2) + (a.1 - b.1).pow(2)) as f64).sqrt()
+}
+
+/// State of an any-angle search over a grid.
+struct Search {
+    width: i64,
+    height: i64,
+    /// Whether each cell, by ID, is allowed by the policy
+    open: Vec<bool>,
+    costs: Vec<f64>,
+    parents: Vec<Option<usize>>,
+    closed: Vec<bool>,
+    sight_checks: usize,
+}
+
+impl Search {
+    /// Evaluates the policy once per cell; `None` for a ragged grid.
+    fn new<T, P>(grid: &Grid2D<T>, policy: &P) -> Option<Self>
+    where
+        T: Clone,
+        P: Policy<DataNode<T>, Graph<DataNode<T>, UnweightedEdge>>,
+    {
+        let width = grid.first().map_or(0, Vec::len);
+        if grid.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        let context = Graph::new();
+        let mut open = Vec::with_capacity(width * grid.len());
+        for (row, cells) in grid.iter().enumerate() {
+            for (col, value) in cells.iter().enumerate() {
+                let node = DataNode::new((row * width + col) as u32, Some(value.clone()));
+                open.push(policy.is_compliant(&node, &context));
+            }
+        }
+
+        let n = open.len();
+        Some(Search {
+            width: width as i64,
+            height: grid.len() as i64,
+            open,
+            costs: vec![f64::INFINITY; n],
+            parents: vec![None; n],
+            closed: vec![false; n],
+            sight_checks: 0,
+        })
+    }
+
+    fn cell(&self, id: u32) -> Option<Cell> {
+        let id = id as i64;
+        (self.width > 0 && id < self.width * self.height)
+            .then(|| (id / self.width, id % self.width))
+    }
+
+    fn index(&self, cell: Cell) -> usize {
+        (cell.0 * self.width + cell.1) as usize
+    }
+
+    fn position(&self, index: usize) -> Cell {
+        (index as i64 / self.width, index as i64 % self.width)
+    }
+
+    fn walkable(&self, cell: Cell) -> bool {
+        (0..self.height).contains(&cell.0)
+            && (0..self.width).contains(&cell.1)
+            && self.open[self.index(cell)]
+    }
+
+    /// Checks that the segment between two cell centers only crosses
+    /// unblocked cells.
+    ///
+    /// Walks the cells entered by the segment in order. Crossing a vertical
+    /// grid line happens at `t = (2i + 1) / 2dx` and crossing a horizontal
+    /// one at `t = (2j + 1) / 2dy`, compared in integers. When both happen
+    /// at once, the segment goes through a corner and moves diagonally.
+    fn line_of_sight(&mut self, from: Cell, to: Cell) -> bool {
+        self.sight_checks += 1;
+
+        let (dy, dx) = ((to.0 - from.0).abs(), (to.1 - from.1).abs());
+        let (sy, sx) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+        let (mut i, mut j) = (0, 0);
+        let mut current = from;
+
+        while current != to {
+            let x_first = (2 * i + 1) * dy;
+            let y_first = (2 * j + 1) * dx;
+            if dy == 0 || (dx != 0 && x_first < y_first) {
+                current.1 += sx;
+                i += 1;
+            } else if dx == 0 || y_first < x_first {
+                current.0 += sy;
+                j += 1;
+            } else {
+                current = (current.0 + sy, current.1 + sx);
+                i += 1;
+                j += 1;
+            }
+
+            if !self.walkable(current) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Runs Theta*, or Lazy Theta* if `lazy` is set.
+    fn run(mut self, start: u32, goal: u32, lazy: bool) -> Option<AnyAnglePath> {
+        let (start, goal) = (self.cell(start)?, self.cell(goal)?);
+        if !self.walkable(start) || !self.walkable(goal) {
+            return None;
+        }
+
+        let (source, target) = (self.index(start), self.index(goal));
+        self.costs[source] = 0.0;
+        self.parents[source] = Some(source);
+
+        let mut frontier = MinHeap::new();
+        frontier.push(source as u32, Some(euclidean(start, goal)));
+        let mut expanded = 0;
+
+        while let Some(id) = frontier.pop() {
+            let current = id as usize;
+            if self.closed[current] {
+                continue;
+            }
+            if lazy {
+                self.set_vertex(current);
+            }
+            self.closed[current] = true;
+            expanded += 1;
+
+            if current == target {
+                return Some(AnyAnglePath {
+                    path: self.path(source, target),
+                    expanded,
+                    sight_checks: self.sight_checks,
+                });
+            }
+
+            let here = self.position(current);
+            for (dr, dc) in NEIGHBORS {
+                let next = (here.0 + dr, here.1 + dc);
+                if !self.walkable(next) || self.closed[self.index(next)] {
+                    continue;
+                }
+
+                let (parent, cost) = self.connect(current, next, lazy);
+                let index = self.index(next);
+                if cost < self.costs[index] {
+                    self.costs[index] = cost;
+                    self.parents[index] = Some(parent);
+                    frontier.push(index as u32, Some(cost + euclidean(next, goal)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Chooses the parent of a neighbor reached from an expanded cell.
+    ///
+    /// The parent of the expanded cell is taken if it sees the neighbor,
+    /// which Lazy Theta* assumes without checking.
+    fn connect(&mut self, current: usize, next: Cell, lazy: bool) -> (usize, f64) {
+        let parent = self.parents[current].unwrap_or(current);
+        let corner = self.position(parent);
+        if lazy || self.line_of_sight(corner, next) {
+            return (parent, self.costs[parent] + euclidean(corner, next));
+        }
+        let here = self.position(current);
+        (current, self.costs[current] + euclidean(here, next))
+    }
+
+    /// Checks the assumed line of sight of a cell about to be expanded,
+    /// falling back to its best expanded neighbor if it is blocked.
+    fn set_vertex(&mut self, current: usize) {
+        let here = self.position(current);
+        let Some(parent) = self.parents[current] else {
+            return;
+        };
+        if parent == current || self.line_of_sight(self.position(parent), here) {
+            return;
+        }
+
+        let mut best: Option<(usize, f64)> = None;
+        for (dr, dc) in NEIGHBORS {
+            let near = (here.0 + dr, here.1 + dc);
+            if !self.walkable(near) || !self.closed[self.index(near)] {
+                continue;
+            }
+            let index = self.index(near);
+            let cost = self.costs[index] + euclidean(near, here);
+            if best.is_none_or(|(_, known)| cost < known) {
+                best = Some((index, cost));
+            }
+        }
+
+        // The cell was reached from an expanded neighbor, so one exists
+        if let Some((index, cost)) = best {
+            self.parents[current] = Some(index);
+            self.costs[current] = cost;
+        }
+    }
+
+    /// Follows the parents back from the target to the source.
+    fn path(&self, source: usize, target: usize) -> RankedPath {
+        let mut nodes = vec![target as u32];
+        let mut current = target;
+        while current != source {
+            current = self.parents[current].expect("expanded cells have a parent");
+            nodes.push(current as u32);
+        }
+        nodes.reverse();
+
+        RankedPath {
+            nodes,
+            cost: self.costs[target],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::jump_point_search;
+    use crate::preset::policies::value::DenyNodeValue;
+    use crate::rng::SeededRng;
+
+    fn parse(rows: &[&str]) -> Grid2D<char> {
+        rows.iter().map(|row| row.chars().collect()).collect()
+    }
+
+    fn walls() -> DenyNodeValue<char> {
+        DenyNodeValue::with_denied_values(vec!['#'])
+    }
+
+    /// Checks a segment by sampling points strictly inside the cells it crosses.
+    fn sampled_sight(grid: &Grid2D<char>, width: u32, from: u32, to: u32) -> bool {
+        let (r0, c0) = ((from / width) as f64 + 0.5, (from % width) as f64 + 0.5);
+        let (r1, c1) = ((to / width) as f64 + 0.5, (to % width) as f64 + 0.5);
+        (0..=997).all(|k| {
+            let t = k as f64 / 997.0;
+            let (r, c) = (r0 + (r1 - r0) * t, c0 + (c1 - c0) * t);
+            let on_corner = (r.fract() < 1e-6 || r.fract() > 1.0 - 1e-6)
+                && (c.fract() < 1e-6 || c.fract() > 1.0 - 1e-6);
+            on_corner || grid[r as usize][c as usize] != '#'
+        })
+    }
+
+    fn assert_valid(grid: &Grid2D<char>, found: &AnyAnglePath) {
+        let width = grid[0].len() as u32;
+        let mut cost = 0.0;
+        for pair in found.path.nodes.windows(2) {
+            assert!(sampled_sight(grid, width, pair[0], pair[1]));
+            let (a, b) = (pair[0] as i64, pair[1] as i64);
+            let width = width as i64;
+            cost += euclidean((a / width, a % width), (b / width, b % width));
+        }
+        assert!((cost - found.path.cost).abs() < 1e-9);
+    }
+
+    #[test]
+    fn crosses_open_grids_in_a_straight_line() {
+        let grid = vec![vec!['.'; 10]; 10];
+
+        for search in [theta_star, lazy_theta_star] {
+            let found = search(&grid, &walls(), 0, 29).unwrap();
+            assert_eq!(found.path.nodes, vec![0, 29]);
+            assert_eq!(found.path.cost, 85f64.sqrt());
+        }
+    }
+
+    #[test]
+    fn bends_around_walls() {
+        let grid = parse(&[
+            "..........", //
+            "..........",
+            "....#.....",
+            "....#.....",
+            "....#.....",
+            "..........",
+        ]);
+
+        // Both go around the bottom of the wall, through the cell below it
+        for search in [theta_star, lazy_theta_star] {
+            let found = search(&grid, &walls(), 30, 39).unwrap();
+            assert_valid(&grid, &found);
+            assert_eq!(found.path.nodes, vec![30, 54, 39]);
+            assert_eq!(found.path.cost, 20f64.sqrt() + 29f64.sqrt());
+        }
+    }
+
+    #[test]
+    fn squeezes_between_diagonal_corners() {
+        let grid = parse(&[
+            ".#", //
+            "#.",
+        ]);
+
+        let found = theta_star(&grid, &walls(), 0, 3).unwrap();
+        assert_eq!(found.path.nodes, vec![0, 3]);
+        assert_eq!(found.path.cost, 2f64.sqrt());
+    }
+
+    #[test]
+    fn rejects_blocked_or_unreachable_cells() {
+        let grid = parse(&[
+            "..#..", //
+            "..#..", "..#..",
+        ]);
+
+        assert_eq!(theta_star(&grid, &walls(), 0, 2), None);
+        assert_eq!(theta_star(&grid, &walls(), 0, 15), None);
+        assert_eq!(lazy_theta_star(&grid, &walls(), 0, 4), None);
+        assert_eq!(theta_star(&grid, &walls(), 0, 4), None);
+    }
+
+    #[test]
+    fn never_longer_than_eight_connected_paths() {
+        let mut rng = SeededRng::new(11);
+
+        for _ in 0..200 {
+            let grid: Grid2D<char> = (0..12)
+                .map(|_| {
+                    (0..12)
+                        .map(|_| if rng.next_below(4) == 0 { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            let (start, goal) = (rng.next_below(144) as u32, rng.next_below(144) as u32);
+
+            let eager = theta_star(&grid, &walls(), start, goal);
+            let lazy = lazy_theta_star(&grid, &walls(), start, goal);
+            let jump = jump_point_search(&grid, |&cell| cell != '#', start, goal);
+            assert_eq!(eager.is_some(), jump.is_some());
+            assert_eq!(lazy.is_some(), jump.is_some());
+
+            if let (Some(eager), Some(lazy), Some(jump)) = (eager, lazy, jump) {
+                assert_valid(&grid, &eager);
+                assert_valid(&grid, &lazy);
+
+                // Every move of an 8-connected path costs at most sqrt(2)
+                let bound = jump.path.cost * 2f64.sqrt() + 1e-9;
+                assert!(eager.path.cost <= bound);
+                assert!(lazy.path.cost <= bound);
+            }
+        }
+    }
+
+    #[test]
+    fn lazy_variant_checks_line_of_sight_less_often() {
+        let mut grid = vec![vec!['.'; 40]; 40];
+        for row in grid.iter_mut().take(30).skip(10) {
+            row[20] = '#';
+        }
+
+        let eager = theta_star(&grid, &walls(), 20 * 40 + 2, 20 * 40 + 37).unwrap();
+        let lazy = lazy_theta_star(&grid, &walls(), 20 * 40 + 2, 20 * 40 + 37).unwrap();
+
+        assert_valid(&grid, &eager);
+        assert_valid(&grid, &lazy);
+        assert!((eager.path.cost - lazy.path.cost).abs() < 1e-9);
+        assert!(lazy.sight_checks * 4 < eager.sight_checks);
+    }
+}
@@ -0,0 +1,331 @@
+use crate::graph::{Edge, Graph, Node};
+use crate::preset::{EmptyNode, UnweightedEdge};
+
+use super::adjacency::Adjacency;
+
+/// Single points of failure of an undirected graph, and its biconnected components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Biconnectivity {
+    /// Nodes whose removal disconnects their component, sorted
+    articulation_points: Vec<u32>,
+    /// Edges whose removal disconnects their component, as sorted `(min, max)` pairs
+    bridges: Vec<(u32, u32)>,
+    /// Members of each block, sorted, blocks being ordered by smallest member
+    blocks: Vec<Vec<u32>>,
+}
+
+impl Biconnectivity {
+    /// Returns the articulation points, sorted.
+    pub fn articulation_points(&self) -> &[u32] {
+        &self.articulation_points
+    }
+
+    /// Returns the bridges as `(min, max)` node ID pairs, sorted.
+    pub fn bridges(&self) -> &[(u32, u32)] {
+        &self.bridges
+    }
+
+    /// Returns the biconnected components.
+    ///
+    /// Each block lists its node IDs, sorted. Articulation points belong to
+    /// several blocks, and isolated nodes form single-node blocks.
+    pub fn blocks(&self) -> &[Vec<u32>] {
+        &self.blocks
+    }
+
+    /// Returns `true` if removing the node disconnects its component.
+    pub fn is_articulation_point(&self, node_id: u32) -> bool {
+        self.articulation_points.binary_search(&node_id).is_ok()
+    }
+
+    /// Returns `true` if removing the edge between two nodes disconnects their component.
+    pub fn is_bridge(&self, a: u32, b: u32) -> bool {
+        self.bridges.binary_search(&(a.min(b), a.max(b))).is_ok()
+    }
+
+    /// Returns the ID of an articulation point's node in the block-cut tree.
+    pub fn cut_node(&self, node_id: u32) -> Option<u32> {
+        self.articulation_points
+            .binary_search(&node_id)
+            .ok()
+            .map(|k| (self.blocks.len() + k) as u32)
+    }
+
+    /// Builds the block-cut tree: one node per block and per articulation point,
+    /// and an edge between each articulation point and the blocks containing it.
+    ///
+    /// Blocks keep their index as node ID, and the articulation point at index
+    /// `k` of `articulation_points()` gets ID `blocks().len() + k` (see
+    /// `cut_node`). Edges are added in both directions, and the result is a
+    /// forest with one tree per connected component.
+    pub fn block_cut_tree(&self) -> Graph<EmptyNode, UnweightedEdge> {
+        let mut tree = Graph::new();
+        let node_count = self.blocks.len() + self.articulation_points.len();
+        for id in 0..node_count as u32 {
+            tree.add_node(EmptyNode::new(id, None));
+        }
+
+        for (block, members) in self.blocks.iter().enumerate() {
+            for &member in members {
+                if let Some(cut) = self.cut_node(member) {
+                    tree.add_edge(UnweightedEdge::new(block as u32, cut, None));
+                    tree.add_edge(UnweightedEdge::new(cut, block as u32, None));
+                }
+            }
+        }
+        tree
+    }
+}
+
+/// Computes articulation points, bridges and biconnected components.
+///
+/// The graph is considered undirected: edge directions are ignored, both
+/// directions of an edge count once, and self loops are ignored. Uses
+/// Hopcroft and Tarjan's lowpoint depth-first search, iteratively so that deep
+/// graphs cannot overflow the stack.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+pub fn biconnected_components<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Biconnectivity
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let n = adjacency.len();
+
+    let neighbors = adjacency.undirected_neighbors();
+
+    let mut order: Vec<Option<usize>> = vec![None; n];
+    let mut low = vec![0; n];
+    let mut is_cut = vec![false; n];
+    let mut bridges = Vec::new();
+    let mut blocks = Vec::new();
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    let mut counter = 0;
+
+    for root in 0..n {
+        if order[root].is_some() {
+            continue;
+        }
+        order[root] = Some(counter);
+        low[root] = counter;
+        counter += 1;
+        if neighbors[root].is_empty() {
+            blocks.push(vec![adjacency.ids[root]]);
+            continue;
+        }
+
+        let mut root_children = 0;
+        // Calls hold (node, parent, next neighbor position)
+        let mut calls = vec![(root, root, 0)];
+
+        while let Some((node, parent, position)) = calls.last_mut() {
+            let (node, parent) = (*node, *parent);
+            if let Some(&next) = neighbors[node].get(*position) {
+                *position += 1;
+                match order[next] {
+                    None => {
+                        order[next] = Some(counter);
+                        low[next] = counter;
+                        counter += 1;
+                        edges.push((node, next));
+                        calls.push((next, node, 0));
+                    }
+                    Some(next_order) if next != parent && next_order < low[node] => {
+                        edges.push((node, next));
+                        low[node] = next_order;
+                    }
+                    Some(next_order) if next != parent && Some(next_order) < order[node] => {
+                        edges.push((node, next));
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            calls.pop();
+            if node == root {
+                continue;
+            }
+            low[parent] = low[parent].min(low[node]);
+            let parent_order = order[parent].unwrap();
+
+            if low[node] > parent_order {
+                let (a, b) = (adjacency.ids[parent], adjacency.ids[node]);
+                bridges.push((a.min(b), a.max(b)));
+            }
+            if low[node] >= parent_order {
+                // Everything explored from `node` since the tree edge forms a block
+                let mut members = Vec::new();
+                while let Some((a, b)) = edges.pop() {
+                    members.push(adjacency.ids[a]);
+                    members.push(adjacency.ids[b]);
+                    if (a, b) == (parent, node) {
+                        break;
+                    }
+                }
+                members.sort_unstable();
+                members.dedup();
+                blocks.push(members);
+
+                if parent == root {
+                    root_children += 1;
+                } else {
+                    is_cut[parent] = true;
+                }
+            }
+        }
+
+        is_cut[root] = root_children > 1;
+    }
+
+    bridges.sort_unstable();
+    blocks.sort();
+    Biconnectivity {
+        articulation_points: (0..n)
+            .filter(|&i| is_cut[i])
+            .map(|i| adjacency.ids[i])
+            .collect(),
+        bridges,
+        blocks,
+    }
+}
+
+/// Returns the articulation points of an undirected graph, sorted.
+///
+/// See `biconnected_components` for how edges are interpreted.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+pub fn articulation_points<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Vec<u32>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    biconnected_components(graph).articulation_points
+}
+
+/// Returns the bridges of an undirected graph as sorted `(min, max)` pairs.
+///
+/// See `biconnected_components` for how edges are interpreted.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+pub fn bridges<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Vec<(u32, u32)>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    biconnected_components(graph).bridges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::unweighted_graph;
+
+    /// Triangle {0, 1, 2} and square {3, 4, 5, 6} joined by bridge 2 - 3,
+    /// a pendant node 7 hanging from 6, and an isolated node 8.
+    fn network() -> Graph<EmptyNode, UnweightedEdge> {
+        unweighted_graph(
+            9,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 0),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (5, 6),
+                (6, 3),
+                (6, 7),
+            ],
+        )
+    }
+
+    #[test]
+    fn finds_single_points_of_failure() {
+        let analysis = biconnected_components(&network());
+
+        assert_eq!(analysis.articulation_points(), &[2, 3, 6]);
+        assert_eq!(analysis.bridges(), &[(2, 3), (6, 7)]);
+        assert!(analysis.is_bridge(3, 2));
+        assert!(!analysis.is_bridge(3, 4));
+        assert!(analysis.is_articulation_point(6));
+        assert!(!analysis.is_articulation_point(0));
+    }
+
+    #[test]
+    fn splits_graph_into_blocks() {
+        let analysis = biconnected_components(&network());
+
+        assert_eq!(
+            analysis.blocks(),
+            &[
+                vec![0, 1, 2],
+                vec![2, 3],
+                vec![3, 4, 5, 6],
+                vec![6, 7],
+                vec![8]
+            ]
+        );
+    }
+
+    #[test]
+    fn both_directions_count_as_one_edge() {
+        // A path stored in both directions still has only bridges
+        let graph = unweighted_graph(3, &[(0, 1), (1, 0), (1, 2), (2, 1)]);
+
+        assert_eq!(bridges(&graph), vec![(0, 1), (1, 2)]);
+        assert_eq!(articulation_points(&graph), vec![1]);
+    }
+
+    #[test]
+    fn cycles_have_no_critical_elements() {
+        let graph = unweighted_graph(4, &[(0, 1), (1, 2), (2, 3), (3, 0), (1, 1)]);
+
+        let analysis = biconnected_components(&graph);
+
+        assert!(analysis.articulation_points().is_empty());
+        assert!(analysis.bridges().is_empty());
+        assert_eq!(analysis.blocks().len(), 1);
+    }
+
+    #[test]
+    fn root_with_several_children_is_articulation_point() {
+        // Star centered on the smallest ID, where the search starts
+        assert_eq!(
+            articulation_points(&unweighted_graph(4, &[(0, 1), (0, 2), (0, 3)])),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn builds_block_cut_tree() {
+        let analysis = biconnected_components(&network());
+
+        let tree = analysis.block_cut_tree();
+
+        // 5 blocks and 3 articulation points
+        assert_eq!(tree.nodes.len(), 8);
+        assert_eq!(analysis.cut_node(3), Some(6));
+        assert_eq!(analysis.cut_node(4), None);
+        // Articulation point 3 joins the bridge block and the square block
+        let mut blocks: Vec<u32> = tree.edges[&6].iter().map(|e| e.to()).collect();
+        blocks.sort_unstable();
+        assert_eq!(blocks, vec![1, 2]);
+        // Tree edges: one per (block, articulation point) membership, both ways
+        assert_eq!(tree.get_edges().len(), 12);
+    }
+
+    #[test]
+    fn deep_graphs_do_not_overflow() {
+        let edges: Vec<(u32, u32)> = (0..20_000).map(|i| (i, i + 1)).collect();
+
+        assert_eq!(bridges(&unweighted_graph(20_001, &edges)).len(), 20_000);
+    }
+}
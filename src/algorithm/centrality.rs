@@ -0,0 +1,696 @@
+use crate::frontier::{MinHeap, Queue};
+use crate::graph::{Edge, Graph, Node};
+use crate::preset::policies::traversal::NoTermination;
+use crate::preset::visitors::{SimpleVisitor, TrackCost, TrackParent, WeightedVisitor};
+use crate::preset::{EmptyNode, WeightedEdge};
+use crate::rng::SeededRng;
+use std::collections::HashMap;
+
+use super::adjacency::Adjacency;
+use super::all_pairs::cheapest_edges;
+
+/// How path lengths are measured by distance based centralities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathMetric {
+    /// Every edge counts as one step, paths are found with a `Queue` traversal
+    #[default]
+    Hops,
+    /// Edge weights are summed, paths are found with a `MinHeap` traversal.
+    /// Weights must be non-negative, without zero-weight cycles.
+    Weighted,
+}
+
+/// Which edges are counted by `degree_centrality`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Incoming edges
+    In,
+    /// Outgoing edges
+    Out,
+    /// Incoming and outgoing edges
+    #[default]
+    Both,
+}
+
+/// PageRank computed by power iteration.
+///
+/// The random surfer follows an outgoing edge with probability `damping`,
+/// and teleports otherwise. Teleports, and moves from nodes without outgoing
+/// edges, land on a node drawn from the personalization vector, uniform by
+/// default. Parallel edges count as many times as they appear.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::PageRank;
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, UnweightedEdge};
+///
+/// let mut graph = Graph::<EmptyNode, UnweightedEdge>::new();
+/// for id in 0..3 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// graph.add_edge(UnweightedEdge::new(0, 2, None));
+/// graph.add_edge(UnweightedEdge::new(1, 2, None));
+/// graph.add_edge(UnweightedEdge::new(2, 0, None));
+///
+/// let scores = PageRank::default().damping(0.9).compute(&graph);
+/// assert!(scores[&2] > scores[&0] && scores[&0] > scores[&1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRank {
+    damping: f64,
+    tolerance: f64,
+    max_iterations: usize,
+    personalization: Option<HashMap<u32, f64>>,
+}
+
+impl Default for PageRank {
+    fn default() -> Self {
+        PageRank {
+            damping: 0.85,
+            tolerance: 1e-9,
+            max_iterations: 100,
+            personalization: None,
+        }
+    }
+}
+
+impl PageRank {
+    /// Sets the probability of following an edge rather than teleporting, `0.85` by default.
+    pub fn damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Sets the total score change under which iterations stop, `1e-9` by default.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the maximum number of iterations, `100` by default.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Biases teleports towards some nodes.
+    ///
+    /// Values are relative weights and are normalized. Nodes missing from
+    /// the map get zero weight. A map without positive weight falls back to
+    /// uniform teleports.
+    pub fn personalization(mut self, personalization: HashMap<u32, f64>) -> Self {
+        self.personalization = Some(personalization);
+        self
+    }
+
+    /// Computes the scores of every node, summing to one.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to rank
+    pub fn compute<TNode, TEdge>(&self, graph: &Graph<TNode, TEdge>) -> HashMap<u32, f64>
+    where
+        TNode: Node,
+        TEdge: Edge,
+    {
+        let adjacency = Adjacency::from_graph(graph);
+        let n = adjacency.len();
+        if n == 0 {
+            return HashMap::new();
+        }
+
+        let mut teleport: Vec<f64> = adjacency
+            .ids
+            .iter()
+            .map(|id| match &self.personalization {
+                Some(weights) => weights.get(id).copied().unwrap_or(0.0).max(0.0),
+                None => 1.0,
+            })
+            .collect();
+        let total: f64 = teleport.iter().sum();
+        if total > 0.0 {
+            teleport.iter_mut().for_each(|t| *t /= total);
+        } else {
+            teleport.fill(1.0 / n as f64);
+        }
+
+        let mut scores = teleport.clone();
+        for _ in 0..self.max_iterations {
+            let dangling: f64 = (0..n)
+                .filter(|&i| adjacency.successors[i].is_empty())
+                .map(|i| scores[i])
+                .sum();
+
+            let mut next: Vec<f64> = teleport
+                .iter()
+                .map(|t| t * (1.0 - self.damping + self.damping * dangling))
+                .collect();
+            for (i, targets) in adjacency.successors.iter().enumerate() {
+                let share = self.damping * scores[i] / targets.len() as f64;
+                for &(j, _) in targets {
+                    next[j] += share;
+                }
+            }
+
+            let change: f64 = next.iter().zip(&scores).map(|(a, b)| (a - b).abs()).sum();
+            scores = next;
+            if change < self.tolerance {
+                break;
+            }
+        }
+
+        adjacency.ids.iter().copied().zip(scores).collect()
+    }
+}
+
+/// Computes the degree centrality of every node.
+///
+/// The degree is divided by `n - 1`, the largest simple degree in a graph
+/// of `n` nodes. Self loops count once per edge, and dangling edges are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+/// * `direction` - The edges to count
+pub fn degree_centrality<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    direction: Direction,
+) -> HashMap<u32, f64>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let n = adjacency.len();
+
+    let mut degrees = vec![0usize; n];
+    for (i, targets) in adjacency.successors.iter().enumerate() {
+        for &(j, _) in targets {
+            if direction != Direction::In {
+                degrees[i] += 1;
+            }
+            if direction != Direction::Out {
+                degrees[j] += 1;
+            }
+        }
+    }
+
+    let scale = if n > 1 { 1.0 / (n - 1) as f64 } else { 0.0 };
+    adjacency
+        .ids
+        .iter()
+        .zip(degrees)
+        .map(|(&id, degree)| (id, degree as f64 * scale))
+        .collect()
+}
+
+/// Computes the closeness centrality of every node, from outgoing distances.
+///
+/// Uses the Wasserman and Faust formula, so that nodes reaching only part of
+/// the graph are not favored: with `r` nodes reached besides itself at a
+/// total distance `d`, the score is `(r / (n - 1)) · (r / d)`. Nodes reaching
+/// nothing score zero.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+/// * `metric` - How path lengths are measured
+pub fn closeness_centrality<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    metric: PathMetric,
+) -> HashMap<u32, f64>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = f64>,
+{
+    let n = graph.nodes.len();
+    let network = network(graph);
+
+    graph
+        .nodes
+        .keys()
+        .map(|&source| {
+            let distances = distances_from(&network, source, metric);
+            let reached = (distances.len() - 1) as f64;
+            let total: f64 = distances.values().sum();
+            let score = if total > 0.0 {
+                (reached / (n - 1) as f64) * (reached / total)
+            } else {
+                0.0
+            };
+            (source, score)
+        })
+        .collect()
+}
+
+/// Computes the betweenness centrality of every node with Brandes' algorithm.
+///
+/// The betweenness of a node is the fraction of shortest paths between
+/// other pairs passing through it, summed over every ordered pair. Scores
+/// are normalized by `(n - 1)(n - 2)`, the number of such pairs. Edges are
+/// followed in their direction, so undirected graphs should store both.
+///
+/// Runs one traversal per node, in `O(n·m)` with `PathMetric::Hops`.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+/// * `metric` - How path lengths are measured
+pub fn betweenness_centrality<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    metric: PathMetric,
+) -> HashMap<u32, f64>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = f64>,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let sources: Vec<usize> = (0..adjacency.len()).collect();
+    brandes(graph, &adjacency, &sources, metric)
+}
+
+/// Estimates the betweenness centrality from a sample of source nodes.
+///
+/// Runs Brandes' accumulation from `samples` distinct sources drawn with a
+/// `SeededRng`, and scales the result as if every node had been a source.
+/// The estimate is unbiased, and exact when `samples` covers every node.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+/// * `metric` - How path lengths are measured
+/// * `samples` - Number of source nodes
+/// * `seed` - Seed of the source sampling
+pub fn sampled_betweenness_centrality<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    metric: PathMetric,
+    samples: usize,
+    seed: u64,
+) -> HashMap<u32, f64>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = f64>,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let n = adjacency.len();
+    let samples = samples.min(n);
+
+    // Partial Fisher-Yates shuffle
+    let mut rng = SeededRng::new(seed);
+    let mut candidates: Vec<usize> = (0..n).collect();
+    for i in 0..samples {
+        let j = i + rng.next_below(n - i);
+        candidates.swap(i, j);
+    }
+    candidates.truncate(samples);
+
+    let mut scores = brandes(graph, &adjacency, &candidates, metric);
+    if samples > 0 {
+        let scale = n as f64 / samples as f64;
+        scores.values_mut().for_each(|score| *score *= scale);
+    }
+    scores
+}
+
+/// Accumulates pair dependencies from the given sources.
+fn brandes<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    adjacency: &Adjacency<f64>,
+    sources: &[usize],
+    metric: PathMetric,
+) -> HashMap<u32, f64>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = f64>,
+{
+    let n = adjacency.len();
+    let network = network(graph);
+    let mut scores = vec![0.0; n];
+
+    for &source in sources {
+        let distances = distances_from(&network, adjacency.ids[source], metric);
+        let distance = |i: usize| distances.get(&adjacency.ids[i]).copied();
+
+        // Edges of the shortest path DAG, parallel edges describing the same path
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for v in (0..n).filter(|&i| distance(i).is_some()) {
+            let from = distance(v).unwrap();
+            for &(w, weight) in &adjacency.successors[v] {
+                let step = match metric {
+                    PathMetric::Hops => 1.0,
+                    PathMetric::Weighted => weight,
+                };
+                let on_shortest_path = w != v
+                    && w != source
+                    && distance(w).is_some_and(|to| same_length(from + step, to));
+                if on_shortest_path && predecessors[w].last() != Some(&v) {
+                    predecessors[w].push(v);
+                    successors[v].push(w);
+                }
+            }
+        }
+
+        // Count shortest paths in topological order of the DAG, which also
+        // orders nodes at the same distance linked by zero-weight edges
+        let mut paths = vec![0.0; n];
+        let mut pending: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+        let mut order = vec![source];
+        paths[source] = 1.0;
+        let mut next = 0;
+        while let Some(&v) = order.get(next) {
+            next += 1;
+            for &w in &successors[v] {
+                paths[w] += paths[v];
+                pending[w] -= 1;
+                if pending[w] == 0 {
+                    order.push(w);
+                }
+            }
+        }
+
+        let mut dependencies = vec![0.0; n];
+        for &w in order.iter().rev() {
+            for &v in &predecessors[w] {
+                dependencies[v] += paths[v] / paths[w] * (1.0 + dependencies[w]);
+            }
+            if w != source {
+                scores[w] += dependencies[w];
+            }
+        }
+    }
+
+    let scale = if n > 2 {
+        1.0 / ((n - 1) * (n - 2)) as f64
+    } else {
+        0.0
+    };
+    adjacency
+        .ids
+        .iter()
+        .zip(scores)
+        .map(|(&id, score)| (id, score * scale))
+        .collect()
+}
+
+/// Returns `true` if two path lengths are equal up to floating point rounding.
+fn same_length(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * a.abs().max(b.abs())
+}
+
+/// Copies the cheapest edge between each pair of known nodes, without self loops.
+///
+/// Traversals read the first parallel edge they meet and would take a self
+/// loop on the source as its distance, so distances are computed on this copy.
+fn network<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Graph<EmptyNode, WeightedEdge>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = f64>,
+{
+    let mut network = Graph::new();
+    for &id in graph.nodes.keys() {
+        network.add_node(EmptyNode::new(id, None));
+    }
+    for ((from, to), weight) in cheapest_edges(graph) {
+        if from != to {
+            network.add_edge(WeightedEdge::new(from, to, Some(weight)));
+        }
+    }
+    network
+}
+
+/// Shortest distances from a source to every node it reaches, itself included.
+///
+/// The graph is expected to come from `network`.
+fn distances_from(
+    graph: &Graph<EmptyNode, WeightedEdge>,
+    source: u32,
+    metric: PathMetric,
+) -> HashMap<u32, f64> {
+    let mut distances = HashMap::from([(source, 0.0)]);
+
+    match metric {
+        PathMetric::Hops => {
+            let mut visitor = SimpleVisitor::new(NoTermination);
+            graph.traverse(source, &mut Queue::new(), &mut visitor);
+
+            // Breadth-first parents form a tree of shortest paths
+            for &id in graph.nodes.keys() {
+                let mut chain = vec![id];
+                while let Some(&node) = chain.last()
+                    && !distances.contains_key(&node)
+                {
+                    match visitor.get_parent(node) {
+                        Some(parent) => chain.push(parent),
+                        None => break,
+                    }
+                }
+                let Some(&known) = chain.last().and_then(|node| distances.get(node)) else {
+                    continue;
+                };
+                for (hops, &node) in chain.iter().rev().enumerate().skip(1) {
+                    distances.insert(node, known + hops as f64);
+                }
+            }
+        }
+        PathMetric::Weighted => {
+            let mut visitor = WeightedVisitor::new(NoTermination);
+            graph.traverse(source, &mut MinHeap::new(), &mut visitor);
+
+            for &id in graph.nodes.keys() {
+                if let Some(cost) = visitor.cost_to(id)
+                    && id != source
+                {
+                    distances.insert(id, cost);
+                }
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    fn undirected(node_count: u32, edges: &[(u32, u32, f64)]) -> Graph<EmptyNode, WeightedEdge> {
+        let both: Vec<(u32, u32, f64)> = edges
+            .iter()
+            .flat_map(|&(a, b, w)| [(a, b, w), (b, a, w)])
+            .collect();
+        graph(node_count, &both)
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn pagerank_sums_to_one_and_favors_hubs() {
+        let graph = graph(4, &[(1, 0, 1.0), (2, 0, 1.0), (3, 0, 1.0), (0, 1, 1.0)]);
+
+        let scores = PageRank::default().compute(&graph);
+
+        assert_close(scores.values().sum(), 1.0);
+        assert!(scores[&0] > scores[&1]);
+        assert!(scores[&1] > scores[&2]);
+        assert_close(scores[&2], scores[&3]);
+    }
+
+    #[test]
+    fn pagerank_of_cycle_is_uniform() {
+        let graph = graph(3, &[(0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0)]);
+
+        for score in PageRank::default().compute(&graph).values() {
+            assert_close(*score, 1.0 / 3.0);
+        }
+    }
+
+    #[test]
+    fn pagerank_redistributes_dangling_nodes() {
+        // Node 1 has no outgoing edge
+        let graph = graph(2, &[(0, 1, 1.0)]);
+
+        let scores = PageRank::default().damping(0.5).compute(&graph);
+
+        // Solves r0 = 0.25 + 0.25·r1 and r1 = 0.25 + 0.5·r0 + 0.25·r1
+        assert_close(scores[&0], 0.4);
+        assert_close(scores[&1], 0.6);
+    }
+
+    #[test]
+    fn personalization_biases_teleports() {
+        let graph = graph(3, &[(0, 1, 1.0), (1, 0, 1.0), (2, 2, 1.0)]);
+
+        let scores = PageRank::default()
+            .personalization(HashMap::from([(2, 1.0)]))
+            .compute(&graph);
+
+        // Teleports always land on 2, which then loops on itself
+        assert_close(scores[&2], 1.0);
+        assert_close(scores[&0], 0.0);
+    }
+
+    #[test]
+    fn degree_centrality_counts_requested_directions() {
+        let graph = graph(3, &[(0, 1, 1.0), (0, 2, 1.0), (1, 2, 1.0)]);
+
+        assert_eq!(degree_centrality(&graph, Direction::Out)[&0], 1.0);
+        assert_eq!(degree_centrality(&graph, Direction::In)[&0], 0.0);
+        assert_eq!(degree_centrality(&graph, Direction::Both)[&2], 1.0);
+    }
+
+    #[test]
+    fn closeness_favors_central_nodes() {
+        // Path 0 - 1 - 2 - 3
+        let graph = undirected(4, &[(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0)]);
+
+        let scores = closeness_centrality(&graph, PathMetric::Hops);
+
+        assert_close(scores[&1], 3.0 / 4.0);
+        assert_close(scores[&0], 3.0 / 6.0);
+    }
+
+    #[test]
+    fn closeness_penalizes_partial_reach() {
+        let graph = graph(3, &[(0, 1, 1.0)]);
+
+        let scores = closeness_centrality(&graph, PathMetric::Hops);
+
+        assert_close(scores[&0], 0.5);
+        assert_eq!(scores[&2], 0.0);
+    }
+
+    #[test]
+    fn closeness_uses_cheapest_parallel_edge() {
+        let graph = graph(2, &[(0, 1, 5.0), (0, 1, 1.0)]);
+
+        let scores = closeness_centrality(&graph, PathMetric::Weighted);
+
+        assert_close(scores[&0], 1.0);
+    }
+
+    #[test]
+    fn closeness_ignores_self_loops() {
+        let graph = graph(2, &[(0, 0, 3.0), (0, 1, 1.0)]);
+
+        assert_close(closeness_centrality(&graph, PathMetric::Weighted)[&0], 1.0);
+        assert_close(closeness_centrality(&graph, PathMetric::Hops)[&0], 1.0);
+    }
+
+    #[test]
+    fn closeness_skips_missing_nodes() {
+        // Node 7 is missing, so 1 cannot be reached from 0
+        let graph = graph(2, &[(0, 7, 1.0), (7, 1, 1.0)]);
+
+        let scores = closeness_centrality(&graph, PathMetric::Hops);
+
+        assert_eq!(scores[&0], 0.0);
+    }
+
+    #[test]
+    fn betweenness_of_star_center() {
+        let graph = undirected(4, &[(0, 1, 1.0), (0, 2, 1.0), (0, 3, 1.0)]);
+
+        let scores = betweenness_centrality(&graph, PathMetric::Hops);
+
+        // Every pair of leaves goes through the center
+        assert_close(scores[&0], 1.0);
+        assert_eq!(scores[&1], 0.0);
+    }
+
+    #[test]
+    fn betweenness_splits_equal_paths() {
+        // Square 0 - 1 - 3 and 0 - 2 - 3
+        let graph = undirected(4, &[(0, 1, 1.0), (0, 2, 1.0), (1, 3, 1.0), (2, 3, 1.0)]);
+
+        let scores = betweenness_centrality(&graph, PathMetric::Hops);
+
+        // Half of the paths between 0 and 3, in both directions, over 6 pairs
+        assert_close(scores[&1], 1.0 / 6.0);
+        assert_close(scores[&1], scores[&2]);
+    }
+
+    #[test]
+    fn weighted_metric_follows_cheap_detours() {
+        // The direct edge 0 - 2 is longer than the detour through 1
+        let graph = undirected(3, &[(0, 1, 1.0), (1, 2, 1.0), (0, 2, 5.0)]);
+
+        let hops = betweenness_centrality(&graph, PathMetric::Hops);
+        let weighted = betweenness_centrality(&graph, PathMetric::Weighted);
+
+        assert_eq!(hops[&1], 0.0);
+        assert_close(weighted[&1], 1.0);
+    }
+
+    #[test]
+    fn weighted_metric_uses_cheapest_parallel_edge() {
+        // The first edge 0 -> 1 is expensive, the second one makes 0 -> 1 -> 2 shortest
+        let graph = graph(3, &[(0, 1, 5.0), (0, 1, 1.0), (0, 2, 3.0), (1, 2, 1.0)]);
+
+        let scores = betweenness_centrality(&graph, PathMetric::Weighted);
+
+        assert_close(scores[&1], 0.5);
+    }
+
+    #[test]
+    fn weighted_metric_counts_paths_whose_sums_round_differently() {
+        // 0.1 + 0.2 and 0.15 + 0.15 are the same length up to rounding
+        let graph = graph(4, &[(0, 1, 0.1), (1, 3, 0.2), (0, 2, 0.15), (2, 3, 0.15)]);
+
+        let scores = betweenness_centrality(&graph, PathMetric::Weighted);
+
+        assert_close(scores[&1], 0.5 / 6.0);
+        assert_close(scores[&2], 0.5 / 6.0);
+    }
+
+    #[test]
+    fn weighted_metric_counts_paths_through_zero_weight_edges() {
+        // 1 -> 2 is free, so both 0 -> 2 and 0 -> 1 -> 2 are shortest
+        let graph = graph(4, &[(0, 1, 1.0), (1, 2, 0.0), (0, 2, 1.0), (2, 3, 1.0)]);
+
+        let scores = betweenness_centrality(&graph, PathMetric::Weighted);
+
+        // 1 is on half of the paths from 0 to 2 and 3, 2 on every path to 3
+        assert_close(scores[&1], 1.0 / 6.0);
+        assert_close(scores[&2], 2.0 / 6.0);
+    }
+
+    #[test]
+    fn weighted_metric_tolerates_nan_weights() {
+        let graph = graph(3, &[(0, 1, 1.0), (1, 2, f64::NAN), (0, 2, 1.0)]);
+
+        let scores = betweenness_centrality(&graph, PathMetric::Weighted);
+
+        assert_eq!(scores[&1], 0.0);
+    }
+
+    #[test]
+    fn sampling_every_source_is_exact() {
+        let graph = undirected(5, &[(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (1, 4, 1.0)]);
+
+        let exact = betweenness_centrality(&graph, PathMetric::Hops);
+        let sampled = sampled_betweenness_centrality(&graph, PathMetric::Hops, 10, 7);
+
+        for (id, score) in exact {
+            assert_close(sampled[&id], score);
+        }
+    }
+
+    #[test]
+    fn sampling_is_reproducible() {
+        let graph = undirected(5, &[(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (1, 4, 1.0)]);
+
+        assert_eq!(
+            sampled_betweenness_centrality(&graph, PathMetric::Weighted, 2, 3),
+            sampled_betweenness_centrality(&graph, PathMetric::Weighted, 2, 3)
+        );
+    }
+}
@@ -0,0 +1,435 @@
+use crate::graph::{Edge, Graph, Node};
+use std::collections::{BTreeSet, HashMap};
+
+use super::adjacency::Adjacency;
+
+/// Order in which `greedy_coloring` colors nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColoringOrder {
+    /// Increasing node ID
+    #[default]
+    Natural,
+    /// Decreasing degree (Welsh–Powell)
+    WelshPowell,
+    /// Most distinct neighbor colors first, ties broken by degree (DSATUR)
+    Dsatur,
+}
+
+/// A color for every node, such that neighbors never share a color.
+///
+/// Colors are numbered `0..count()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coloring {
+    colors: HashMap<u32, usize>,
+    count: usize,
+}
+
+impl Coloring {
+    /// Returns the color of a node, or `None` if the node is unknown.
+    pub fn color(&self, node_id: u32) -> Option<usize> {
+        self.colors.get(&node_id).copied()
+    }
+
+    /// Returns the color of every node, keyed by node ID.
+    pub fn colors(&self) -> &HashMap<u32, usize> {
+        &self.colors
+    }
+
+    /// Returns the number of colors used.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the IDs of the nodes of each color, sorted, indexed by color.
+    pub fn classes(&self) -> Vec<Vec<u32>> {
+        let mut classes = vec![Vec::new(); self.count];
+        for (&id, &color) in &self.colors {
+            classes[color].push(id);
+        }
+        classes.iter_mut().for_each(|class| class.sort_unstable());
+        classes
+    }
+
+    /// Stores each node's color as its data, with `Node::set_data`.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to update, nodes without a color are left untouched
+    pub fn apply<TNode, TEdge>(&self, graph: &mut Graph<TNode, TEdge>)
+    where
+        TNode: Node<Data = usize>,
+    {
+        for (id, node) in graph.nodes.iter_mut() {
+            if let Some(color) = self.colors.get(id) {
+                node.set_data(color);
+            }
+        }
+    }
+}
+
+/// Colors an undirected graph greedily.
+///
+/// Nodes are colored one at a time, in the given order, with the smallest
+/// color unused by their neighbors. Edge directions are ignored and self
+/// loops are skipped. The result is a proper coloring, not necessarily
+/// with the fewest colors: `Dsatur` usually gets closest.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to color
+/// * `order` - The order in which nodes are colored
+pub fn greedy_coloring<TNode, TEdge>(graph: &Graph<TNode, TEdge>, order: ColoringOrder) -> Coloring
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let neighbors = adjacency.undirected_neighbors();
+    let n = adjacency.len();
+    let mut colors: Vec<Option<usize>> = vec![None; n];
+
+    let smallest_free = |node: usize, colors: &[Option<usize>]| {
+        let used: BTreeSet<usize> = neighbors[node].iter().filter_map(|&j| colors[j]).collect();
+        (0..).find(|color| !used.contains(color)).unwrap()
+    };
+
+    match order {
+        ColoringOrder::Natural | ColoringOrder::WelshPowell => {
+            let mut sequence: Vec<usize> = (0..n).collect();
+            if order == ColoringOrder::WelshPowell {
+                sequence.sort_by_key(|&i| std::cmp::Reverse(neighbors[i].len()));
+            }
+            for node in sequence {
+                colors[node] = Some(smallest_free(node, &colors));
+            }
+        }
+        ColoringOrder::Dsatur => {
+            let mut saturation: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+            for _ in 0..n {
+                let node = (0..n)
+                    .filter(|&i| colors[i].is_none())
+                    .max_by_key(|&i| {
+                        (
+                            saturation[i].len(),
+                            neighbors[i].len(),
+                            std::cmp::Reverse(i),
+                        )
+                    })
+                    .unwrap();
+                let color = smallest_free(node, &colors);
+                colors[node] = Some(color);
+                for &j in &neighbors[node] {
+                    saturation[j].insert(color);
+                }
+            }
+        }
+    }
+
+    let colors: Vec<usize> = colors.into_iter().flatten().collect();
+    Coloring {
+        count: colors.iter().max().map_or(0, |&max| max + 1),
+        colors: adjacency.ids.iter().copied().zip(colors).collect(),
+    }
+}
+
+/// Finds a maximal independent set of an undirected graph.
+///
+/// Nodes are picked greedily by increasing degree, each pick excluding its
+/// neighbors, until no node can be added. The set is maximal, not
+/// necessarily maximum. Edge directions are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+///
+/// # Returns
+///
+/// The IDs of the set, sorted
+pub fn maximal_independent_set<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Vec<u32>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let neighbors = adjacency.undirected_neighbors();
+
+    let mut sequence: Vec<usize> = (0..adjacency.len()).collect();
+    sequence.sort_by_key(|&i| neighbors[i].len());
+
+    let mut excluded = vec![false; adjacency.len()];
+    let mut set = Vec::new();
+    for node in sequence {
+        if excluded[node] {
+            continue;
+        }
+        set.push(adjacency.ids[node]);
+        for &j in &neighbors[node] {
+            excluded[j] = true;
+        }
+    }
+    set.sort_unstable();
+    set
+}
+
+/// Enumerates the maximal cliques of an undirected graph with the Bron–Kerbosch algorithm.
+///
+/// Uses Tomita's pivoting rule. Edge directions are ignored and self loops
+/// are skipped. The number of maximal cliques can grow exponentially with
+/// the graph size.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+///
+/// # Returns
+///
+/// Every maximal clique as sorted node IDs, cliques being sorted
+pub fn maximal_cliques<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Vec<Vec<u32>>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let neighbors = adjacency.undirected_neighbors();
+    cliques_of(&adjacency.ids, &neighbors)
+}
+
+/// Enumerates the maximal independent sets of an undirected graph.
+///
+/// These are the maximal cliques of the complement graph, found with the
+/// Bron–Kerbosch algorithm. Intended for small conflict graphs: the number
+/// of sets can grow exponentially.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+///
+/// # Returns
+///
+/// Every maximal independent set as sorted node IDs, sets being sorted
+pub fn maximal_independent_sets<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Vec<Vec<u32>>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let neighbors = adjacency.undirected_neighbors();
+    let n = adjacency.len();
+
+    let complement: Vec<Vec<usize>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .filter(|&j| j != i && neighbors[i].binary_search(&j).is_err())
+                .collect()
+        })
+        .collect();
+    cliques_of(&adjacency.ids, &complement)
+}
+
+/// Returns a largest clique, the first one in sorted order among ties.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+pub fn maximum_clique<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Vec<u32>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    maximal_cliques(graph)
+        .into_iter()
+        .rev()
+        .max_by_key(Vec::len)
+        .unwrap_or_default()
+}
+
+fn cliques_of(ids: &[u32], neighbors: &[Vec<usize>]) -> Vec<Vec<u32>> {
+    let mut cliques = Vec::new();
+    let candidates: Vec<usize> = (0..ids.len()).collect();
+    bron_kerbosch(
+        neighbors,
+        &mut Vec::new(),
+        candidates,
+        Vec::new(),
+        &mut cliques,
+    );
+
+    let mut cliques: Vec<Vec<u32>> = cliques
+        .into_iter()
+        .map(|clique: Vec<usize>| {
+            let mut clique: Vec<u32> = clique.into_iter().map(|i| ids[i]).collect();
+            clique.sort_unstable();
+            clique
+        })
+        .collect();
+    cliques.sort();
+    cliques
+}
+
+/// Extends `clique` with `candidates`, `excluded` holding nodes already explored.
+fn bron_kerbosch(
+    neighbors: &[Vec<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && !clique.is_empty() {
+            cliques.push(clique.clone());
+        }
+        return;
+    }
+
+    let adjacent = |a: usize, b: usize| neighbors[a].binary_search(&b).is_ok();
+    // The pivot covers the most candidates, which need not be tried directly
+    let pivot = candidates
+        .iter()
+        .chain(&excluded)
+        .copied()
+        .max_by_key(|&u| candidates.iter().filter(|&&v| adjacent(u, v)).count())
+        .unwrap();
+
+    let branches: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|&v| !adjacent(pivot, v))
+        .collect();
+    for v in branches {
+        clique.push(v);
+        bron_kerbosch(
+            neighbors,
+            clique,
+            candidates
+                .iter()
+                .copied()
+                .filter(|&u| adjacent(v, u))
+                .collect(),
+            excluded
+                .iter()
+                .copied()
+                .filter(|&u| adjacent(v, u))
+                .collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.retain(|&u| u != v);
+        excluded.push(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::unweighted_graph;
+    use crate::preset::{DataNode, EmptyNode, UnweightedEdge};
+
+    fn assert_proper(graph: &Graph<EmptyNode, UnweightedEdge>, coloring: &Coloring) {
+        for edge in graph.get_edges() {
+            if edge.from() != edge.to() {
+                assert_ne!(coloring.color(edge.from()), coloring.color(edge.to()));
+            }
+        }
+    }
+
+    /// Crown graph on 8 nodes: i and j + 4 linked unless i == j. Natural
+    /// order with interleaved IDs is a classic worst case for greedy coloring.
+    fn crown() -> Graph<EmptyNode, UnweightedEdge> {
+        let mut edges = Vec::new();
+        for i in 0..4u32 {
+            for j in 0..4u32 {
+                if i != j {
+                    edges.push((2 * i, 2 * j + 1));
+                }
+            }
+        }
+        unweighted_graph(8, &edges)
+    }
+
+    #[test]
+    fn every_order_gives_proper_coloring() {
+        let graph = crown();
+
+        for order in [
+            ColoringOrder::Natural,
+            ColoringOrder::WelshPowell,
+            ColoringOrder::Dsatur,
+        ] {
+            assert_proper(&graph, &greedy_coloring(&graph, order));
+        }
+    }
+
+    #[test]
+    fn dsatur_colors_bipartite_graphs_with_two_colors() {
+        let graph = crown();
+
+        assert_eq!(greedy_coloring(&graph, ColoringOrder::Natural).count(), 4);
+        assert_eq!(greedy_coloring(&graph, ColoringOrder::Dsatur).count(), 2);
+    }
+
+    #[test]
+    fn welsh_powell_colors_hubs_first() {
+        // Star centered on 3 plus an edge 0 - 1
+        let graph = unweighted_graph(5, &[(3, 0), (3, 1), (3, 2), (3, 4), (0, 1)]);
+
+        let coloring = greedy_coloring(&graph, ColoringOrder::WelshPowell);
+
+        assert_eq!(coloring.color(3), Some(0));
+        assert_eq!(coloring.count(), 3);
+        assert_eq!(coloring.classes()[0], vec![3]);
+    }
+
+    #[test]
+    fn coloring_applies_to_node_data() {
+        let mut graph = Graph::<DataNode<usize>, UnweightedEdge>::new();
+        for id in 0..3 {
+            graph.add_node(DataNode::new(id, Some(usize::MAX)));
+        }
+        graph.add_edge(UnweightedEdge::new(0, 1, None));
+        graph.add_edge(UnweightedEdge::new(1, 2, None));
+
+        let coloring = greedy_coloring(&graph, ColoringOrder::Natural);
+        coloring.apply(&mut graph);
+
+        assert_eq!(graph.nodes[&0].data(), Some(&0));
+        assert_eq!(graph.nodes[&1].data(), Some(&1));
+        assert_eq!(graph.nodes[&2].data(), Some(&0));
+    }
+
+    #[test]
+    fn independent_set_is_maximal() {
+        // Path 0 - 1 - 2 - 3 - 4
+        let graph = unweighted_graph(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+
+        assert_eq!(maximal_independent_set(&graph), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn enumerates_maximal_cliques() {
+        // Triangle {0, 1, 2} sharing node 2 with triangle {2, 3, 4}, plus 4 - 5
+        let graph = unweighted_graph(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)]);
+
+        assert_eq!(
+            maximal_cliques(&graph),
+            vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5]]
+        );
+        assert_eq!(maximum_clique(&graph), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn isolated_nodes_are_cliques_and_self_loops_are_ignored() {
+        let graph = unweighted_graph(3, &[(0, 1), (1, 0), (2, 2)]);
+
+        assert_eq!(maximal_cliques(&graph), vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn enumerates_maximal_independent_sets() {
+        let graph = unweighted_graph(4, &[(0, 1), (1, 2), (2, 3)]);
+
+        assert_eq!(
+            maximal_independent_sets(&graph),
+            vec![vec![0, 2], vec![0, 3], vec![1, 3]]
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use crate::graph::{Edge, Graph, Node};
+use crate::preset::{EmptyNode, UnweightedEdge};
+use std::collections::{BTreeSet, HashMap};
+
+use super::DisjointSet;
+use super::adjacency::Adjacency;
+
+/// A partition of a graph's nodes into components.
+///
+/// Components are identified by `0..count()`. Every node of the analyzed
+/// graph belongs to exactly one component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    component_of: HashMap<u32, usize>,
+    sizes: Vec<usize>,
+}
+
+impl Components {
+    /// Builds the partition from the component of each node, in node order.
+    fn from_assignment(ids: &[u32], assignment: &[usize], count: usize) -> Self {
+        let mut sizes = vec![0; count];
+        for &component in assignment {
+            sizes[component] += 1;
+        }
+
+        Components {
+            component_of: ids
+                .iter()
+                .copied()
+                .zip(assignment.iter().copied())
+                .collect(),
+            sizes,
+        }
+    }
+
+    /// Returns the component of a node, or `None` if the node is unknown.
+    pub fn component(&self, node_id: u32) -> Option<usize> {
+        self.component_of.get(&node_id).copied()
+    }
+
+    /// Returns the number of components.
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Returns the number of nodes of each component, indexed by component.
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// Returns the IDs of the nodes of a component, sorted.
+    pub fn members(&self, component: usize) -> Vec<u32> {
+        let mut members: Vec<u32> = self
+            .component_of
+            .iter()
+            .filter(|&(_, &c)| c == component)
+            .map(|(&id, _)| id)
+            .collect();
+        members.sort_unstable();
+        members
+    }
+
+    /// Returns `true` if two nodes belong to the same component.
+    pub fn connected(&self, a: u32, b: u32) -> bool {
+        matches!((self.component(a), self.component(b)), (Some(x), Some(y)) if x == y)
+    }
+
+    /// Returns `true` if the graph forms at most one component.
+    pub fn is_connected(&self) -> bool {
+        self.count() <= 1
+    }
+}
+
+/// Computes the weakly connected components of a graph.
+///
+/// Edge directions are ignored. Components are numbered by their smallest node ID.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+pub fn weakly_connected_components<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Components
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let mut sets = DisjointSet::new(adjacency.len());
+
+    for (i, targets) in adjacency.successors.iter().enumerate() {
+        for &(j, _) in targets {
+            sets.union(i, j);
+        }
+    }
+
+    let mut numbering: HashMap<usize, usize> = HashMap::new();
+    let assignment: Vec<usize> = (0..adjacency.len())
+        .map(|i| {
+            let root = sets.find(i);
+            let next = numbering.len();
+            *numbering.entry(root).or_insert(next)
+        })
+        .collect();
+
+    Components::from_assignment(&adjacency.ids, &assignment, numbering.len())
+}
+
+/// Computes the strongly connected components of a graph with Tarjan's algorithm.
+///
+/// Performs a single depth-first search, iteratively so that deep graphs
+/// cannot overflow the stack. Components are numbered in topological order
+/// of the condensation: edges between components always go from a lower to
+/// a higher component.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+pub fn tarjan_scc<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Components
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let n = adjacency.len();
+
+    let mut order: Vec<Option<usize>> = vec![None; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut assignment = vec![0; n];
+    let mut count = 0;
+    let mut counter = 0;
+
+    for root in 0..n {
+        if order[root].is_some() {
+            continue;
+        }
+
+        let mut calls = vec![(root, 0)];
+        order[root] = Some(counter);
+        low[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some((node, position)) = calls.last_mut() {
+            let node = *node;
+            if let Some(&(next, _)) = adjacency.successors[node].get(*position) {
+                *position += 1;
+                match order[next] {
+                    None => {
+                        order[next] = Some(counter);
+                        low[next] = counter;
+                        counter += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        calls.push((next, 0));
+                    }
+                    Some(next_order) if on_stack[next] => low[node] = low[node].min(next_order),
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                low[parent] = low[parent].min(low[node]);
+            }
+            if Some(low[node]) == order[node] {
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    assignment[member] = count;
+                    if member == node {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+
+    // Tarjan completes sink components first, reverse to get topological order
+    for component in &mut assignment {
+        *component = count - 1 - *component;
+    }
+
+    Components::from_assignment(&adjacency.ids, &assignment, count)
+}
+
+/// Computes the strongly connected components of a graph with Kosaraju's algorithm.
+///
+/// Performs a depth-first search to order nodes by finish time, then
+/// collects components with a second search on the reversed graph. Like
+/// `tarjan_scc`, components are numbered in topological order of the
+/// condensation.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+pub fn kosaraju_scc<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Components
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let n = adjacency.len();
+
+    let mut visited = vec![false; n];
+    let mut finished = Vec::with_capacity(n);
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut calls = vec![(root, 0)];
+        while let Some((node, position)) = calls.last_mut() {
+            let node = *node;
+            if let Some(&(next, _)) = adjacency.successors[node].get(*position) {
+                *position += 1;
+                if !visited[next] {
+                    visited[next] = true;
+                    calls.push((next, 0));
+                }
+            } else {
+                calls.pop();
+                finished.push(node);
+            }
+        }
+    }
+
+    let reversed = adjacency.reversed();
+    let mut assignment: Vec<Option<usize>> = vec![None; n];
+    let mut count = 0;
+    for &root in finished.iter().rev() {
+        if assignment[root].is_some() {
+            continue;
+        }
+        assignment[root] = Some(count);
+        let mut pending = vec![root];
+        while let Some(node) = pending.pop() {
+            for &(next, _) in &reversed.successors[node] {
+                if assignment[next].is_none() {
+                    assignment[next] = Some(count);
+                    pending.push(next);
+                }
+            }
+        }
+        count += 1;
+    }
+
+    let assignment: Vec<usize> = assignment.into_iter().flatten().collect();
+    Components::from_assignment(&adjacency.ids, &assignment, count)
+}
+
+/// Builds the condensation of a graph: one node per component, and one edge
+/// between two components whenever an edge of the graph links them.
+///
+/// When `components` are strongly connected components, the condensation is
+/// a directed acyclic graph. Node IDs are component IDs and duplicate edges
+/// are merged.
+///
+/// # Arguments
+///
+/// * `graph` - The analyzed graph
+/// * `components` - A partition of the graph's nodes
+pub fn condensation<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    components: &Components,
+) -> Graph<EmptyNode, UnweightedEdge>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let mut links = BTreeSet::new();
+    for edge in graph.edges.values().flatten() {
+        if let (Some(from), Some(to)) = (
+            components.component(edge.from()),
+            components.component(edge.to()),
+        ) && from != to
+        {
+            links.insert((from as u32, to as u32));
+        }
+    }
+
+    let mut dag = Graph::new();
+    for component in 0..components.count() as u32 {
+        dag.add_node(EmptyNode::new(component, None));
+    }
+    for (from, to) in links {
+        dag.add_edge(UnweightedEdge::new(from, to, None));
+    }
+    dag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::unweighted_graph;
+
+    /// Two cycles {0, 1, 2} and {3, 4} linked by 2 -> 3, plus an isolated node 5.
+    fn sample() -> Graph<EmptyNode, UnweightedEdge> {
+        unweighted_graph(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)])
+    }
+
+    #[test]
+    fn weak_components_ignore_directions() {
+        let components =
+            weakly_connected_components(&unweighted_graph(5, &[(1, 0), (2, 1), (3, 4)]));
+
+        assert_eq!(components.count(), 2);
+        assert_eq!(components.sizes(), &[3, 2]);
+        assert_eq!(components.members(0), vec![0, 1, 2]);
+        assert!(components.connected(0, 2));
+        assert!(!components.connected(2, 3));
+        assert!(!components.is_connected());
+    }
+
+    #[test]
+    fn strong_components_agree() {
+        for components in [tarjan_scc(&sample()), kosaraju_scc(&sample())] {
+            assert_eq!(components.count(), 3);
+            assert!(components.connected(0, 2));
+            assert!(components.connected(3, 4));
+            assert!(!components.connected(2, 3));
+            assert_eq!(
+                components.members(components.component(5).unwrap()),
+                vec![5]
+            );
+        }
+    }
+
+    #[test]
+    fn strong_components_follow_topological_order() {
+        for components in [tarjan_scc(&sample()), kosaraju_scc(&sample())] {
+            assert!(components.component(0) < components.component(3));
+        }
+    }
+
+    #[test]
+    fn acyclic_graph_has_singleton_components() {
+        let components = tarjan_scc(&unweighted_graph(4, &[(0, 1), (1, 2), (0, 3)]));
+
+        assert_eq!(components.count(), 4);
+        assert!(components.sizes().iter().all(|&size| size == 1));
+    }
+
+    #[test]
+    fn self_loop_is_single_component() {
+        let components = kosaraju_scc(&unweighted_graph(2, &[(0, 0), (0, 1)]));
+
+        assert_eq!(components.count(), 2);
+        assert!(!components.is_connected());
+    }
+
+    #[test]
+    fn deep_graphs_do_not_overflow() {
+        let edges: Vec<(u32, u32)> = (0..20_000).map(|i| (i, (i + 1) % 20_000)).collect();
+        let graph = unweighted_graph(20_000, &edges);
+
+        assert!(tarjan_scc(&graph).is_connected());
+        assert!(kosaraju_scc(&graph).is_connected());
+    }
+
+    #[test]
+    fn condensation_is_acyclic_and_deduplicated() {
+        let graph = unweighted_graph(4, &[(0, 1), (1, 0), (0, 2), (1, 2), (2, 3), (3, 2)]);
+        let components = tarjan_scc(&graph);
+
+        let dag = condensation(&graph, &components);
+
+        assert_eq!(dag.nodes.len(), 2);
+        assert_eq!(dag.get_edges().len(), 1);
+        let edge = dag.get_edges()[0];
+        assert_eq!(edge.from(), components.component(0).unwrap() as u32);
+        assert_eq!(edge.to(), components.component(2).unwrap() as u32);
+    }
+
+    #[test]
+    fn unknown_nodes_have_no_component() {
+        let components = weakly_connected_components(&unweighted_graph(2, &[(0, 1), (1, 9)]));
+
+        assert_eq!(components.component(9), None);
+        assert!(components.is_connected());
+    }
+}
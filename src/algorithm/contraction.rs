@@ -0,0 +1,580 @@
+use crate::frontier::{Frontier, MinHeap};
+use crate::graph::{Edge, Graph, Node};
+use crate::preset::WeightedEdge;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::RankedPath;
+use super::all_pairs::cheapest_edges;
+
+/// Edge of the hierarchy, either original or a shortcut bypassing `middle`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Arc {
+    target: usize,
+    weight: f64,
+    middle: Option<usize>,
+}
+
+/// Preprocessed graph answering point-to-point shortest path queries fast.
+///
+/// Nodes are contracted one by one in order of importance. Contracting a
+/// node removes it from the remaining graph and adds shortcut edges between
+/// its neighbors wherever it lay on their only shortest path. A query then
+/// runs two Dijkstra searches that only climb towards more important nodes,
+/// and settles a tiny fraction of the graph on road-like networks.
+///
+/// Built by `HierarchyBuilder`. Weights must be non-negative.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::HierarchyBuilder;
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, WeightedEdge};
+///
+/// let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+/// for id in 0..4 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// graph.add_edge(WeightedEdge::new(0, 1, Some(1.0)));
+/// graph.add_edge(WeightedEdge::new(1, 2, Some(1.0)));
+/// graph.add_edge(WeightedEdge::new(2, 3, Some(1.0)));
+/// graph.add_edge(WeightedEdge::new(0, 3, Some(5.0)));
+///
+/// let hierarchy = HierarchyBuilder::default().build(&graph);
+/// assert_eq!(hierarchy.distance(0, 3), Some(3.0));
+/// assert_eq!(hierarchy.shortest_path(0, 3).unwrap().nodes, vec![0, 1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContractionHierarchy {
+    /// Node IDs, sorted
+    ids: Vec<u32>,
+    index: HashMap<u32, usize>,
+    /// Contraction order of each node
+    rank: Vec<usize>,
+    /// Edges leaving each node towards higher ranked nodes
+    up: Vec<Vec<Arc>>,
+    /// Edges entering each node from higher ranked nodes, targeting their source
+    down: Vec<Vec<Arc>>,
+    shortcuts: usize,
+}
+
+impl ContractionHierarchy {
+    /// Returns the number of shortcut edges added by the contraction.
+    pub fn shortcut_count(&self) -> usize {
+        self.shortcuts
+    }
+
+    /// Returns the contraction order of a node, from 0 for the least important.
+    pub fn rank(&self, node_id: u32) -> Option<usize> {
+        Some(self.rank[*self.index.get(&node_id)?])
+    }
+
+    /// Returns the shortest distance between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    ///
+    /// # Returns
+    ///
+    /// `None` if the target is unreachable or either node is unknown
+    pub fn distance(&self, from: u32, to: u32) -> Option<f64> {
+        let (s, t) = (*self.index.get(&from)?, *self.index.get(&to)?);
+        self.search(s, t).map(|(distance, _)| distance)
+    }
+
+    /// Returns the shortest path between two nodes, shortcuts unpacked.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    ///
+    /// # Returns
+    ///
+    /// The node IDs of the path in the original graph and its cost, or
+    /// `None` if the target is unreachable or either node is unknown
+    pub fn shortest_path(&self, from: u32, to: u32) -> Option<RankedPath> {
+        let edges = self.shortest_path_edges(from, to)?;
+        let mut nodes = vec![from];
+        nodes.extend(edges.iter().map(|edge| edge.to()));
+        let cost = edges.iter().map(|edge| edge.weight()).sum();
+        Some(RankedPath { nodes, cost })
+    }
+
+    /// Returns the original edges along the shortest path between two nodes.
+    ///
+    /// Parallel edges are represented by the cheapest one.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    ///
+    /// # Returns
+    ///
+    /// The edges in path order, empty when `from == to`, or `None` if the
+    /// target is unreachable or either node is unknown
+    pub fn shortest_path_edges(&self, from: u32, to: u32) -> Option<Vec<WeightedEdge>> {
+        let (s, t) = (*self.index.get(&from)?, *self.index.get(&to)?);
+        let (_, hops) = self.search(s, t)?;
+
+        let mut edges = Vec::new();
+        for pair in hops.windows(2) {
+            // Shortcuts are replaced by their two halves until original edges remain
+            let mut pending = vec![(pair[0], pair[1])];
+            while let Some((a, b)) = pending.pop() {
+                let arc = self.arc(a, b);
+                match arc.middle {
+                    Some(middle) => {
+                        pending.push((middle, b));
+                        pending.push((a, middle));
+                    }
+                    None => edges.push(WeightedEdge::new(
+                        self.ids[a],
+                        self.ids[b],
+                        Some(arc.weight),
+                    )),
+                }
+            }
+        }
+        Some(edges)
+    }
+
+    /// Finds the hierarchy edge from `a` to `b`.
+    fn arc(&self, a: usize, b: usize) -> Arc {
+        let found = if self.rank[a] < self.rank[b] {
+            self.up[a].iter().find(|arc| arc.target == b)
+        } else {
+            self.down[b].iter().find(|arc| arc.target == a)
+        };
+        *found.expect("hierarchy edge along a found path")
+    }
+
+    /// Runs the bidirectional upward search.
+    ///
+    /// The forward search from `s` is exhaustive, the backward search from
+    /// `t` stops once its distances exceed the best meeting cost.
+    ///
+    /// # Returns
+    ///
+    /// The distance and the hierarchy nodes of the path, shortcuts packed
+    fn search(&self, s: usize, t: usize) -> Option<(f64, Vec<usize>)> {
+        let (forward, forward_parents) = self.upward_search(s, &self.up, None);
+        let (backward, backward_parents) = self.upward_search(t, &self.down, Some(&forward));
+
+        let (distance, meeting) = backward
+            .iter()
+            .filter_map(|(&v, &db)| Some((forward.get(&v)? + db, v)))
+            .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)))?;
+
+        let mut hops = vec![meeting];
+        while let Some(&parent) = forward_parents.get(hops.last().unwrap()) {
+            hops.push(parent);
+        }
+        hops.reverse();
+        let mut current = meeting;
+        while let Some(&parent) = backward_parents.get(&current) {
+            hops.push(parent);
+            current = parent;
+        }
+        Some((distance, hops))
+    }
+
+    /// Dijkstra over upward edges from `source`.
+    ///
+    /// When `other` holds the distances of the opposite search, the search
+    /// stops as soon as it cannot improve the best meeting cost.
+    fn upward_search(
+        &self,
+        source: usize,
+        arcs: &[Vec<Arc>],
+        other: Option<&HashMap<usize, f64>>,
+    ) -> (HashMap<usize, f64>, HashMap<usize, usize>) {
+        let mut distances = HashMap::from([(source, 0.0)]);
+        let mut parents = HashMap::new();
+        let mut settled = HashSet::new();
+        let mut best = f64::INFINITY;
+        let mut frontier = MinHeap::new();
+        frontier.push(source as u32, Some(0.0));
+
+        while let Some(node) = frontier.pop() {
+            let node = node as usize;
+            if !settled.insert(node) {
+                continue;
+            }
+            let distance = distances[&node];
+            if let Some(other) = other {
+                if distance >= best {
+                    break;
+                }
+                if let Some(rest) = other.get(&node) {
+                    best = best.min(distance + rest);
+                }
+            }
+
+            for arc in &arcs[node] {
+                let candidate = distance + arc.weight;
+                if distances
+                    .get(&arc.target)
+                    .is_none_or(|&current| candidate < current)
+                {
+                    distances.insert(arc.target, candidate);
+                    parents.insert(arc.target, node);
+                    frontier.push(arc.target as u32, Some(candidate));
+                }
+            }
+        }
+        (distances, parents)
+    }
+}
+
+/// Builds contraction hierarchies.
+///
+/// Nodes are ordered by edge difference, the number of shortcuts their
+/// contraction adds minus the edges it removes, plus their number of
+/// already contracted neighbors to spread contractions evenly. Priorities
+/// are lazily updated: a node whose priority grew when popped is deferred.
+///
+/// A shortcut is skipped when a witness search finds a path at most as
+/// cheap avoiding the contracted node. Witness searches give up after
+/// settling `witness_limit` nodes, which only adds unneeded shortcuts and
+/// never affects query results.
+#[derive(Debug, Clone)]
+pub struct HierarchyBuilder {
+    witness_limit: usize,
+}
+
+impl Default for HierarchyBuilder {
+    fn default() -> Self {
+        HierarchyBuilder { witness_limit: 500 }
+    }
+}
+
+impl HierarchyBuilder {
+    /// Sets the number of nodes a witness search may settle.
+    pub fn witness_limit(mut self, witness_limit: usize) -> Self {
+        self.witness_limit = witness_limit;
+        self
+    }
+
+    /// Contracts a graph into a hierarchy.
+    ///
+    /// Parallel edges are reduced to the cheapest one, self loops and edges
+    /// to unknown nodes are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph, with non-negative weights
+    pub fn build<TNode, TEdge>(&self, graph: &Graph<TNode, TEdge>) -> ContractionHierarchy
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let mut ids: Vec<u32> = graph.nodes.keys().copied().collect();
+        ids.sort_unstable();
+        let index: HashMap<u32, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let n = ids.len();
+
+        let mut overlay = Overlay {
+            outgoing: vec![BTreeMap::new(); n],
+            incoming: vec![BTreeMap::new(); n],
+            witness_limit: self.witness_limit,
+        };
+        for ((from, to), weight) in cheapest_edges(graph) {
+            if from != to {
+                overlay.insert(index[&from], index[&to], weight, None);
+            }
+        }
+
+        let mut contracted_neighbors = vec![0_i64; n];
+        let mut priorities: Vec<i64> = (0..n).map(|node| overlay.edge_difference(node)).collect();
+        let mut queue = MinHeap::<i64>::default();
+        for (node, &priority) in priorities.iter().enumerate() {
+            queue.push(node as u32, Some(priority));
+        }
+
+        let mut contracted = vec![false; n];
+        let mut rank = vec![0; n];
+        let mut up = vec![Vec::new(); n];
+        let mut down = vec![Vec::new(); n];
+        let mut shortcuts = 0;
+        let mut next_rank = 0;
+
+        while let Some(node) = queue.pop() {
+            let node = node as usize;
+            if contracted[node] {
+                continue;
+            }
+            let priority = overlay.edge_difference(node) + contracted_neighbors[node];
+            if priority > priorities[node] {
+                priorities[node] = priority;
+                queue.push(node as u32, Some(priority));
+                continue;
+            }
+
+            for (from, to, weight) in overlay.shortcuts(node) {
+                if overlay.insert(from, to, weight, Some(node)) {
+                    shortcuts += 1;
+                }
+            }
+
+            let arcs = |edges: &BTreeMap<usize, (f64, Option<usize>)>| -> Vec<Arc> {
+                edges
+                    .iter()
+                    .map(|(&target, &(weight, middle))| Arc {
+                        target,
+                        weight,
+                        middle,
+                    })
+                    .collect()
+            };
+            up[node] = arcs(&overlay.outgoing[node]);
+            down[node] = arcs(&overlay.incoming[node]);
+            for neighbor in overlay.remove(node) {
+                contracted_neighbors[neighbor] += 1;
+            }
+            contracted[node] = true;
+            rank[node] = next_rank;
+            next_rank += 1;
+        }
+
+        ContractionHierarchy {
+            ids,
+            index,
+            rank,
+            up,
+            down,
+            shortcuts,
+        }
+    }
+}
+
+/// Remaining graph during contraction, edges keyed by their other end.
+struct Overlay {
+    outgoing: Vec<BTreeMap<usize, (f64, Option<usize>)>>,
+    incoming: Vec<BTreeMap<usize, (f64, Option<usize>)>>,
+    witness_limit: usize,
+}
+
+impl Overlay {
+    /// Adds an edge, or lowers the weight of an existing one.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a new edge was added
+    fn insert(&mut self, from: usize, to: usize, weight: f64, middle: Option<usize>) -> bool {
+        if let Some(&(current, _)) = self.outgoing[from].get(&to) {
+            if weight < current {
+                self.outgoing[from].insert(to, (weight, middle));
+                self.incoming[to].insert(from, (weight, middle));
+            }
+            return false;
+        }
+        self.outgoing[from].insert(to, (weight, middle));
+        self.incoming[to].insert(from, (weight, middle));
+        true
+    }
+
+    /// Removes a node and its edges, returning its former neighbors.
+    fn remove(&mut self, node: usize) -> Vec<usize> {
+        let mut neighbors = Vec::new();
+        for (&to, _) in std::mem::take(&mut self.outgoing[node]).iter() {
+            self.incoming[to].remove(&node);
+            neighbors.push(to);
+        }
+        for (&from, _) in std::mem::take(&mut self.incoming[node]).iter() {
+            self.outgoing[from].remove(&node);
+            neighbors.push(from);
+        }
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    fn edge_difference(&self, node: usize) -> i64 {
+        let removed = self.outgoing[node].len() + self.incoming[node].len();
+        self.shortcuts(node).len() as i64 - removed as i64
+    }
+
+    /// Returns the shortcuts needed to contract a node, as `(from, to, weight)`.
+    fn shortcuts(&self, node: usize) -> Vec<(usize, usize, f64)> {
+        let mut shortcuts = Vec::new();
+        for (&from, &(first, _)) in &self.incoming[node] {
+            let limit = self.outgoing[node]
+                .iter()
+                .filter(|&(&to, _)| to != from)
+                .map(|(_, &(second, _))| first + second)
+                .fold(f64::NEG_INFINITY, f64::max);
+            if limit == f64::NEG_INFINITY {
+                continue;
+            }
+
+            let witnesses = self.witness_search(from, node, limit);
+            for (&to, &(second, _)) in &self.outgoing[node] {
+                let through = first + second;
+                if to != from && witnesses.get(&to).is_none_or(|&witness| witness > through) {
+                    shortcuts.push((from, to, through));
+                }
+            }
+        }
+        shortcuts
+    }
+
+    /// Dijkstra from `source` avoiding `skipped`, up to distance `limit`.
+    fn witness_search(&self, source: usize, skipped: usize, limit: f64) -> HashMap<usize, f64> {
+        let mut distances = HashMap::from([(source, 0.0)]);
+        let mut settled = HashSet::new();
+        let mut frontier = MinHeap::new();
+        frontier.push(source as u32, Some(0.0));
+
+        while settled.len() < self.witness_limit {
+            let Some(node) = frontier.pop() else {
+                break;
+            };
+            let node = node as usize;
+            if !settled.insert(node) {
+                continue;
+            }
+            let distance = distances[&node];
+            if distance > limit {
+                break;
+            }
+            for (&to, &(weight, _)) in &self.outgoing[node] {
+                let candidate = distance + weight;
+                if to != skipped
+                    && distances
+                        .get(&to)
+                        .is_none_or(|&current| candidate < current)
+                {
+                    distances.insert(to, candidate);
+                    frontier.push(to as u32, Some(candidate));
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::floyd_warshall;
+    use crate::preset::EmptyNode;
+
+    /// Grid of `side * side` nodes, 4-connected, with a few one-way streets.
+    fn grid(side: u32) -> Graph<EmptyNode, WeightedEdge> {
+        let mut graph = Graph::new();
+        for id in 0..side * side {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for id in 0..side * side {
+            let weight = 1.0 + ((id * 13) % 7) as f64;
+            let one_way = id % 5 == 0;
+            if id % side + 1 < side {
+                graph.add_edge(WeightedEdge::new(id, id + 1, Some(weight)));
+                if !one_way {
+                    graph.add_edge(WeightedEdge::new(id + 1, id, Some(weight)));
+                }
+            }
+            if id + side < side * side {
+                graph.add_edge(WeightedEdge::new(id + side, id, Some(weight + 0.5)));
+                if !one_way {
+                    graph.add_edge(WeightedEdge::new(id, id + side, Some(weight + 0.5)));
+                }
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn distances_match_floyd_warshall() {
+        let graph = grid(7);
+        let matrix = floyd_warshall(&graph).unwrap();
+
+        for witness_limit in [0, 500] {
+            let hierarchy = HierarchyBuilder::default()
+                .witness_limit(witness_limit)
+                .build(&graph);
+            for from in 0..49 {
+                for to in 0..49 {
+                    assert_eq!(hierarchy.distance(from, to), matrix.distance(from, to));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn witness_search_avoids_shortcuts() {
+        let graph = grid(7);
+
+        let naive = HierarchyBuilder::default().witness_limit(0).build(&graph);
+        let pruned = HierarchyBuilder::default().build(&graph);
+
+        assert!(pruned.shortcut_count() < naive.shortcut_count());
+    }
+
+    #[test]
+    fn unpacks_paths_into_original_edges() {
+        let graph = grid(6);
+        let hierarchy = HierarchyBuilder::default().build(&graph);
+        let matrix = floyd_warshall(&graph).unwrap();
+
+        for (from, to) in [(0, 35), (35, 0), (5, 30), (14, 14)] {
+            let edges = hierarchy.shortest_path_edges(from, to).unwrap();
+            let path = hierarchy.shortest_path(from, to).unwrap();
+
+            assert_eq!(path.nodes.first(), Some(&from));
+            assert_eq!(path.nodes.last(), Some(&to));
+            assert_eq!(path.nodes.len(), edges.len() + 1);
+            assert!((path.cost - matrix.distance(from, to).unwrap()).abs() < 1e-9);
+            for edge in &edges {
+                let original = graph.edges[&edge.from()]
+                    .iter()
+                    .find(|e| e.to() == edge.to())
+                    .unwrap();
+                assert_eq!(original.weight(), edge.weight());
+            }
+        }
+    }
+
+    #[test]
+    fn contracts_star_center_last() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        for id in 0..4 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for leaf in 1..4 {
+            graph.add_edge(WeightedEdge::new(0, leaf, Some(1.0)));
+            graph.add_edge(WeightedEdge::new(leaf, 0, Some(1.0)));
+        }
+        graph.add_edge(WeightedEdge::new(0, 0, Some(1.0)));
+        graph.add_edge(WeightedEdge::new(1, 0, Some(3.0)));
+
+        let hierarchy = HierarchyBuilder::default().build(&graph);
+
+        // Leaves add no shortcut, the center would add six
+        assert_eq!(hierarchy.rank(0), Some(3));
+        assert_eq!(hierarchy.shortcut_count(), 0);
+        assert_eq!(hierarchy.shortest_path(1, 2).unwrap().nodes, vec![1, 0, 2]);
+        assert_eq!(hierarchy.shortest_path(1, 2).unwrap().cost, 2.0);
+        assert_eq!(hierarchy.rank(7), None);
+    }
+
+    #[test]
+    fn unreachable_and_unknown_nodes_have_no_path() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        for id in 0..3 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        graph.add_edge(WeightedEdge::new(0, 1, Some(1.0)));
+
+        let hierarchy = HierarchyBuilder::default().build(&graph);
+
+        assert_eq!(hierarchy.distance(1, 0), None);
+        assert_eq!(hierarchy.distance(0, 2), None);
+        assert!(hierarchy.shortest_path(0, 9).is_none());
+        assert_eq!(hierarchy.shortest_path_edges(2, 2).unwrap().len(), 0);
+    }
+}
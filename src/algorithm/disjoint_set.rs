@@ -0,0 +1,121 @@
+/// Union-find structure over the indices `0..len`.
+///
+/// Uses path compression and union by size, so every operation runs in
+/// near-constant amortized time.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::DisjointSet;
+///
+/// let mut sets = DisjointSet::new(4);
+/// assert!(sets.union(0, 1));
+/// assert!(!sets.union(1, 0));
+/// assert_eq!(sets.find(0), sets.find(1));
+/// assert_ne!(sets.find(0), sets.find(2));
+/// assert_eq!(sets.set_size(1), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl DisjointSet {
+    /// Creates `len` singleton sets.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns `true` if the structure holds no element.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the representative of the set containing `element`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is out of bounds.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the elements were in different sets, `false` otherwise
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        true
+    }
+
+    /// Returns the size of the set containing `element`.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_with_singletons() {
+        let mut sets = DisjointSet::new(3);
+
+        assert_eq!(sets.len(), 3);
+        assert!((0..3).all(|i| sets.find(i) == i && sets.set_size(i) == 1));
+    }
+
+    #[test]
+    fn union_merges_transitively() {
+        let mut sets = DisjointSet::new(5);
+
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(1, 3);
+
+        assert_eq!(sets.find(0), sets.find(2));
+        assert_eq!(sets.set_size(3), 4);
+        assert_ne!(sets.find(4), sets.find(0));
+    }
+
+    #[test]
+    fn union_reports_redundant_merges() {
+        let mut sets = DisjointSet::new(2);
+
+        assert!(sets.union(0, 1));
+        assert!(!sets.union(0, 1));
+        assert!(DisjointSet::new(0).is_empty());
+    }
+}
@@ -0,0 +1,649 @@
+use crate::frontier::MinHeap;
+use crate::graph::{Cost, Edge, Graph, Node};
+use crate::preset::policies::traversal::NoTermination;
+use crate::preset::visitors::{TrackCost, TrackParent, WeightedVisitor};
+use crate::preset::{EmptyNode, WeightedEdge};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use super::DisjointSet;
+use super::adjacency::Adjacency;
+
+/// How edges are interpreted by route inspection algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Every edge is a one-way connection
+    #[default]
+    Directed,
+    /// Every edge is a two-way connection. Edges `a -> b` and `b -> a` are
+    /// paired into a single connection, so undirected graphs storing both
+    /// directions are supported.
+    Undirected,
+}
+
+/// Error reported when a graph has no Eulerian path or circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotEulerian {
+    /// The edges do not all belong to the same connected component
+    Disconnected,
+    /// Some nodes have an unsuitable degree balance, listed by ID
+    Unbalanced { nodes: Vec<u32> },
+}
+
+impl fmt::Display for NotEulerian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotEulerian::Disconnected => write!(f, "edges are not connected"),
+            NotEulerian::Unbalanced { nodes } => {
+                let nodes: Vec<String> = nodes.iter().map(u32::to_string).collect();
+                write!(f, "unbalanced degree at nodes {}", nodes.join(", "))
+            }
+        }
+    }
+}
+
+impl Error for NotEulerian {}
+
+/// A closed walk covering every edge, found by `chinese_postman`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostmanTour<C = f64> {
+    /// Node IDs of the walk, the first node being repeated at the end
+    pub walk: Vec<u32>,
+    /// Total weight of the walk, repeated edges included
+    pub cost: C,
+    /// Connections walked a second time, as `(from, to, weight)`
+    pub repeated: Vec<(u32, u32, C)>,
+}
+
+/// Builds an Eulerian circuit: a closed walk using every edge exactly once.
+///
+/// Uses Hierholzer's algorithm, starting from the smallest node ID having
+/// edges. Isolated nodes are ignored and dangling edges are dropped.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to walk
+/// * `orientation` - How edges are interpreted
+///
+/// # Returns
+///
+/// The node IDs of the walk, the first node being repeated at the end. The
+/// walk is empty when the graph has no edge.
+///
+/// # Errors
+///
+/// Returns `NotEulerian` if the edges are disconnected or if a node has
+/// different in and out degrees (odd degree when undirected).
+pub fn eulerian_circuit<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    orientation: Orientation,
+) -> Result<Vec<u32>, NotEulerian>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let network = Network::from_graph(graph, orientation);
+    network.check_connected()?;
+
+    let unbalanced = network.unbalanced();
+    if !unbalanced.is_empty() {
+        return Err(NotEulerian::Unbalanced {
+            nodes: unbalanced.iter().map(|&(i, _)| network.ids[i]).collect(),
+        });
+    }
+
+    Ok(network.walk_from(network.first_with_edges()))
+}
+
+/// Builds an Eulerian path: a walk using every edge exactly once.
+///
+/// Returns a circuit when one exists. Otherwise the path starts at the node
+/// with one more outgoing than incoming edge, or at the smallest odd degree
+/// node when undirected.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to walk
+/// * `orientation` - How edges are interpreted
+///
+/// # Returns
+///
+/// The node IDs of the walk, empty when the graph has no edge
+///
+/// # Errors
+///
+/// Returns `NotEulerian` if the edges are disconnected or if more than two
+/// nodes are unbalanced.
+pub fn eulerian_path<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    orientation: Orientation,
+) -> Result<Vec<u32>, NotEulerian>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let network = Network::from_graph(graph, orientation);
+    network.check_connected()?;
+
+    let unbalanced = network.unbalanced();
+    let start = match (orientation, unbalanced.as_slice()) {
+        (_, []) => network.first_with_edges(),
+        (Orientation::Directed, &[(a, 1), (_, -1)])
+        | (Orientation::Directed, &[(_, -1), (a, 1)]) => a,
+        (Orientation::Undirected, &[(a, _), (_, _)]) => a,
+        _ => {
+            return Err(NotEulerian::Unbalanced {
+                nodes: unbalanced.iter().map(|&(i, _)| network.ids[i]).collect(),
+            });
+        }
+    };
+
+    Ok(network.walk_from(start))
+}
+
+/// Solves the route inspection problem on an undirected weighted graph.
+///
+/// Finds the cheapest closed walk using every connection at least once,
+/// for instance to sweep every street. Odd degree nodes are paired with
+/// minimum total shortest path distance, the shortest paths being found with
+/// a `WeightedVisitor`, and the paths are walked twice. The pairing is exact
+/// for up to 20 odd degree nodes and greedy beyond.
+///
+/// Edges are interpreted as with `Orientation::Undirected`. Weights must be
+/// non-negative.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to cover
+///
+/// # Errors
+///
+/// Returns `NotEulerian::Disconnected` if the edges are disconnected.
+pub fn chinese_postman<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> Result<PostmanTour<TEdge::Weight>, NotEulerian>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let mut network = Network::from_graph(graph, Orientation::Undirected);
+    network.check_connected()?;
+
+    let odd: Vec<usize> = network.unbalanced().iter().map(|&(i, _)| i).collect();
+    let mut repeated = Vec::new();
+
+    if !odd.is_empty() {
+        // Cheapest connection between each pair, in both directions
+        let mut cheapest: BTreeMap<(u32, u32), TEdge::Weight> = BTreeMap::new();
+        for &(a, b, weight) in &network.edges {
+            let (a, b) = (network.ids[a], network.ids[b]);
+            for key in [(a, b), (b, a)] {
+                cheapest
+                    .entry(key)
+                    .and_modify(|current| {
+                        if weight.compare(current) == Ordering::Less {
+                            *current = weight;
+                        }
+                    })
+                    .or_insert(weight);
+            }
+        }
+        let mut streets = Graph::<EmptyNode, WeightedEdge<TEdge::Weight>>::new();
+        for &id in &network.ids {
+            streets.add_node(EmptyNode::new(id, None));
+        }
+        for (&(a, b), &weight) in &cheapest {
+            if a != b {
+                streets.add_edge(WeightedEdge::new(a, b, Some(weight)));
+            }
+        }
+
+        let searches: Vec<WeightedVisitor<NoTermination, TEdge::Weight>> = odd
+            .iter()
+            .map(|&i| {
+                let mut visitor = WeightedVisitor::new(NoTermination);
+                streets.traverse(network.ids[i], &mut MinHeap::default(), &mut visitor);
+                visitor
+            })
+            .collect();
+        let distance = |a: usize, b: usize| {
+            searches[a]
+                .cost_to(network.ids[odd[b]])
+                .expect("Connected nodes must reach each other")
+        };
+
+        for (a, b) in pair_odd_nodes(odd.len(), distance) {
+            let mut node = network.ids[odd[b]];
+            while let Some(parent) = searches[a].get_parent(node) {
+                let weight = cheapest[&(parent, node)];
+                repeated.push((parent, node, weight));
+                network.add_edge(network.index[&parent], network.index[&node], weight);
+                node = parent;
+            }
+        }
+    }
+
+    let walk = network.walk_from(network.first_with_edges());
+    let cost = network
+        .edges
+        .iter()
+        .fold(TEdge::Weight::zero(), |cost, &(_, _, weight)| {
+            cost.plus(weight)
+        });
+    Ok(PostmanTour {
+        walk,
+        cost,
+        repeated,
+    })
+}
+
+/// Pairs `count` nodes with minimum total distance.
+///
+/// Uses dynamic programming over subsets for up to 20 nodes, and greedily
+/// pairs closest nodes beyond.
+fn pair_odd_nodes<C: Cost>(
+    count: usize,
+    distance: impl Fn(usize, usize) -> C,
+) -> Vec<(usize, usize)> {
+    if count > 20 {
+        let mut candidates: Vec<(usize, usize)> = (0..count)
+            .flat_map(|a| (a + 1..count).map(move |b| (a, b)))
+            .collect();
+        candidates.sort_by(|&(a, b), &(c, d)| distance(a, b).compare(&distance(c, d)));
+
+        let mut paired = vec![false; count];
+        let mut pairs = Vec::new();
+        for (a, b) in candidates {
+            if !paired[a] && !paired[b] {
+                paired[a] = true;
+                paired[b] = true;
+                pairs.push((a, b));
+            }
+        }
+        return pairs;
+    }
+
+    let full = (1usize << count) - 1;
+    let mut choices: Vec<Option<(usize, usize)>> = vec![None; full + 1];
+    let mut costs: Vec<Option<C>> = vec![None; full + 1];
+    costs[0] = Some(C::zero());
+
+    for mask in 0..full {
+        let Some(cost) = costs[mask] else {
+            continue;
+        };
+        // Always pair the first free node, so each matching is built once
+        let a = (!mask).trailing_zeros() as usize;
+        for b in a + 1..count {
+            if mask & (1 << b) != 0 {
+                continue;
+            }
+            let next = mask | (1 << a) | (1 << b);
+            let candidate = cost.plus(distance(a, b));
+            if costs[next].is_none_or(|current| candidate.compare(&current) == Ordering::Less) {
+                costs[next] = Some(candidate);
+                choices[next] = Some((a, b));
+            }
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let mut mask = full;
+    while let Some((a, b)) = choices[mask] {
+        pairs.push((a, b));
+        mask &= !((1 << a) | (1 << b));
+    }
+    pairs
+}
+
+/// Edge list view of a graph for walks.
+struct Network<W> {
+    ids: Vec<u32>,
+    index: std::collections::HashMap<u32, usize>,
+    orientation: Orientation,
+    /// Connections as `(from, to, weight)`, one per traversal
+    edges: Vec<(usize, usize, W)>,
+}
+
+impl<W: Cost> Network<W> {
+    fn from_graph<TNode, TEdge>(graph: &Graph<TNode, TEdge>, orientation: Orientation) -> Self
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = W>,
+    {
+        let adjacency = Adjacency::from_graph(graph);
+        let mut edges = Vec::new();
+
+        match orientation {
+            Orientation::Directed => {
+                for (i, targets) in adjacency.successors.iter().enumerate() {
+                    edges.extend(targets.iter().map(|&(j, weight)| (i, j, weight)));
+                }
+            }
+            Orientation::Undirected => {
+                // Pair each edge with an edge in the opposite direction, cheapest first
+                let mut between: BTreeMap<(usize, usize), (Vec<W>, Vec<W>)> = BTreeMap::new();
+                for (i, targets) in adjacency.successors.iter().enumerate() {
+                    for &(j, weight) in targets {
+                        let entry = between.entry((i.min(j), i.max(j))).or_default();
+                        if i <= j {
+                            entry.0.push(weight);
+                        } else {
+                            entry.1.push(weight);
+                        }
+                    }
+                }
+                for ((a, b), (mut forward, mut backward)) in between {
+                    forward.sort_by(|x, y| x.compare(y));
+                    backward.sort_by(|x, y| x.compare(y));
+                    for k in 0..forward.len().max(backward.len()) {
+                        let weight = match (forward.get(k), backward.get(k)) {
+                            (Some(&x), Some(&y)) if y.compare(&x) == Ordering::Less => y,
+                            (Some(&x), _) => x,
+                            (None, Some(&y)) => y,
+                            (None, None) => unreachable!(),
+                        };
+                        edges.push((a, b, weight));
+                    }
+                }
+            }
+        }
+
+        Network {
+            ids: adjacency.ids,
+            index: adjacency.index,
+            orientation,
+            edges,
+        }
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        self.edges.push((from, to, weight));
+    }
+
+    /// Whether each node is an endpoint of at least one edge.
+    fn has_edges(&self) -> Vec<bool> {
+        let mut has_edges = vec![false; self.ids.len()];
+        for &(a, b, _) in &self.edges {
+            has_edges[a] = true;
+            has_edges[b] = true;
+        }
+        has_edges
+    }
+
+    fn first_with_edges(&self) -> usize {
+        self.has_edges()
+            .iter()
+            .position(|&has_edges| has_edges)
+            .unwrap_or_default()
+    }
+
+    fn check_connected(&self) -> Result<(), NotEulerian> {
+        let mut sets = DisjointSet::new(self.ids.len());
+        for &(a, b, _) in &self.edges {
+            sets.union(a, b);
+        }
+        let has_edges = self.has_edges();
+        let mut roots = (0..self.ids.len())
+            .filter(|&i| has_edges[i])
+            .map(|i| sets.find(i));
+        match roots.next() {
+            Some(root) if roots.any(|other| other != root) => Err(NotEulerian::Disconnected),
+            _ => Ok(()),
+        }
+    }
+
+    /// Nodes whose degrees prevent a circuit, with their out minus in
+    /// degree, or their degree when undirected.
+    fn unbalanced(&self) -> Vec<(usize, i64)> {
+        let mut balance = vec![0i64; self.ids.len()];
+        for &(a, b, _) in &self.edges {
+            match self.orientation {
+                Orientation::Directed => {
+                    balance[a] += 1;
+                    balance[b] -= 1;
+                }
+                Orientation::Undirected => {
+                    balance[a] += 1;
+                    balance[b] += 1;
+                }
+            }
+        }
+
+        balance
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, value)| match self.orientation {
+                Orientation::Directed => value != 0,
+                Orientation::Undirected => value % 2 != 0,
+            })
+            .collect()
+    }
+
+    /// Hierholzer's algorithm from a start node, assuming a walk exists.
+    fn walk_from(&self, start: usize) -> Vec<u32> {
+        if self.edges.is_empty() {
+            return Vec::new();
+        }
+
+        let mut incident: Vec<Vec<(usize, usize)>> = vec![Vec::new(); self.ids.len()];
+        for (e, &(a, b, _)) in self.edges.iter().enumerate() {
+            incident[a].push((e, b));
+            if self.orientation == Orientation::Undirected && a != b {
+                incident[b].push((e, a));
+            }
+        }
+
+        let mut used = vec![false; self.edges.len()];
+        let mut positions = vec![0; self.ids.len()];
+        let mut stack = vec![start];
+        let mut walk = Vec::with_capacity(self.edges.len() + 1);
+
+        while let Some(&node) = stack.last() {
+            let next = loop {
+                match incident[node].get(positions[node]) {
+                    Some(&(e, _)) if used[e] => positions[node] += 1,
+                    other => break other.copied(),
+                }
+            };
+            match next {
+                Some((e, other)) => {
+                    used[e] = true;
+                    stack.push(other);
+                }
+                None => {
+                    walk.push(self.ids[node]);
+                    stack.pop();
+                }
+            }
+        }
+
+        walk.reverse();
+        walk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+
+    fn undirected<C: Cost>(
+        node_count: u32,
+        edges: &[(u32, u32, C)],
+    ) -> Graph<EmptyNode, WeightedEdge<C>> {
+        let both: Vec<(u32, u32, C)> = edges
+            .iter()
+            .flat_map(|&(a, b, w)| [(a, b, w), (b, a, w)])
+            .collect();
+        graph(node_count, &both)
+    }
+
+    /// Checks that a walk uses every undirected connection exactly once.
+    fn assert_covers(walk: &[u32], edges: &[(u32, u32)]) {
+        let mut expected: Vec<(u32, u32)> =
+            edges.iter().map(|&(a, b)| (a.min(b), a.max(b))).collect();
+        let mut walked: Vec<(u32, u32)> = walk
+            .windows(2)
+            .map(|p| (p[0].min(p[1]), p[0].max(p[1])))
+            .collect();
+        expected.sort_unstable();
+        walked.sort_unstable();
+        assert_eq!(walked, expected);
+    }
+
+    #[test]
+    fn directed_circuit_follows_edge_directions() {
+        // Two cycles sharing node 0
+        let graph = graph(
+            4,
+            &[
+                (0, 1, 1.0),
+                (1, 0, 1.0),
+                (0, 2, 1.0),
+                (2, 3, 1.0),
+                (3, 0, 1.0),
+            ],
+        );
+
+        let walk = eulerian_circuit(&graph, Orientation::Directed).unwrap();
+
+        assert_eq!(walk.len(), 6);
+        assert_eq!(walk.first(), walk.last());
+        for pair in walk.windows(2) {
+            assert!(graph.edges[&pair[0]].iter().any(|e| e.to() == pair[1]));
+        }
+    }
+
+    #[test]
+    fn undirected_circuit_pairs_both_directions() {
+        let edges = [
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 0, 1.0),
+            (2, 3, 1.0),
+            (3, 4, 1.0),
+            (4, 2, 1.0),
+        ];
+
+        let walk = eulerian_circuit(&undirected(5, &edges), Orientation::Undirected).unwrap();
+
+        assert_eq!(walk.first(), Some(&0));
+        assert_eq!(walk.last(), Some(&0));
+        assert_covers(&walk, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]);
+    }
+
+    #[test]
+    fn path_starts_at_unbalanced_node() {
+        let directed = graph(3, &[(1, 0, 1.0), (0, 2, 1.0), (2, 1, 1.0), (1, 2, 1.0)]);
+        let walk = eulerian_path(&directed, Orientation::Directed).unwrap();
+        assert_eq!(walk.first(), Some(&1));
+        assert_eq!(walk.last(), Some(&2));
+
+        // Undirected path 0 - 1 - 2 with a triangle 1 - 3 - 4
+        let edges = [
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (1, 3, 1.0),
+            (3, 4, 1.0),
+            (4, 1, 1.0),
+        ];
+        let walk = eulerian_path(&graph(5, &edges), Orientation::Undirected).unwrap();
+        assert_eq!(walk.first(), Some(&0));
+        assert_eq!(walk.last(), Some(&2));
+        assert_covers(&walk, &[(0, 1), (1, 2), (1, 3), (3, 4), (4, 1)]);
+    }
+
+    #[test]
+    fn reports_unbalanced_nodes() {
+        let star = undirected(4, &[(0, 1, 1.0), (0, 2, 1.0), (0, 3, 1.0)]);
+
+        let error = eulerian_path(&star, Orientation::Undirected).unwrap_err();
+
+        assert_eq!(
+            error,
+            NotEulerian::Unbalanced {
+                nodes: vec![0, 1, 2, 3]
+            }
+        );
+        assert_eq!(error.to_string(), "unbalanced degree at nodes 0, 1, 2, 3");
+        assert!(eulerian_circuit(&graph(2, &[(0, 1, 1.0)]), Orientation::Directed).is_err());
+    }
+
+    #[test]
+    fn reports_disconnected_edges() {
+        // Isolated node 4 does not matter, two separate cycles do
+        let graph = undirected(5, &[(0, 1, 1.0), (1, 0, 1.0), (2, 3, 1.0)]);
+
+        assert_eq!(
+            eulerian_circuit(&graph, Orientation::Directed),
+            Err(NotEulerian::Disconnected)
+        );
+    }
+
+    #[test]
+    fn graph_without_edges_has_empty_walk() {
+        assert_eq!(
+            eulerian_circuit(&graph::<f64>(3, &[]), Orientation::Undirected),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn postman_on_eulerian_graph_repeats_nothing() {
+        let graph = undirected(3, &[(0, 1, 2u64), (1, 2, 3), (2, 0, 4)]);
+
+        let tour = chinese_postman(&graph).unwrap();
+
+        assert_eq!(tour.cost, 9);
+        assert!(tour.repeated.is_empty());
+        assert_eq!(tour.walk.len(), 4);
+    }
+
+    #[test]
+    fn postman_repeats_cheapest_pairing() {
+        // Square 0-1-2-3 with diagonal 0-2: nodes 0 and 2 are odd
+        let edges = [
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 3, 1.0),
+            (3, 0, 1.0),
+            (0, 2, 3.0),
+        ];
+
+        let tour = chinese_postman(&undirected(4, &edges)).unwrap();
+
+        assert_eq!(tour.cost, 9.0);
+        assert_eq!(tour.repeated.len(), 2);
+        assert_eq!(tour.walk.first(), tour.walk.last());
+        assert_eq!(tour.walk.len(), 8);
+    }
+
+    #[test]
+    fn postman_pairs_several_odd_nodes() {
+        // Path 0 - 1 - 2 - 3: walking it back and forth is optimal
+        let tour = chinese_postman(&graph(4, &[(0, 1, 1.0), (1, 2, 2.0), (2, 3, 3.0)])).unwrap();
+
+        assert_eq!(tour.cost, 12.0);
+        assert_eq!(tour.repeated.len(), 3);
+        assert_covers(
+            &tour.walk,
+            &[(0, 1), (1, 2), (2, 3), (0, 1), (1, 2), (2, 3)],
+        );
+    }
+
+    #[test]
+    fn exact_and_greedy_pairings_agree_on_easy_cases() {
+        let distance = |a: usize, b: usize| (a as u64).abs_diff(b as u64);
+
+        assert_eq!(pair_odd_nodes(4, distance).len(), 2);
+        assert_eq!(pair_odd_nodes(22, distance).len(), 11);
+        let cost: u64 = pair_odd_nodes(6, distance)
+            .iter()
+            .map(|&(a, b)| distance(a, b))
+            .sum();
+        assert_eq!(cost, 3);
+    }
+}
@@ -0,0 +1,482 @@
+use crate::graph::{Cost, Edge, Graph, Node};
+use crate::preset::EmptyNode;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::ops::Sub;
+
+/// An edge of a residual graph.
+///
+/// Every edge of a flow network is paired with a backward edge, stored in the
+/// adjacency list of its destination at index `reverse_index()`. The weight
+/// of a residual edge is its remaining capacity: pushing flow along an edge
+/// lowers its weight and raises the weight of its backward edge, which lets
+/// later augmentations cancel it.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ResidualEdge<C = f64> {
+    to: u32,
+    from: u32,
+    capacity: C,
+    residual: C,
+    reverse: usize,
+    forward: bool,
+}
+
+impl<C: Cost + Sub<Output = C>> ResidualEdge<C> {
+    /// Returns the capacity of the original edge, zero for backward edges.
+    pub fn capacity(&self) -> C {
+        self.capacity
+    }
+
+    /// Returns the flow going through the original edge, zero for backward edges.
+    ///
+    /// The flow of a forward edge is also the remaining capacity of its
+    /// backward edge.
+    pub fn flow(&self) -> C {
+        if self.forward {
+            self.capacity - self.residual
+        } else {
+            C::zero()
+        }
+    }
+
+    /// Returns `true` for edges of the original network, `false` for backward edges.
+    pub fn is_forward(&self) -> bool {
+        self.forward
+    }
+
+    /// Returns the index of the paired edge in the adjacency list of `to()`.
+    pub fn reverse_index(&self) -> usize {
+        self.reverse
+    }
+}
+
+impl<C: Cost + Sub<Output = C>> Edge for ResidualEdge<C> {
+    type Weight = C;
+
+    /// Creates a forward edge with the given capacity and no flow.
+    ///
+    /// The backward edge and the pairing are set by `residual_graph`.
+    fn new(from: u32, to: u32, weight: Option<C>) -> Self {
+        let capacity = weight.unwrap_or(C::one());
+        ResidualEdge {
+            from,
+            to,
+            capacity,
+            residual: capacity,
+            reverse: 0,
+            forward: true,
+        }
+    }
+    fn to(&self) -> u32 {
+        self.to
+    }
+    fn from(&self) -> u32 {
+        self.from
+    }
+    /// Returns the remaining capacity.
+    fn weight(&self) -> C {
+        self.residual
+    }
+    /// Sets the remaining capacity.
+    fn set_weight(&mut self, weight: C) {
+        self.residual = weight;
+    }
+}
+
+/// Builds the residual graph of a flow network whose edge weights are capacities.
+///
+/// Each edge is stored with its paired backward edge. Edges leading to or
+/// leaving unknown nodes are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The flow network
+pub fn residual_graph<TNode, TEdge, C>(
+    graph: &Graph<TNode, TEdge>,
+) -> Graph<EmptyNode, ResidualEdge<C>>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = C>,
+    C: Cost + Sub<Output = C>,
+{
+    let mut ids: Vec<u32> = graph.nodes.keys().copied().collect();
+    ids.sort_unstable();
+
+    let mut residual = Graph::new();
+    for &id in &ids {
+        residual.add_node(EmptyNode::new(id, None));
+        residual.edges.insert(id, Vec::new());
+    }
+
+    for &id in &ids {
+        for edge in graph.edges.get(&id).into_iter().flatten() {
+            if !graph.nodes.contains_key(&edge.to()) {
+                continue;
+            }
+            add_pair(&mut residual, edge.from(), edge.to(), edge.weight());
+        }
+    }
+    residual
+}
+
+/// Adds an edge and its backward edge to a residual graph.
+fn add_pair<C: Cost + Sub<Output = C>>(
+    residual: &mut Graph<EmptyNode, ResidualEdge<C>>,
+    from: u32,
+    to: u32,
+    capacity: C,
+) {
+    let forward_index = residual.edges.entry(from).or_default().len();
+    let backward_index = residual.edges.entry(to).or_default().len() + usize::from(from == to);
+
+    let mut forward = ResidualEdge::new(from, to, Some(capacity));
+    forward.reverse = backward_index;
+    let backward = ResidualEdge {
+        from: to,
+        to: from,
+        capacity: C::zero(),
+        residual: C::zero(),
+        reverse: forward_index,
+        forward: false,
+    };
+
+    residual.add_edge(forward);
+    residual.add_edge(backward);
+}
+
+/// Result of a maximum flow computation.
+#[derive(Debug)]
+pub struct MaxFlow<C = f64> {
+    source: u32,
+    sink: u32,
+    value: C,
+    residual: Graph<EmptyNode, ResidualEdge<C>>,
+}
+
+impl<C: Cost + Sub<Output = C>> MaxFlow<C> {
+    /// Returns the total flow sent from the source to the sink.
+    pub fn value(&self) -> C {
+        self.value
+    }
+
+    /// Returns the flow of every edge of the network as `(from, to, flow)`,
+    /// ordered by source node ID then by insertion order.
+    pub fn flows(&self) -> Vec<(u32, u32, C)> {
+        let mut ids: Vec<u32> = self.residual.edges.keys().copied().collect();
+        ids.sort_unstable();
+
+        ids.iter()
+            .flat_map(|id| &self.residual.edges[id])
+            .filter(|edge| edge.is_forward())
+            .map(|edge| (edge.from(), edge.to(), edge.flow()))
+            .collect()
+    }
+
+    /// Returns the source side of a minimum cut: the nodes still reachable
+    /// from the source in the residual graph, sorted.
+    ///
+    /// Every other node is on the sink side.
+    pub fn source_side(&self) -> Vec<u32> {
+        if !self.residual.nodes.contains_key(&self.source) {
+            return Vec::new();
+        }
+        let levels = levels(&self.residual, self.source);
+        let mut side: Vec<u32> = levels.keys().copied().collect();
+        side.sort_unstable();
+        side
+    }
+
+    /// Returns the edges of a minimum cut as `(from, to, capacity)`.
+    ///
+    /// These edges go from the source side to the sink side, are saturated,
+    /// and their capacities sum up to the maximum flow value.
+    pub fn cut_edges(&self) -> Vec<(u32, u32, C)> {
+        let side: BTreeSet<u32> = self.source_side().into_iter().collect();
+
+        side.iter()
+            .flat_map(|id| self.residual.edges.get(id).into_iter().flatten())
+            .filter(|edge| edge.is_forward() && !side.contains(&edge.to()))
+            .map(|edge| (edge.from(), edge.to(), edge.capacity()))
+            .collect()
+    }
+
+    /// Returns the source and sink of the flow.
+    pub fn terminals(&self) -> (u32, u32) {
+        (self.source, self.sink)
+    }
+
+    /// Returns the residual graph left by the flow.
+    pub fn residual(&self) -> &Graph<EmptyNode, ResidualEdge<C>> {
+        &self.residual
+    }
+
+    /// Consumes the result, returning the residual graph left by the flow.
+    pub fn into_residual(self) -> Graph<EmptyNode, ResidualEdge<C>> {
+        self.residual
+    }
+}
+
+/// Computes a maximum flow from `source` to `sink` with Dinic's algorithm.
+///
+/// Edge weights are capacities. Each phase builds a level graph with a
+/// breadth-first search on the residual graph, then saturates it with
+/// augmenting paths that only move one level forward.
+///
+/// # Arguments
+///
+/// * `graph` - The flow network
+/// * `source` - The node ID emitting the flow
+/// * `sink` - The node ID receiving the flow
+pub fn dinic<TNode, TEdge, C>(graph: &Graph<TNode, TEdge>, source: u32, sink: u32) -> MaxFlow<C>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = C>,
+    C: Cost + Sub<Output = C>,
+{
+    let mut residual = residual_graph(graph);
+    let value = augment(&mut residual, source, sink);
+
+    MaxFlow {
+        source,
+        sink,
+        value,
+        residual,
+    }
+}
+
+/// Pushes as much flow as possible through a residual graph.
+///
+/// # Returns
+///
+/// The additional flow sent from `source` to `sink`
+pub(crate) fn augment<C: Cost + Sub<Output = C>>(
+    residual: &mut Graph<EmptyNode, ResidualEdge<C>>,
+    source: u32,
+    sink: u32,
+) -> C {
+    let mut total = C::zero();
+    if source == sink
+        || !residual.nodes.contains_key(&source)
+        || !residual.nodes.contains_key(&sink)
+    {
+        return total;
+    }
+
+    loop {
+        let levels = levels(residual, source);
+        if !levels.contains_key(&sink) {
+            return total;
+        }
+
+        // Index of the next edge to try from each node during this phase
+        let mut next_edges: HashMap<u32, usize> = HashMap::new();
+        let mut path: Vec<(u32, usize)> = Vec::new();
+        let mut current = source;
+
+        loop {
+            if current == sink {
+                let bottleneck = path
+                    .iter()
+                    .map(|&(node, index)| residual.edges[&node][index].weight())
+                    .min_by(|a, b| a.compare(b))
+                    .expect("Path to the sink cannot be empty");
+
+                for &(node, index) in &path {
+                    push_flow(residual, node, index, bottleneck);
+                }
+                total = total.plus(bottleneck);
+                path.clear();
+                current = source;
+                continue;
+            }
+
+            let edges = &residual.edges[&current];
+            let position = next_edges.entry(current).or_insert(0);
+            let advance = edges[*position..].iter().position(|edge| {
+                is_open(edge) && levels.get(&edge.to()) == Some(&(levels[&current] + 1))
+            });
+
+            match advance {
+                Some(offset) => {
+                    *position += offset;
+                    path.push((current, *position));
+                    current = edges[*position].to();
+                }
+                None => {
+                    *position = edges.len();
+                    // Dead end: retreat and skip the edge leading here
+                    let Some((previous, index)) = path.pop() else {
+                        break;
+                    };
+                    next_edges.insert(previous, index + 1);
+                    current = previous;
+                }
+            }
+        }
+    }
+}
+
+fn is_open<C: Cost + Sub<Output = C>>(edge: &ResidualEdge<C>) -> bool {
+    edge.weight().compare(&C::zero()) == Ordering::Greater
+}
+
+/// Moves flow along an edge, updating its paired backward edge.
+fn push_flow<C: Cost + Sub<Output = C>>(
+    residual: &mut Graph<EmptyNode, ResidualEdge<C>>,
+    node: u32,
+    index: usize,
+    amount: C,
+) {
+    let edges = residual.edges.get_mut(&node).unwrap();
+    let edge = &mut edges[index];
+    edge.set_weight(edge.weight() - amount);
+    let (to, reverse) = (edge.to(), edge.reverse_index());
+
+    let paired = &mut residual.edges.get_mut(&to).unwrap()[reverse];
+    paired.set_weight(paired.weight().plus(amount));
+}
+
+/// Breadth-first distances from the source along open residual edges.
+fn levels<C: Cost + Sub<Output = C>>(
+    residual: &Graph<EmptyNode, ResidualEdge<C>>,
+    source: u32,
+) -> HashMap<u32, usize> {
+    let mut levels = HashMap::from([(source, 0)]);
+    let mut pending = VecDeque::from([source]);
+
+    while let Some(node) = pending.pop_front() {
+        for edge in residual.edges.get(&node).into_iter().flatten() {
+            if is_open(edge) && !levels.contains_key(&edge.to()) {
+                levels.insert(edge.to(), levels[&node] + 1);
+                pending.push_back(edge.to());
+            }
+        }
+    }
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+    use crate::preset::WeightedEdge;
+
+    /// Classic network with a maximum flow of 23 from 0 to 5.
+    fn network() -> Graph<EmptyNode, WeightedEdge<u64>> {
+        graph(
+            6,
+            &[
+                (0, 1, 16),
+                (0, 2, 13),
+                (1, 2, 10),
+                (2, 1, 4),
+                (1, 3, 12),
+                (3, 2, 9),
+                (2, 4, 14),
+                (4, 3, 7),
+                (3, 5, 20),
+                (4, 5, 4),
+            ],
+        )
+    }
+
+    #[test]
+    fn residual_edges_are_paired() {
+        let residual = residual_graph(&graph(2, &[(0, 1, 3.0)]));
+
+        let forward = residual.edges[&0][0];
+        let backward = residual.edges[&1][forward.reverse_index()];
+        assert_eq!(forward.weight(), 3.0);
+        assert!(forward.is_forward());
+        assert_eq!(backward.weight(), 0.0);
+        assert!(!backward.is_forward());
+        assert_eq!(residual.edges[&0][backward.reverse_index()].to(), 1);
+    }
+
+    #[test]
+    fn backward_edges_carry_no_flow() {
+        let flow = dinic(&network(), 0, 5);
+        let residual = flow.residual();
+
+        for edge in residual.edges.values().flatten() {
+            let paired = residual.edges[&edge.to()][edge.reverse_index()];
+            if edge.is_forward() {
+                assert_eq!(edge.flow(), paired.weight());
+            } else {
+                assert_eq!(edge.flow(), 0);
+                assert_eq!(paired.flow(), edge.weight());
+            }
+        }
+    }
+
+    #[test]
+    fn computes_maximum_flow() {
+        let flow = dinic(&network(), 0, 5);
+
+        assert_eq!(flow.value(), 23);
+        assert_eq!(flow.terminals(), (0, 5));
+    }
+
+    #[test]
+    fn flows_respect_capacities_and_conservation() {
+        let flow = dinic(&network(), 0, 5);
+        let flows = flow.flows();
+
+        assert_eq!(flows.len(), 10);
+        let mut balance: HashMap<u32, i64> = HashMap::new();
+        for (from, to, amount) in flows {
+            let capacity = network().edges[&from]
+                .iter()
+                .find(|e| e.to() == to)
+                .unwrap()
+                .weight();
+            assert!(amount <= capacity);
+            *balance.entry(from).or_default() -= amount as i64;
+            *balance.entry(to).or_default() += amount as i64;
+        }
+        assert_eq!(balance[&0], -23);
+        assert_eq!(balance[&5], 23);
+        assert!((1..5).all(|id| balance[&id] == 0));
+    }
+
+    #[test]
+    fn minimum_cut_matches_flow_value() {
+        let flow = dinic(&network(), 0, 5);
+
+        assert_eq!(flow.source_side(), vec![0, 1, 2, 4]);
+        let cut = flow.cut_edges();
+        assert_eq!(cut.iter().map(|&(_, _, c)| c).sum::<u64>(), 23);
+        assert_eq!(cut, vec![(1, 3, 12), (4, 3, 7), (4, 5, 4)]);
+    }
+
+    #[test]
+    fn parallel_edges_add_up() {
+        let flow = dinic(&graph(2, &[(0, 1, 1.5), (0, 1, 2.0)]), 0, 1);
+
+        assert_eq!(flow.value(), 3.5);
+    }
+
+    #[test]
+    fn disconnected_sink_receives_no_flow() {
+        let flow = dinic(&graph(3, &[(0, 1, 5.0)]), 0, 2);
+
+        assert_eq!(flow.value(), 0.0);
+        assert_eq!(flow.source_side(), vec![0, 1]);
+        assert!(flow.cut_edges().is_empty());
+    }
+
+    #[test]
+    fn degenerate_terminals_receive_no_flow() {
+        assert_eq!(dinic(&network(), 0, 0).value(), 0);
+        assert_eq!(dinic(&network(), 0, 42).value(), 0);
+    }
+
+    #[test]
+    fn residual_graph_can_be_augmented_again() {
+        let mut residual = dinic(&graph(3, &[(0, 1, 2u64), (1, 2, 5)]), 0, 2).into_residual();
+
+        add_pair(&mut residual, 0, 1, 3);
+
+        assert_eq!(augment(&mut residual, 0, 2), 3);
+    }
+}
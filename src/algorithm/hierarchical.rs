@@ -0,0 +1,783 @@
+use crate::frontier::{Frontier, Instrumented, MinHeap};
+use crate::graph::{Edge, Graph, ImplicitGraph, Node};
+use crate::preset::policies::traversal::GoalReached;
+use crate::preset::samplers::Grid2D;
+use crate::preset::visitors::{TrackCost, TrackParent, WeightedVisitor};
+use crate::preset::{EmptyNode, WeightedEdge};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use super::RankedPath;
+
+/// Result of a hierarchical search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HierarchicalPath {
+    /// Every cell of the refined path, from start to goal, and its cost
+    pub path: RankedPath,
+    /// The nodes of the abstract path: start, transitions crossed and goal
+    pub waypoints: Vec<u32>,
+    /// Number of nodes popped from the frontier by the abstract search
+    pub expanded: usize,
+}
+
+/// Cluster-based abstraction of a grid graph for HPA* queries.
+///
+/// The grid is split into square clusters of `cluster_size` cells. Along
+/// each border between two clusters, consecutive crossing edges form an
+/// entrance, and one or two of them, depending on its length, are kept as
+/// transitions. The abstract graph links transitions across borders and,
+/// inside a cluster, with their distance through that cluster.
+///
+/// Queries connect the start and goal to the transitions of their
+/// clusters, search the abstract graph and refine each abstract edge into
+/// cells. Paths are found whenever the goal is reachable, but are not
+/// always the shortest ones.
+///
+/// The cell graph must be sampled from the grid with `Grid2DSampler`, so
+/// that cell `(row, col)` has ID `row * width + col`; blocked cells are the
+/// ones missing from it. When cells change, the graph is sampled again and
+/// only the clusters containing them are invalidated.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::HierarchicalGrid;
+/// use hodos::builder::GraphBuilder;
+/// use hodos::preset::policies::structural::DenyDanglingEdge;
+/// use hodos::preset::policies::value::DenyNodeValue;
+/// use hodos::preset::samplers::Grid2DSampler;
+///
+/// let mut terrain = vec![vec!['.'; 8]; 8];
+/// let sample = |terrain: &Vec<Vec<char>>| {
+///     GraphBuilder::new(
+///         DenyDanglingEdge::default(),
+///         DenyNodeValue::with_denied_values(vec!['#']),
+///         Grid2DSampler::new(),
+///     )
+///     .build(terrain)
+/// };
+///
+/// let graph = sample(&terrain);
+/// let mut hierarchy = HierarchicalGrid::new(&terrain, &graph, 4);
+/// assert_eq!(hierarchy.find_path(&graph, 0, 63).unwrap().path.cost, 14.0);
+///
+/// // Wall off the top left cluster
+/// for i in 0..4 {
+///     terrain[3][i] = '#';
+///     terrain[i][3] = '#';
+/// }
+/// let graph = sample(&terrain);
+/// hierarchy.invalidate(&graph, hierarchy.cluster_of(24).unwrap());
+/// assert_eq!(hierarchy.find_path(&graph, 0, 63), None);
+/// ```
+#[derive(Debug)]
+pub struct HierarchicalGrid {
+    width: usize,
+    height: usize,
+    cluster_size: usize,
+    /// Number of clusters per row
+    columns: usize,
+    /// Number of clusters per column
+    rows: usize,
+    /// Crossing edges kept between each pair of clusters, smaller index first
+    entrances: BTreeMap<(usize, usize), Vec<WeightedEdge>>,
+    /// Transition cells of each cluster
+    transitions: Vec<BTreeSet<u32>>,
+    /// Edges between the transitions of each cluster
+    intra: Vec<Vec<WeightedEdge>>,
+    abstract_graph: Graph<EmptyNode, WeightedEdge>,
+}
+
+impl HierarchicalGrid {
+    /// Builds the abstraction of a grid graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid the graph was sampled from, giving its dimensions
+    /// * `graph` - The cell graph sampled with `Grid2DSampler`
+    /// * `cluster_size` - Side of the square clusters, at least 1
+    pub fn new<T, TNode, TEdge>(
+        grid: &Grid2D<T>,
+        graph: &Graph<TNode, TEdge>,
+        cluster_size: usize,
+    ) -> Self
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let width = grid.first().map_or(0, Vec::len);
+        let height = grid.len();
+        let cluster_size = cluster_size.max(1);
+        let columns = width.div_ceil(cluster_size);
+        let rows = height.div_ceil(cluster_size);
+
+        let mut hierarchy = HierarchicalGrid {
+            width,
+            height,
+            cluster_size,
+            columns,
+            rows,
+            entrances: BTreeMap::new(),
+            transitions: vec![BTreeSet::new(); columns * rows],
+            intra: vec![Vec::new(); columns * rows],
+            abstract_graph: Graph::new(),
+        };
+
+        for cluster in 0..hierarchy.cluster_count() {
+            for neighbor in hierarchy.adjacent_clusters(cluster) {
+                if cluster < neighbor {
+                    let kept = hierarchy.entrance_edges(graph, cluster, neighbor);
+                    hierarchy.entrances.insert((cluster, neighbor), kept);
+                }
+            }
+        }
+        for cluster in 0..hierarchy.cluster_count() {
+            hierarchy.transitions[cluster] = hierarchy.collect_transitions(cluster);
+            hierarchy.connect_cluster(graph, cluster);
+        }
+        hierarchy.rebuild_abstract_graph();
+        hierarchy
+    }
+
+    /// Returns the side of the clusters.
+    pub fn cluster_size(&self) -> usize {
+        self.cluster_size
+    }
+
+    /// Returns the number of clusters.
+    pub fn cluster_count(&self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// Returns the cluster containing a cell, `None` outside the grid.
+    pub fn cluster_of(&self, cell: u32) -> Option<usize> {
+        let (row, col) = self.position(cell)?;
+        Some((row / self.cluster_size) * self.columns + col / self.cluster_size)
+    }
+
+    /// Returns the transition cells of a cluster, sorted.
+    pub fn transitions(&self, cluster: usize) -> Vec<u32> {
+        self.transitions
+            .get(cluster)
+            .map_or_else(Vec::new, |cells| cells.iter().copied().collect())
+    }
+
+    /// Returns the abstract graph linking every transition.
+    pub fn abstract_graph(&self) -> &Graph<EmptyNode, WeightedEdge> {
+        &self.abstract_graph
+    }
+
+    /// Recomputes the entrances and distances of a cluster after its cells changed.
+    ///
+    /// Entrances on every border of the cluster are recomputed, so adjacent
+    /// clusters whose transitions changed have their distances recomputed
+    /// too. Other clusters are left untouched, and only the abstract edges
+    /// of the recomputed entrances and clusters are replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The cell graph sampled again from the updated grid
+    /// * `cluster` - The cluster whose cells changed
+    pub fn invalidate<TNode, TEdge>(&mut self, graph: &Graph<TNode, TEdge>, cluster: usize)
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        if cluster >= self.cluster_count() {
+            return;
+        }
+
+        let neighbors = self.adjacent_clusters(cluster);
+        let previous: BTreeSet<u32> = neighbors
+            .iter()
+            .chain([&cluster])
+            .flat_map(|&c| self.transitions[c].iter().copied())
+            .collect();
+        for &neighbor in &neighbors {
+            let pair = (cluster.min(neighbor), cluster.max(neighbor));
+            let kept = self.entrance_edges(graph, pair.0, pair.1);
+            self.entrances.insert(pair, kept);
+        }
+
+        self.transitions[cluster] = self.collect_transitions(cluster);
+        self.connect_cluster(graph, cluster);
+        let mut reconnected = vec![cluster];
+        for &neighbor in &neighbors {
+            let transitions = self.collect_transitions(neighbor);
+            if transitions != self.transitions[neighbor] {
+                self.transitions[neighbor] = transitions;
+                self.connect_cluster(graph, neighbor);
+                reconnected.push(neighbor);
+            }
+        }
+        self.update_abstract_graph(cluster, &neighbors, &reconnected, &previous);
+    }
+
+    /// Finds a path between two cells through the abstract graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The cell graph the abstraction is up to date with
+    /// * `start` - ID of the start cell
+    /// * `goal` - ID of the goal cell
+    ///
+    /// # Returns
+    ///
+    /// The refined path, or `None` if either cell is missing from the graph
+    /// or the goal cannot be reached
+    pub fn find_path<TNode, TEdge>(
+        &self,
+        graph: &Graph<TNode, TEdge>,
+        start: u32,
+        goal: u32,
+    ) -> Option<HierarchicalPath>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        if !graph.nodes.contains_key(&start) || !graph.nodes.contains_key(&goal) {
+            return None;
+        }
+        let (start_cluster, goal_cluster) = (self.cluster_of(start)?, self.cluster_of(goal)?);
+
+        // Temporary arcs connecting the start and goal to their clusters
+        let (from_start, _) = self.local_search(graph, start_cluster, start, false);
+        let (to_goal, _) = self.local_search(graph, goal_cluster, goal, true);
+        let mut start_arcs: BTreeMap<u32, f64> = self.transitions[start_cluster]
+            .iter()
+            .filter_map(|&cell| Some((cell, *from_start.get(&cell)?)))
+            .collect();
+        if let Some(&direct) = from_start.get(&goal) {
+            start_arcs.insert(goal, direct);
+        }
+        let goal_arcs: HashMap<u32, f64> = self.transitions[goal_cluster]
+            .iter()
+            .filter_map(|&cell| Some((cell, *to_goal.get(&cell)?)))
+            .collect();
+
+        let search = ImplicitGraph::new(|id: u32| {
+            let mut targets: BTreeMap<u32, f64> = BTreeMap::new();
+            let mut add = |to: u32, weight: f64| {
+                let best = targets.entry(to).or_insert(weight);
+                *best = best.min(weight);
+            };
+            for edge in self.abstract_graph.edges.get(&id).into_iter().flatten() {
+                add(edge.to(), edge.weight());
+            }
+            if id == start {
+                for (&to, &weight) in &start_arcs {
+                    add(to, weight);
+                }
+            }
+            if let Some(&weight) = goal_arcs.get(&id) {
+                add(goal, weight);
+            }
+            targets.remove(&id);
+
+            targets
+                .into_iter()
+                .map(|(to, weight)| WeightedEdge::new(id, to, Some(weight)))
+                .collect()
+        });
+        let mut visitor = WeightedVisitor::new(GoalReached::new(goal));
+        let mut frontier = Instrumented::<MinHeap>::new();
+        search.traverse(start, &mut frontier, &mut visitor);
+        let cost = visitor.cost_to(goal)?;
+
+        let mut waypoints = vec![goal];
+        while let Some(parent) = visitor.get_parent(waypoints[waypoints.len() - 1]) {
+            waypoints.push(parent);
+        }
+        waypoints.reverse();
+
+        let mut nodes = vec![start];
+        for pair in waypoints.windows(2) {
+            let cluster = self.cluster_of(pair[0])?;
+            if self.cluster_of(pair[1]) == Some(cluster) {
+                nodes.extend(self.refine(graph, cluster, pair[0], pair[1])?);
+            } else {
+                nodes.push(pair[1]);
+            }
+        }
+
+        Some(HierarchicalPath {
+            path: RankedPath { nodes, cost },
+            waypoints,
+            expanded: frontier.stats().pops,
+        })
+    }
+
+    fn position(&self, cell: u32) -> Option<(usize, usize)> {
+        let cell = cell as usize;
+        (self.width > 0 && cell < self.width * self.height)
+            .then(|| (cell / self.width, cell % self.width))
+    }
+
+    /// Returns the IDs of the cells of a cluster, in increasing order.
+    fn cells(&self, cluster: usize) -> impl Iterator<Item = u32> + '_ {
+        let (top, left) = (
+            (cluster / self.columns) * self.cluster_size,
+            (cluster % self.columns) * self.cluster_size,
+        );
+        let bottom = (top + self.cluster_size).min(self.height);
+        let right = (left + self.cluster_size).min(self.width);
+        (top..bottom)
+            .flat_map(move |row| (left..right).map(move |col| (row * self.width + col) as u32))
+    }
+
+    /// Returns the clusters around a cluster, diagonals included.
+    fn adjacent_clusters(&self, cluster: usize) -> Vec<usize> {
+        let (row, col) = (
+            (cluster / self.columns) as i64,
+            (cluster % self.columns) as i64,
+        );
+        let mut adjacent = Vec::new();
+        for dr in -1..=1 {
+            for dc in -1..=1 {
+                let (r, c) = (row + dr, col + dc);
+                if (dr, dc) != (0, 0)
+                    && (0..self.rows as i64).contains(&r)
+                    && (0..self.columns as i64).contains(&c)
+                {
+                    adjacent.push(r as usize * self.columns + c as usize);
+                }
+            }
+        }
+        adjacent
+    }
+
+    /// Selects the crossing edges kept between two clusters.
+    ///
+    /// Crossings are grouped into entrances, runs in which both ends move
+    /// by at most one cell from one crossing to the next, so that the cells
+    /// of an entrance are connected on each side. The middle crossing of
+    /// short entrances is kept, and both extremities of entrances spanning
+    /// six crossings or more.
+    fn entrance_edges<TNode, TEdge>(
+        &self,
+        graph: &Graph<TNode, TEdge>,
+        first: usize,
+        second: usize,
+    ) -> Vec<WeightedEdge>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        // Cheapest weight of each directed crossing edge
+        let mut weights: BTreeMap<(u32, u32), f64> = BTreeMap::new();
+        for (from, to) in [(first, second), (second, first)] {
+            for cell in self.cells(from).filter(|c| graph.nodes.contains_key(c)) {
+                for edge in graph.edges.get(&cell).into_iter().flatten() {
+                    if self.cluster_of(edge.to()) == Some(to)
+                        && graph.nodes.contains_key(&edge.to())
+                    {
+                        let weight = weights.entry((cell, edge.to())).or_insert(edge.weight());
+                        *weight = weight.min(edge.weight());
+                    }
+                }
+            }
+        }
+
+        // Crossings as (cell in first, cell in second), in either direction
+        let crossings: BTreeSet<(u32, u32)> = weights
+            .keys()
+            .map(|&(from, to)| {
+                if self.cluster_of(from) == Some(first) {
+                    (from, to)
+                } else {
+                    (to, from)
+                }
+            })
+            .collect();
+
+        let mut entrances: Vec<Vec<(u32, u32)>> = Vec::new();
+        for crossing in crossings {
+            match entrances.last_mut() {
+                Some(run) if self.touches(run[run.len() - 1], crossing) => run.push(crossing),
+                _ => entrances.push(vec![crossing]),
+            }
+        }
+
+        let mut kept = Vec::new();
+        for run in entrances {
+            let chosen = if run.len() >= 6 {
+                vec![run[0], run[run.len() - 1]]
+            } else {
+                vec![run[run.len() / 2]]
+            };
+            for (a, b) in chosen {
+                for (from, to) in [(a, b), (b, a)] {
+                    if let Some(&weight) = weights.get(&(from, to)) {
+                        kept.push(WeightedEdge::new(from, to, Some(weight)));
+                    }
+                }
+            }
+        }
+        kept
+    }
+
+    /// Whether two crossings have both ends at most one cell apart.
+    fn touches(&self, a: (u32, u32), b: (u32, u32)) -> bool {
+        let near = |x: u32, y: u32| match (self.position(x), self.position(y)) {
+            (Some((r1, c1)), Some((r2, c2))) => r1.abs_diff(r2) <= 1 && c1.abs_diff(c2) <= 1,
+            _ => false,
+        };
+        near(a.0, b.0) && near(a.1, b.1)
+    }
+
+    /// Collects the cells of a cluster used by the kept crossing edges.
+    fn collect_transitions(&self, cluster: usize) -> BTreeSet<u32> {
+        self.entrances
+            .iter()
+            .filter(|((a, b), _)| *a == cluster || *b == cluster)
+            .flat_map(|(_, edges)| edges.iter().flat_map(|e| [e.from(), e.to()]))
+            .filter(|&cell| self.cluster_of(cell) == Some(cluster))
+            .collect()
+    }
+
+    /// Computes the distances between the transitions of a cluster.
+    fn connect_cluster<TNode, TEdge>(&mut self, graph: &Graph<TNode, TEdge>, cluster: usize)
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let mut edges = Vec::new();
+        for &from in &self.transitions[cluster] {
+            let (distances, _) = self.local_search(graph, cluster, from, false);
+            for &to in &self.transitions[cluster] {
+                if let Some(&distance) = distances.get(&to).filter(|_| to != from) {
+                    edges.push(WeightedEdge::new(from, to, Some(distance)));
+                }
+            }
+        }
+        self.intra[cluster] = edges;
+    }
+
+    fn rebuild_abstract_graph(&mut self) {
+        let mut abstract_graph = Graph::new();
+        for cell in self.transitions.iter().flatten() {
+            abstract_graph.add_node(EmptyNode::new(*cell, None));
+        }
+        for edge in self.entrances.values().chain(&self.intra).flatten() {
+            abstract_graph.add_edge(*edge);
+        }
+        self.abstract_graph = abstract_graph;
+    }
+
+    /// Replaces the abstract edges around an invalidated cluster.
+    ///
+    /// Edges of the entrances between `cluster` and its `neighbors`, and the
+    /// edges inside the `reconnected` clusters, are removed and added back
+    /// from their recomputed values. Only the outgoing edges of the
+    /// `previous` transitions of these clusters are scanned, as every
+    /// removed edge starts from one of them.
+    fn update_abstract_graph(
+        &mut self,
+        cluster: usize,
+        neighbors: &[usize],
+        reconnected: &[usize],
+        previous: &BTreeSet<u32>,
+    ) {
+        for &cell in previous {
+            if let Some(mut edges) = self.abstract_graph.edges.remove(&cell) {
+                edges.retain(|edge| {
+                    match (self.cluster_of(edge.from()), self.cluster_of(edge.to())) {
+                        (Some(a), Some(b)) if a == b => !reconnected.contains(&a),
+                        (Some(a), Some(b)) => a != cluster && b != cluster,
+                        _ => false,
+                    }
+                });
+                if !edges.is_empty() {
+                    self.abstract_graph.edges.insert(cell, edges);
+                }
+            }
+            let kept = self
+                .cluster_of(cell)
+                .is_some_and(|c| self.transitions[c].contains(&cell));
+            if !kept {
+                self.abstract_graph.nodes.remove(&cell);
+            }
+        }
+
+        for &c in neighbors.iter().chain([&cluster]) {
+            for &cell in &self.transitions[c] {
+                self.abstract_graph.add_node(EmptyNode::new(cell, None));
+            }
+        }
+        let entrances = neighbors
+            .iter()
+            .filter_map(|&n| self.entrances.get(&(cluster.min(n), cluster.max(n))));
+        let intra = reconnected.iter().map(|&c| &self.intra[c]);
+        for edge in entrances.chain(intra).flatten() {
+            self.abstract_graph.add_edge(*edge);
+        }
+    }
+
+    /// Dijkstra from a cell, staying inside its cluster.
+    ///
+    /// Follows the edges backwards if `reversed` is set, giving distances
+    /// to the cell instead of from it.
+    ///
+    /// # Returns
+    ///
+    /// The distance and the parent of each reached cell
+    fn local_search<TNode, TEdge>(
+        &self,
+        graph: &Graph<TNode, TEdge>,
+        cluster: usize,
+        source: u32,
+        reversed: bool,
+    ) -> (HashMap<u32, f64>, HashMap<u32, u32>)
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let mut arcs: HashMap<u32, Vec<(u32, f64)>> = HashMap::new();
+        for cell in self.cells(cluster).filter(|c| graph.nodes.contains_key(c)) {
+            for edge in graph.edges.get(&cell).into_iter().flatten() {
+                let to = edge.to();
+                if self.cluster_of(to) == Some(cluster) && graph.nodes.contains_key(&to) {
+                    let (tail, head) = if reversed { (to, cell) } else { (cell, to) };
+                    arcs.entry(tail).or_default().push((head, edge.weight()));
+                }
+            }
+        }
+
+        let mut distances = HashMap::from([(source, 0.0)]);
+        let mut parents = HashMap::new();
+        let mut settled = HashSet::new();
+        let mut frontier = MinHeap::new();
+        frontier.push(source, Some(0.0));
+
+        while let Some(cell) = frontier.pop() {
+            if !settled.insert(cell) {
+                continue;
+            }
+            let distance = distances[&cell];
+            for &(next, weight) in arcs.get(&cell).into_iter().flatten() {
+                let candidate = distance + weight;
+                if distances.get(&next).is_none_or(|&known| candidate < known) {
+                    distances.insert(next, candidate);
+                    parents.insert(next, cell);
+                    frontier.push(next, Some(candidate));
+                }
+            }
+        }
+        (distances, parents)
+    }
+
+    /// Expands an abstract edge inside a cluster into the cells after `from`.
+    fn refine<TNode, TEdge>(
+        &self,
+        graph: &Graph<TNode, TEdge>,
+        cluster: usize,
+        from: u32,
+        to: u32,
+    ) -> Option<Vec<u32>>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let (_, parents) = self.local_search(graph, cluster, from, false);
+        let mut cells = vec![to];
+        while cells[cells.len() - 1] != from {
+            cells.push(*parents.get(&cells[cells.len() - 1])?);
+        }
+        cells.pop();
+        cells.reverse();
+        Some(cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::GraphBuilder;
+    use crate::preset::policies::structural::DenyDanglingEdge;
+    use crate::preset::policies::traversal::NoTermination;
+    use crate::preset::policies::value::DenyNodeValue;
+    use crate::preset::samplers::Grid2DSampler;
+    use crate::preset::{DataNode, UnweightedEdge};
+    use crate::rng::SeededRng;
+
+    type Cells = Graph<DataNode<char>, UnweightedEdge>;
+
+    fn sample(terrain: &Grid2D<char>, connect_eight: bool) -> Cells {
+        let sampler = if connect_eight {
+            Grid2DSampler::with_connect_eight()
+        } else {
+            Grid2DSampler::new()
+        };
+        GraphBuilder::new(
+            DenyDanglingEdge::default(),
+            DenyNodeValue::with_denied_values(vec!['#']),
+            sampler,
+        )
+        .build(terrain)
+    }
+
+    fn random_terrain(rng: &mut SeededRng, width: usize, height: usize) -> Grid2D<char> {
+        (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.next_below(4) == 0 { '#' } else { '.' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn dijkstra(graph: &Cells, start: u32) -> WeightedVisitor<NoTermination> {
+        let mut visitor = WeightedVisitor::new(NoTermination);
+        graph.traverse(start, &mut MinHeap::new(), &mut visitor);
+        visitor
+    }
+
+    fn assert_valid(graph: &Cells, found: &HierarchicalPath) {
+        let mut cost = 0.0;
+        for pair in found.path.nodes.windows(2) {
+            let edge = graph.edges[&pair[0]].iter().find(|e| e.to() == pair[1]);
+            cost += edge.expect("consecutive cells are connected").weight();
+        }
+        assert_eq!(cost, found.path.cost);
+    }
+
+    fn edges(hierarchy: &HierarchicalGrid) -> Vec<(u32, u32, u64)> {
+        let mut edges: Vec<_> = hierarchy
+            .abstract_graph()
+            .get_edges()
+            .into_iter()
+            .map(|e| (e.from(), e.to(), e.weight().to_bits()))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn partitions_the_grid_into_clusters() {
+        let terrain = vec![vec!['.'; 10]; 7];
+        let graph = sample(&terrain, false);
+        let hierarchy = HierarchicalGrid::new(&terrain, &graph, 4);
+
+        assert_eq!(hierarchy.cluster_count(), 6);
+        assert_eq!(hierarchy.cluster_of(0), Some(0));
+        assert_eq!(hierarchy.cluster_of(9), Some(2));
+        assert_eq!(hierarchy.cluster_of(69), Some(5));
+        assert_eq!(hierarchy.cluster_of(70), None);
+
+        // Open borders of 4 cells have a single transition in the middle
+        assert_eq!(hierarchy.transitions(0), vec![23, 32]);
+        assert!(
+            hierarchy.abstract_graph().edges[&23]
+                .iter()
+                .any(|e| e.to() == 32)
+        );
+    }
+
+    #[test]
+    fn finds_paths_whenever_the_goal_is_reachable() {
+        let mut rng = SeededRng::new(5);
+
+        for round in 0..20 {
+            let connect_eight = round % 2 == 1;
+            let terrain = random_terrain(&mut rng, 17, 13);
+            let graph = sample(&terrain, connect_eight);
+            let hierarchy = HierarchicalGrid::new(&terrain, &graph, 4);
+
+            let start = rng.next_below(17 * 13) as u32;
+            let reference = dijkstra(&graph, start);
+            for _ in 0..25 {
+                let goal = rng.next_below(17 * 13) as u32;
+                let found = hierarchy.find_path(&graph, start, goal);
+                let optimal = graph
+                    .nodes
+                    .contains_key(&start)
+                    .then(|| reference.cost_to(goal))
+                    .flatten();
+                assert_eq!(found.is_some(), optimal.is_some());
+
+                if let (Some(found), Some(optimal)) = (found, optimal) {
+                    assert_valid(&graph, &found);
+                    assert!(found.path.cost >= optimal);
+                    assert_eq!(found.path.nodes.first(), Some(&start));
+                    assert_eq!(found.path.nodes.last(), Some(&goal));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stays_close_to_optimal_on_open_grids() {
+        let terrain = vec![vec!['.'; 32]; 32];
+        let graph = sample(&terrain, false);
+        let hierarchy = HierarchicalGrid::new(&terrain, &graph, 8);
+
+        let found = hierarchy.find_path(&graph, 0, 32 * 32 - 1).unwrap();
+        assert_valid(&graph, &found);
+        // Transitions at both ends of each border keep straight paths optimal
+        assert_eq!(found.path.cost, 62.0);
+        assert_eq!(found.waypoints.first(), Some(&0));
+    }
+
+    #[test]
+    fn counts_expanded_nodes_rather_than_discovered_ones() {
+        let terrain = vec![vec!['.'; 8]; 8];
+        let graph = sample(&terrain, false);
+        let hierarchy = HierarchicalGrid::new(&terrain, &graph, 4);
+
+        // The start reaches its two transitions and the goal, but only the
+        // start and the goal are popped
+        let found = hierarchy.find_path(&graph, 0, 1).unwrap();
+        assert_eq!(hierarchy.transitions(0).len(), 2);
+        assert_eq!(found.waypoints, vec![0, 1]);
+        assert_eq!(found.expanded, 2);
+    }
+
+    #[test]
+    fn invalidation_matches_a_fresh_build() {
+        let mut rng = SeededRng::new(9);
+
+        for round in 0..20 {
+            let connect_eight = round % 2 == 0;
+            let mut terrain = random_terrain(&mut rng, 15, 15);
+            let graph = sample(&terrain, connect_eight);
+            let mut hierarchy = HierarchicalGrid::new(&terrain, &graph, 5);
+
+            // Change a few cells of a single cluster
+            let cluster = rng.next_below(hierarchy.cluster_count());
+            let cells: Vec<u32> = hierarchy.cells(cluster).collect();
+            for _ in 0..4 {
+                let cell = cells[rng.next_below(cells.len())] as usize;
+                let value = &mut terrain[cell / 15][cell % 15];
+                *value = if *value == '#' { '.' } else { '#' };
+            }
+            let graph = sample(&terrain, connect_eight);
+            hierarchy.invalidate(&graph, cluster);
+
+            let fresh = HierarchicalGrid::new(&terrain, &graph, 5);
+            assert_eq!(edges(&hierarchy), edges(&fresh));
+            assert_eq!(hierarchy.transitions, fresh.transitions);
+            let nodes = |h: &HierarchicalGrid| {
+                h.abstract_graph()
+                    .nodes
+                    .keys()
+                    .copied()
+                    .collect::<BTreeSet<_>>()
+            };
+            assert_eq!(nodes(&hierarchy), nodes(&fresh));
+        }
+    }
+
+    #[test]
+    fn rejects_missing_cells() {
+        let terrain = vec![vec!['.', '#', '.']];
+        let graph = sample(&terrain, false);
+        let hierarchy = HierarchicalGrid::new(&terrain, &graph, 2);
+
+        assert_eq!(hierarchy.find_path(&graph, 0, 1), None);
+        assert_eq!(hierarchy.find_path(&graph, 0, 2), None);
+        assert_eq!(hierarchy.find_path(&graph, 0, 7), None);
+
+        let found = hierarchy.find_path(&graph, 0, 0).unwrap();
+        assert_eq!(found.path.nodes, vec![0]);
+        assert_eq!(found.path.cost, 0.0);
+    }
+}
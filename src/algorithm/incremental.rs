@@ -0,0 +1,918 @@
+use crate::graph::{Edge, Graph, Node};
+use crate::strategy::{Heuristic, PairwiseHeuristic};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+
+use super::RankedPath;
+use super::adjacency::Adjacency;
+
+/// New weight for the edges between two nodes.
+///
+/// Every parallel edge from `from` to `to` receives the weight through
+/// `Edge::set_weight`. Blocking a passage is done with `f64::INFINITY`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightChange {
+    /// Source node ID
+    pub from: u32,
+    /// Target node ID
+    pub to: u32,
+    /// Weight given to the edges
+    pub weight: f64,
+}
+
+impl WeightChange {
+    /// Creates a weight change for the edges from `from` to `to`.
+    pub fn new(from: u32, to: u32, weight: f64) -> Self {
+        WeightChange { from, to, weight }
+    }
+}
+
+/// Lifelong Planning A* between a fixed start and goal.
+///
+/// The first search behaves like A*. When edge weights change, `update`
+/// applies the changes to the graph and repairs the previous search,
+/// expanding only the nodes whose distances are affected instead of
+/// searching from scratch.
+///
+/// The heuristic estimates the distance from a node to the goal and must
+/// be consistent. Edge weights must be non-negative, without zero-weight
+/// cycles.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::{LpaStar, WeightChange};
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, WeightedEdge};
+///
+/// let mut graph: Graph<EmptyNode, WeightedEdge> = Graph::new();
+/// for id in 0..4 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// for (from, to, weight) in [(0, 1, 1.0), (1, 3, 1.0), (0, 2, 2.0), (2, 3, 2.0)] {
+///     graph.add_edge(WeightedEdge::new(from, to, Some(weight)));
+/// }
+///
+/// let mut planner = LpaStar::new(&graph, 0, 3, |_| 0.0).unwrap();
+/// assert_eq!(planner.path().unwrap().nodes, vec![0, 1, 3]);
+///
+/// let path = planner.update(&mut graph, &[WeightChange::new(1, 3, f64::INFINITY)]);
+/// assert_eq!(path.unwrap().nodes, vec![0, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LpaStar<H> {
+    ids: Vec<u32>,
+    index: HashMap<u32, usize>,
+    search: Search,
+    heuristic: H,
+}
+
+impl<H: Heuristic> LpaStar<H> {
+    /// Runs the initial search from `start` to `goal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to plan on
+    /// * `start` - The source node ID
+    /// * `goal` - The target node ID
+    /// * `heuristic` - Consistent estimate of the distance to `goal`
+    ///
+    /// # Returns
+    ///
+    /// The planner, or `None` if `start` or `goal` is not in the graph
+    pub fn new<TNode, TEdge>(
+        graph: &Graph<TNode, TEdge>,
+        start: u32,
+        goal: u32,
+        heuristic: H,
+    ) -> Option<Self>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let adjacency = Adjacency::from_graph(graph);
+        let source = *adjacency.index.get(&start)?;
+        let target = *adjacency.index.get(&goal)?;
+
+        let ids = adjacency.ids.clone();
+        let h = |i: usize| heuristic.estimate(ids[i]);
+        let search = Search::new(&adjacency.successors, false, source, target, &h);
+
+        let mut planner = LpaStar {
+            ids: adjacency.ids,
+            index: adjacency.index,
+            search,
+            heuristic,
+        };
+        planner.repair(&[]);
+        Some(planner)
+    }
+
+    /// Returns the source node ID.
+    pub fn start(&self) -> u32 {
+        self.ids[self.search.source]
+    }
+
+    /// Returns the target node ID.
+    pub fn goal(&self) -> u32 {
+        self.ids[self.search.target]
+    }
+
+    /// Returns the distance from the start to the goal, `None` if unreachable.
+    pub fn cost(&self) -> Option<f64> {
+        self.search.distance()
+    }
+
+    /// Returns the number of nodes expanded by the last search or repair.
+    pub fn expanded(&self) -> usize {
+        self.search.expanded
+    }
+
+    /// Returns a shortest path from the start to the goal.
+    ///
+    /// # Returns
+    ///
+    /// The path and its cost, or `None` if the goal is unreachable
+    pub fn path(&self) -> Option<RankedPath> {
+        let mut nodes = self.search.walk()?;
+        nodes.reverse();
+        Some(RankedPath {
+            nodes: nodes.into_iter().map(|i| self.ids[i]).collect(),
+            cost: self.search.distance()?,
+        })
+    }
+
+    /// Applies a batch of weight changes and repairs the search.
+    ///
+    /// Changes are written to the graph with `Edge::set_weight`. Changes
+    /// targeting missing edges are ignored, and edges without a settable
+    /// weight, such as `UnweightedEdge`, keep their weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph the planner was created from
+    /// * `changes` - The new edge weights
+    ///
+    /// # Returns
+    ///
+    /// The updated shortest path, or `None` if the goal became unreachable
+    pub fn update<TNode, TEdge>(
+        &mut self,
+        graph: &mut Graph<TNode, TEdge>,
+        changes: &[WeightChange],
+    ) -> Option<RankedPath>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let arcs = indexed_changes(&self.index, graph, changes);
+        self.repair(&arcs);
+        self.path()
+    }
+
+    /// Updates the given arc costs and recomputes the affected distances.
+    fn repair(&mut self, arcs: &[(usize, usize, f64)]) {
+        let LpaStar {
+            ids,
+            search,
+            heuristic,
+            ..
+        } = self;
+        let h = |i: usize| heuristic.estimate(ids[i]);
+
+        for &(from, to, weight) in arcs {
+            search.set_cost(from, to, weight, &h);
+        }
+        search.compute(&h);
+    }
+}
+
+/// D* Lite for a moving agent heading to a fixed goal.
+///
+/// The search runs backwards from the goal, so the agent can move along
+/// its path with `move_to` and report changed edge weights with `update`
+/// at any point; both only repair the affected part of the search.
+///
+/// The heuristic is a `PairwiseHeuristic`, as the node it estimates from
+/// follows the agent, and must be consistent. Edge weights must be
+/// non-negative, without zero-weight cycles.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::{DStarLite, WeightChange};
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, WeightedEdge};
+///
+/// // Corridor 0 - 1 - 2 - 3 with a detour 1 - 4 - 3
+/// let mut graph: Graph<EmptyNode, WeightedEdge> = Graph::new();
+/// for id in 0..5 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// for (a, b, weight) in [(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (1, 4, 2.0), (4, 3, 2.0)] {
+///     graph.add_edge(WeightedEdge::new(a, b, Some(weight)));
+///     graph.add_edge(WeightedEdge::new(b, a, Some(weight)));
+/// }
+///
+/// let mut planner = DStarLite::new(&graph, 0, 3, |_, _| 0.0).unwrap();
+/// planner.move_to(1);
+///
+/// // The agent discovers that the corridor is blocked
+/// let path = planner.update(&mut graph, &[WeightChange::new(1, 2, f64::INFINITY)]);
+/// assert_eq!(path.unwrap().nodes, vec![1, 4, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DStarLite<H> {
+    ids: Vec<u32>,
+    index: HashMap<u32, usize>,
+    search: Search,
+    heuristic: H,
+}
+
+impl<H: PairwiseHeuristic> DStarLite<H> {
+    /// Runs the initial search for a path from `start` to `goal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to plan on
+    /// * `start` - The initial position of the agent
+    /// * `goal` - The target node ID
+    /// * `heuristic` - Consistent estimate of the distance between two nodes
+    ///
+    /// # Returns
+    ///
+    /// The planner, or `None` if `start` or `goal` is not in the graph
+    pub fn new<TNode, TEdge>(
+        graph: &Graph<TNode, TEdge>,
+        start: u32,
+        goal: u32,
+        heuristic: H,
+    ) -> Option<Self>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let adjacency = Adjacency::from_graph(graph);
+        let source = *adjacency.index.get(&goal)?;
+        let target = *adjacency.index.get(&start)?;
+
+        let ids = adjacency.ids.clone();
+        let h = |i: usize| heuristic.estimate_between(start, ids[i]);
+        let search = Search::new(&adjacency.successors, true, source, target, &h);
+
+        let mut planner = DStarLite {
+            ids: adjacency.ids,
+            index: adjacency.index,
+            search,
+            heuristic,
+        };
+        planner.repair(&[]);
+        Some(planner)
+    }
+
+    /// Returns the current position of the agent.
+    pub fn position(&self) -> u32 {
+        self.ids[self.search.target]
+    }
+
+    /// Returns the target node ID.
+    pub fn goal(&self) -> u32 {
+        self.ids[self.search.source]
+    }
+
+    /// Returns the distance from the agent to the goal, `None` if unreachable.
+    pub fn cost(&self) -> Option<f64> {
+        self.search.distance()
+    }
+
+    /// Returns the number of nodes expanded by the last search or repair.
+    pub fn expanded(&self) -> usize {
+        self.search.expanded
+    }
+
+    /// Returns a shortest path from the agent to the goal.
+    ///
+    /// # Returns
+    ///
+    /// The path and its cost, or `None` if the goal is unreachable
+    pub fn path(&self) -> Option<RankedPath> {
+        let nodes = self.search.walk()?;
+        Some(RankedPath {
+            nodes: nodes.into_iter().map(|i| self.ids[i]).collect(),
+            cost: self.search.distance()?,
+        })
+    }
+
+    /// Moves the agent to another node and repairs the search.
+    ///
+    /// The agent usually moves to the next node of its path, but any node
+    /// of the graph is accepted. Unknown nodes leave the agent in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The new position of the agent
+    ///
+    /// # Returns
+    ///
+    /// The shortest path from the new position, or `None` if the goal is unreachable
+    pub fn move_to(&mut self, node_id: u32) -> Option<RankedPath> {
+        if let Some(&target) = self.index.get(&node_id) {
+            // Keys computed from the previous position stay lower bounds
+            // once offset by the distance estimate between both positions
+            let position = self.position();
+            self.search.km += self.heuristic.estimate_between(position, node_id);
+            self.search.target = target;
+            self.repair(&[]);
+        }
+        self.path()
+    }
+
+    /// Applies a batch of weight changes and repairs the search.
+    ///
+    /// Changes are written to the graph with `Edge::set_weight`. Changes
+    /// targeting missing edges are ignored, and edges without a settable
+    /// weight, such as `UnweightedEdge`, keep their weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph the planner was created from
+    /// * `changes` - The new edge weights
+    ///
+    /// # Returns
+    ///
+    /// The updated shortest path, or `None` if the goal became unreachable
+    pub fn update<TNode, TEdge>(
+        &mut self,
+        graph: &mut Graph<TNode, TEdge>,
+        changes: &[WeightChange],
+    ) -> Option<RankedPath>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        // The backward search sees every edge reversed
+        let arcs: Vec<(usize, usize, f64)> = indexed_changes(&self.index, graph, changes)
+            .into_iter()
+            .map(|(from, to, weight)| (to, from, weight))
+            .collect();
+        self.repair(&arcs);
+        self.path()
+    }
+
+    /// Updates the given arc costs and recomputes the affected distances.
+    fn repair(&mut self, arcs: &[(usize, usize, f64)]) {
+        let DStarLite {
+            ids,
+            search,
+            heuristic,
+            ..
+        } = self;
+        let position = ids[search.target];
+        let h = |i: usize| heuristic.estimate_between(position, ids[i]);
+
+        for &(from, to, weight) in arcs {
+            search.set_cost(from, to, weight, &h);
+        }
+        search.compute(&h);
+    }
+}
+
+/// Writes weight changes to a graph and returns the new cost of each
+/// affected pair of known nodes, as `(from index, to index, cost)`.
+///
+/// The cost of a pair is the smallest weight among its parallel edges, read
+/// back from the graph so that edges ignoring `set_weight` keep their weight.
+fn indexed_changes<TNode, TEdge>(
+    index: &HashMap<u32, usize>,
+    graph: &mut Graph<TNode, TEdge>,
+    changes: &[WeightChange],
+) -> Vec<(usize, usize, f64)>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = f64>,
+{
+    let mut arcs = Vec::new();
+    for change in changes {
+        let Some(edges) = graph.edges.get_mut(&change.from) else {
+            continue;
+        };
+
+        let mut cost: Option<f64> = None;
+        for edge in edges.iter_mut().filter(|e| e.to() == change.to) {
+            edge.set_weight(change.weight);
+            cost = Some(cost.map_or(edge.weight(), |c| c.min(edge.weight())));
+        }
+
+        if let (Some(cost), Some(&from), Some(&to)) =
+            (cost, index.get(&change.from), index.get(&change.to))
+        {
+            arcs.push((from, to, cost));
+        }
+    }
+    arcs
+}
+
+/// Priority of a node: `(min(g, rhs) + h + km, min(g, rhs))`.
+type Key = (f64, f64);
+
+fn compare(a: Key, b: Key) -> Ordering {
+    a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+}
+
+/// Heap entry ordered by increasing key.
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    key: Key,
+    node: usize,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(other.key, self.key).then(other.node.cmp(&self.node))
+    }
+}
+
+/// Priority queue of inconsistent nodes supporting removal.
+///
+/// Removed or requeued nodes leave stale entries in the heap, which are
+/// discarded when they reach the top.
+#[derive(Debug, Clone)]
+struct OpenList {
+    heap: BinaryHeap<Entry>,
+    keys: Vec<Option<Key>>,
+}
+
+impl OpenList {
+    fn new(len: usize) -> Self {
+        OpenList {
+            heap: BinaryHeap::new(),
+            keys: vec![None; len],
+        }
+    }
+
+    fn insert(&mut self, node: usize, key: Key) {
+        self.keys[node] = Some(key);
+        self.heap.push(Entry { key, node });
+    }
+
+    fn remove(&mut self, node: usize) {
+        self.keys[node] = None;
+    }
+
+    fn top(&mut self) -> Option<(usize, Key)> {
+        while let Some(entry) = self.heap.peek() {
+            if self.keys[entry.node] == Some(entry.key) {
+                return Some((entry.node, entry.key));
+            }
+            self.heap.pop();
+        }
+        None
+    }
+
+    fn pop(&mut self) -> Option<(usize, Key)> {
+        let (node, key) = self.top()?;
+        self.heap.pop();
+        self.keys[node] = None;
+        Some((node, key))
+    }
+}
+
+/// Incremental search shared by both planners.
+///
+/// Distances grow from `source` and the search stops once `target` is
+/// settled. Each arc `(tail, head)` bounds the distance of its head, which
+/// is a graph edge for LPA* and a reversed graph edge for D* Lite.
+#[derive(Debug, Clone)]
+struct Search {
+    /// Arcs entering each node as `(tail, cost)`
+    incoming: Vec<Vec<(usize, f64)>>,
+    /// Heads of the arcs leaving each node
+    outgoing: Vec<Vec<usize>>,
+    source: usize,
+    target: usize,
+    /// Settled distances from the source
+    g: Vec<f64>,
+    /// One-step lookahead distances from the source
+    rhs: Vec<f64>,
+    open: OpenList,
+    /// Offset added to keys as the target moves
+    km: f64,
+    expanded: usize,
+}
+
+impl Search {
+    /// Creates a search over the graph edges, reversed if requested.
+    ///
+    /// Parallel edges are merged into their cheapest arc and self loops are
+    /// dropped.
+    fn new(
+        successors: &[Vec<(usize, f64)>],
+        reversed: bool,
+        source: usize,
+        target: usize,
+        h: &dyn Fn(usize) -> f64,
+    ) -> Self {
+        let n = successors.len();
+        let mut arcs: BTreeMap<(usize, usize), f64> = BTreeMap::new();
+        for (from, targets) in successors.iter().enumerate() {
+            for &(to, weight) in targets {
+                if from == to {
+                    continue;
+                }
+                let arc = if reversed { (to, from) } else { (from, to) };
+                let cost = arcs.entry(arc).or_insert(weight);
+                *cost = cost.min(weight);
+            }
+        }
+
+        let mut incoming = vec![Vec::new(); n];
+        let mut outgoing = vec![Vec::new(); n];
+        for (&(tail, head), &cost) in &arcs {
+            incoming[head].push((tail, cost));
+            outgoing[tail].push(head);
+        }
+
+        let mut search = Search {
+            incoming,
+            outgoing,
+            source,
+            target,
+            g: vec![f64::INFINITY; n],
+            rhs: vec![f64::INFINITY; n],
+            open: OpenList::new(n),
+            km: 0.0,
+            expanded: 0,
+        };
+        search.rhs[source] = 0.0;
+        search.open.insert(source, search.key(source, h));
+        search
+    }
+
+    fn key(&self, node: usize, h: &dyn Fn(usize) -> f64) -> Key {
+        let best = self.g[node].min(self.rhs[node]);
+        (best + h(node) + self.km, best)
+    }
+
+    /// Recomputes the lookahead of a node and queues it if inconsistent.
+    fn update_vertex(&mut self, node: usize, h: &dyn Fn(usize) -> f64) {
+        if node != self.source {
+            self.rhs[node] = self.incoming[node]
+                .iter()
+                .map(|&(tail, cost)| self.g[tail] + cost)
+                .fold(f64::INFINITY, f64::min);
+        }
+
+        self.open.remove(node);
+        if self.g[node] != self.rhs[node] {
+            self.open.insert(node, self.key(node, h));
+        }
+    }
+
+    /// Changes the cost of an existing arc.
+    fn set_cost(&mut self, tail: usize, head: usize, cost: f64, h: &dyn Fn(usize) -> f64) {
+        if let Some(arc) = self.incoming[head].iter_mut().find(|arc| arc.0 == tail) {
+            arc.1 = cost;
+            self.update_vertex(head, h);
+        }
+    }
+
+    /// Expands inconsistent nodes until the target is settled.
+    fn compute(&mut self, h: &dyn Fn(usize) -> f64) {
+        self.expanded = 0;
+
+        while let Some((node, old)) = self.open.top() {
+            // Nodes tying the target's key are expanded too: through
+            // zero-weight arcs, they may still change its distance
+            let settled = self.g[self.target] == self.rhs[self.target];
+            if settled && compare(old, self.key(self.target, h)) == Ordering::Greater {
+                break;
+            }
+
+            self.open.pop();
+            let new = self.key(node, h);
+            if compare(old, new) == Ordering::Less {
+                self.open.insert(node, new);
+                continue;
+            }
+
+            self.expanded += 1;
+            if self.g[node] > self.rhs[node] {
+                self.g[node] = self.rhs[node];
+            } else {
+                self.g[node] = f64::INFINITY;
+                self.update_vertex(node, h);
+            }
+            for i in 0..self.outgoing[node].len() {
+                let head = self.outgoing[node][i];
+                self.update_vertex(head, h);
+            }
+        }
+    }
+
+    /// Returns the distance from the source to the target, if finite.
+    fn distance(&self) -> Option<f64> {
+        let distance = self.g[self.target];
+        distance.is_finite().then_some(distance)
+    }
+
+    /// Follows the cheapest arcs back from the target to the source.
+    ///
+    /// # Returns
+    ///
+    /// The node indices from the target to the source
+    fn walk(&self) -> Option<Vec<usize>> {
+        self.distance()?;
+
+        let mut nodes = vec![self.target];
+        let mut current = self.target;
+        while current != self.source {
+            let through = |&(tail, cost): &(usize, f64)| self.g[tail] + cost;
+            let &(tail, _) = self.incoming[current]
+                .iter()
+                .filter(|arc| through(arc).is_finite())
+                .min_by(|a, b| through(a).total_cmp(&through(b)).then(a.0.cmp(&b.0)))?;
+
+            nodes.push(tail);
+            current = tail;
+            if nodes.len() > self.g.len() {
+                return None;
+            }
+        }
+        Some(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::MinHeap;
+    use crate::preset::policies::traversal::NoTermination;
+    use crate::preset::visitors::{TrackCost, WeightedVisitor};
+    use crate::preset::{EmptyNode, WeightedEdge};
+    use crate::rng::SeededRng;
+
+    const SIDE: u32 = 8;
+
+    /// Open 4-connected grid where every move costs 1.
+    fn grid() -> Graph<EmptyNode, WeightedEdge> {
+        let mut graph = Graph::new();
+        for id in 0..SIDE * SIDE {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for id in 0..SIDE * SIDE {
+            let (row, col) = (id / SIDE, id % SIDE);
+            if col + 1 < SIDE {
+                graph.add_edge(WeightedEdge::new(id, id + 1, Some(1.0)));
+                graph.add_edge(WeightedEdge::new(id + 1, id, Some(1.0)));
+            }
+            if row + 1 < SIDE {
+                graph.add_edge(WeightedEdge::new(id, id + SIDE, Some(1.0)));
+                graph.add_edge(WeightedEdge::new(id + SIDE, id, Some(1.0)));
+            }
+        }
+        graph
+    }
+
+    /// Changes blocking every edge entering a cell.
+    fn block(graph: &Graph<EmptyNode, WeightedEdge>, cell: u32) -> Vec<WeightChange> {
+        graph
+            .get_edges()
+            .into_iter()
+            .filter(|e| e.to() == cell)
+            .map(|e| WeightChange::new(e.from(), cell, f64::INFINITY))
+            .collect()
+    }
+
+    fn manhattan(a: u32, b: u32) -> f64 {
+        let (ra, ca) = (a / SIDE, a % SIDE);
+        let (rb, cb) = (b / SIDE, b % SIDE);
+        (ra.abs_diff(rb) + ca.abs_diff(cb)) as f64
+    }
+
+    fn dijkstra(graph: &Graph<EmptyNode, WeightedEdge>, from: u32, to: u32) -> Option<f64> {
+        let mut visitor = WeightedVisitor::new(NoTermination);
+        graph.traverse(from, &mut MinHeap::new(), &mut visitor);
+        visitor.cost_to(to).filter(|cost| cost.is_finite())
+    }
+
+    fn assert_valid(graph: &Graph<EmptyNode, WeightedEdge>, path: &RankedPath) {
+        let mut cost = 0.0;
+        for pair in path.nodes.windows(2) {
+            cost += graph.edges[&pair[0]]
+                .iter()
+                .filter(|e| e.to() == pair[1])
+                .map(|e| e.weight())
+                .fold(f64::INFINITY, f64::min);
+        }
+        assert_eq!(cost, path.cost);
+    }
+
+    #[test]
+    fn lpa_star_finds_the_shortest_path() {
+        let graph = grid();
+        let goal = SIDE * SIDE - 1;
+        let planner = LpaStar::new(&graph, 0, goal, |id| manhattan(id, goal)).unwrap();
+
+        let path = planner.path().unwrap();
+        assert_eq!(path.cost, 14.0);
+        assert_eq!(path.nodes.first(), Some(&0));
+        assert_eq!(path.nodes.last(), Some(&goal));
+        assert_valid(&graph, &path);
+    }
+
+    #[test]
+    fn lpa_star_repairs_after_random_changes() {
+        let mut graph = grid();
+        let goal = SIDE * SIDE - 1;
+        let mut planner = LpaStar::new(&graph, 0, goal, |id| manhattan(id, goal)).unwrap();
+        let mut rng = SeededRng::new(7);
+
+        for _ in 0..40 {
+            let edges: Vec<(u32, u32)> = graph
+                .get_edges()
+                .into_iter()
+                .map(|e| (e.from(), e.to()))
+                .collect();
+            let changes: Vec<WeightChange> = (0..4)
+                .map(|_| {
+                    let (from, to) = edges[rng.next_below(edges.len())];
+                    let weight = match rng.next_below(4) {
+                        0 => f64::INFINITY,
+                        w => w as f64,
+                    };
+                    WeightChange::new(from, to, weight)
+                })
+                .collect();
+
+            let path = planner.update(&mut graph, &changes);
+            assert_eq!(planner.cost(), dijkstra(&graph, 0, goal));
+            if let Some(path) = path {
+                assert_valid(&graph, &path);
+            }
+        }
+    }
+
+    #[test]
+    fn lpa_star_repairs_through_zero_weight_edges() {
+        let mut graph = Graph::new();
+        for id in 0..5 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        graph.add_edge(WeightedEdge::new(1, 4, Some(2.0)));
+        graph.add_edge(WeightedEdge::new(4, 0, Some(0.0)));
+        let mut planner = LpaStar::new(&graph, 1, 0, |_| 0.0).unwrap();
+        assert_eq!(planner.cost(), Some(2.0));
+
+        // Node 4 becomes underconsistent with the same key as the goal
+        let path = planner.update(&mut graph, &[WeightChange::new(1, 4, 3.0)]);
+        assert_eq!(path.unwrap().nodes, vec![1, 4, 0]);
+        assert_eq!(planner.cost(), Some(3.0));
+    }
+
+    #[test]
+    fn zero_weight_changes_match_dijkstra() {
+        let mut rng = SeededRng::new(11);
+        let goal = SIDE * SIDE - 1;
+
+        for round in 0..20 {
+            let mut graph = grid();
+            let mut lpa_star = LpaStar::new(&graph, 0, goal, |_| 0.0).unwrap();
+            let start = rng.next_below((SIDE * SIDE) as usize) as u32;
+            let mut d_star_lite = DStarLite::new(&graph, start, goal, |_, _| 0.0).unwrap();
+
+            for _ in 0..10 {
+                let edges: Vec<(u32, u32)> = graph
+                    .get_edges()
+                    .into_iter()
+                    .map(|e| (e.from(), e.to()))
+                    .collect();
+                // Only edges towards higher IDs become free, leaving no zero-weight cycle
+                let changes: Vec<WeightChange> = (0..6)
+                    .map(|_| {
+                        let (from, to) = edges[rng.next_below(edges.len())];
+                        let weight = match rng.next_below(4) {
+                            3 if round % 2 == 0 => f64::INFINITY,
+                            0 if from > to => 1.0,
+                            w => w as f64,
+                        };
+                        WeightChange::new(from, to, weight)
+                    })
+                    .collect();
+
+                if let Some(path) = lpa_star.update(&mut graph, &changes) {
+                    assert_valid(&graph, &path);
+                }
+                assert_eq!(lpa_star.cost(), dijkstra(&graph, 0, goal));
+                if let Some(path) = d_star_lite.update(&mut graph, &changes) {
+                    assert_valid(&graph, &path);
+                }
+                assert_eq!(d_star_lite.cost(), dijkstra(&graph, start, goal));
+            }
+        }
+    }
+
+    #[test]
+    fn repair_expands_fewer_nodes_than_the_initial_search() {
+        let mut graph = grid();
+        let goal = SIDE * SIDE - 1;
+        let mut planner = LpaStar::new(&graph, 0, goal, |_| 0.0).unwrap();
+        let initial = planner.expanded();
+
+        let changes = block(&graph, goal - 1);
+        let path = planner.update(&mut graph, &changes).unwrap();
+
+        assert_eq!(path.cost, 14.0);
+        assert!(planner.expanded() < initial);
+    }
+
+    #[test]
+    fn unreachable_goal_and_unknown_nodes() {
+        let mut graph = grid();
+        let goal = SIDE * SIDE - 1;
+        assert!(LpaStar::new(&graph, 0, 1000, |_| 0.0).is_none());
+        assert!(DStarLite::new(&graph, 1000, goal, |_, _| 0.0).is_none());
+
+        let mut planner = LpaStar::new(&graph, 0, goal, |_| 0.0).unwrap();
+        let changes = block(&graph, goal);
+        assert_eq!(planner.update(&mut graph, &changes), None);
+        assert_eq!(planner.cost(), None);
+
+        // Missing edges are ignored
+        let changes = [WeightChange::new(0, goal, 1.0)];
+        assert_eq!(planner.update(&mut graph, &changes), None);
+    }
+
+    #[test]
+    fn d_star_lite_follows_a_changing_grid() {
+        let mut graph = grid();
+        let goal = SIDE * SIDE - 1;
+        let mut planner = DStarLite::new(&graph, 0, goal, manhattan).unwrap();
+        let mut rng = SeededRng::new(3);
+
+        // The agent walks its path, discovering a blocked cell at every step
+        let mut steps = 0;
+        while planner.position() != goal {
+            let cell = rng.next_below((SIDE * SIDE) as usize) as u32;
+            if cell != planner.position() && cell != goal {
+                let changes = block(&graph, cell);
+                planner.update(&mut graph, &changes);
+            }
+
+            let expected = dijkstra(&graph, planner.position(), goal);
+            assert_eq!(planner.cost(), expected);
+            let Some(path) = planner.path() else {
+                break;
+            };
+            assert_valid(&graph, &path);
+            assert_eq!(path.nodes[0], planner.position());
+
+            planner.move_to(path.nodes[1]);
+            steps += 1;
+            assert!(steps < 100);
+        }
+    }
+
+    #[test]
+    fn d_star_lite_moves_anywhere() {
+        let graph = grid();
+        let goal = SIDE * SIDE - 1;
+        let mut planner = DStarLite::new(&graph, 0, goal, manhattan).unwrap();
+
+        let path = planner.move_to(SIDE * 4).unwrap();
+        assert_eq!(path.nodes[0], SIDE * 4);
+        assert_eq!(path.cost, manhattan(SIDE * 4, goal));
+
+        // Unknown nodes leave the agent in place
+        planner.move_to(1000);
+        assert_eq!(planner.position(), SIDE * 4);
+    }
+
+    #[test]
+    fn d_star_lite_accepts_landmark_bounds() {
+        let mut graph = grid();
+        let goal = SIDE * SIDE - 1;
+        let landmarks = crate::algorithm::Landmarks::new(&graph, &[0, goal]);
+        let mut planner = DStarLite::new(&graph, SIDE, goal, landmarks).unwrap();
+
+        assert_eq!(planner.cost(), Some(manhattan(SIDE, goal)));
+
+        // Raising weights keeps landmark bounds admissible
+        let changes = block(&graph, SIDE + 1);
+        let path = planner.update(&mut graph, &changes).unwrap();
+        assert_eq!(Some(path.cost), dijkstra(&graph, SIDE, goal));
+    }
+}
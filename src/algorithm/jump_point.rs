@@ -0,0 +1,399 @@
+use crate::frontier::{Frontier, MinHeap};
+use crate::preset::samplers::Grid2D;
+use std::collections::{HashMap, HashSet};
+
+use super::RankedPath;
+
+/// Result of a jump point search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JumpPointPath {
+    /// Every cell of the path, from start to goal, and its number of moves
+    pub path: RankedPath,
+    /// The jump points on the path, start and goal included. Consecutive
+    /// ones are aligned, and the path may go straight through some of them.
+    pub jump_points: Vec<u32>,
+    /// Number of jump points expanded by the search
+    pub expanded: usize,
+}
+
+/// Finds a shortest path on an 8-connected grid with Jump Point Search.
+///
+/// Uses the same model as a graph sampled with
+/// `Grid2DSampler::with_connect_eight`: cell `(row, col)` has ID
+/// `row * width + col`, every move to one of the eight neighbors costs one,
+/// and diagonal moves may cut corners. Paths are as short as Dijkstra's on
+/// the sampled graph.
+///
+/// Instead of pushing every neighbor, the search jumps along straight and
+/// diagonal lines until it meets a cell with a neighbor that cannot be
+/// reached as cheaply without it. Only those jump points enter the frontier,
+/// so open areas are crossed without being expanded.
+///
+/// # Arguments
+///
+/// * `grid` - Rectangular grid of cells
+/// * `walkable` - Returns `true` for cells that can be entered
+/// * `start` - ID of the start cell
+/// * `goal` - ID of the goal cell
+///
+/// # Returns
+///
+/// The path, or `None` if either cell is outside the grid, not walkable,
+/// or the goal cannot be reached
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::jump_point_search;
+///
+/// let grid = vec![
+///     vec!['.', '.', '.', '.'],
+///     vec!['.', '#', '#', '.'],
+///     vec!['.', '.', '.', '.'],
+/// ];
+///
+/// let found = jump_point_search(&grid, |&cell| cell != '#', 4, 7).unwrap();
+/// assert_eq!(found.path.cost, 3.0);
+/// assert_eq!(found.path.nodes.first(), Some(&4));
+/// assert_eq!(found.path.nodes.last(), Some(&7));
+/// ```
+pub fn jump_point_search<T, F>(
+    grid: &Grid2D<T>,
+    walkable: F,
+    start: u32,
+    goal: u32,
+) -> Option<JumpPointPath>
+where
+    F: Fn(&T) -> bool,
+{
+    let map = Map {
+        grid,
+        walkable,
+        width: grid.first().map_or(0, Vec::len) as i64,
+    };
+    let (start, goal) = (map.cell(start)?, map.cell(goal)?);
+    if !map.walkable(start) || !map.walkable(goal) {
+        return None;
+    }
+
+    let mut costs = HashMap::from([(start, 0.0)]);
+    let mut parents: HashMap<Cell, Cell> = HashMap::new();
+    let mut closed: HashSet<Cell> = HashSet::new();
+    let mut frontier = MinHeap::new();
+    frontier.push(map.id(start), Some(chebyshev(start, goal)));
+
+    while let Some(id) = frontier.pop() {
+        let current = map.cell(id)?;
+        if !closed.insert(current) {
+            continue;
+        }
+        if current == goal {
+            return Some(map.unfold(&parents, goal, closed.len()));
+        }
+
+        let cost = costs[&current];
+        let directions = match parents.get(&current) {
+            Some(&parent) => map.pruned_directions(current, direction(parent, current)),
+            None => NEIGHBORS.to_vec(),
+        };
+        for step in directions {
+            let Some(jump) = map.jump(current, step, goal) else {
+                continue;
+            };
+            let candidate = cost + chebyshev(current, jump);
+            if costs.get(&jump).is_none_or(|&known| candidate < known) {
+                costs.insert(jump, candidate);
+                parents.insert(jump, current);
+                frontier.push(map.id(jump), Some(candidate + chebyshev(jump, goal)));
+            }
+        }
+    }
+    None
+}
+
+/// Grid position as `(row, col)`.
+type Cell = (i64, i64);
+
+const NEIGHBORS: [Cell; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Number of moves between two cells on an open 8-connected grid.
+fn chebyshev(a: Cell, b: Cell) -> f64 {
+    (a.0 - b.0).abs().max((a.1 - b.1).abs()) as f64
+}
+
+/// Unit step leading from `from` towards `to` along a line.
+fn direction(from: Cell, to: Cell) -> Cell {
+    ((to.0 - from.0).signum(), (to.1 - from.1).signum())
+}
+
+struct Map<'a, T, F> {
+    grid: &'a Grid2D<T>,
+    walkable: F,
+    width: i64,
+}
+
+impl<T, F: Fn(&T) -> bool> Map<'_, T, F> {
+    fn cell(&self, id: u32) -> Option<Cell> {
+        if self.width == 0 {
+            return None;
+        }
+        let cell = (id as i64 / self.width, id as i64 % self.width);
+        (cell.0 < self.grid.len() as i64).then_some(cell)
+    }
+
+    fn id(&self, (row, col): Cell) -> u32 {
+        (row * self.width + col) as u32
+    }
+
+    /// Returns `true` for cells inside the grid that can be entered.
+    fn walkable(&self, (row, col): Cell) -> bool {
+        row >= 0
+            && col >= 0
+            && col < self.width
+            && self
+                .grid
+                .get(row as usize)
+                .and_then(|cells| cells.get(col as usize))
+                .is_some_and(|cell| (self.walkable)(cell))
+    }
+
+    /// Returns `true` if `side` is blocked while the cell past it is open.
+    fn forced(&self, side: Cell, past: Cell) -> bool {
+        !self.walkable(side) && self.walkable(past)
+    }
+
+    /// Returns `true` if a cell reached with `step` has a forced neighbor.
+    fn has_forced_neighbor(&self, (row, col): Cell, (dr, dc): Cell) -> bool {
+        match (dr, dc) {
+            (0, dc) => {
+                self.forced((row + 1, col), (row + 1, col + dc))
+                    || self.forced((row - 1, col), (row - 1, col + dc))
+            }
+            (dr, 0) => {
+                self.forced((row, col + 1), (row + dr, col + 1))
+                    || self.forced((row, col - 1), (row + dr, col - 1))
+            }
+            (dr, dc) => {
+                self.forced((row, col - dc), (row + dr, col - dc))
+                    || self.forced((row - dr, col), (row - dr, col + dc))
+            }
+        }
+    }
+
+    /// Returns the directions worth following from a cell reached with `step`.
+    ///
+    /// Natural neighbors continue the move, forced neighbors turn around
+    /// the obstacles that make them unreachable otherwise.
+    fn pruned_directions(&self, (row, col): Cell, (dr, dc): Cell) -> Vec<Cell> {
+        let mut directions = vec![(dr, dc)];
+        match (dr, dc) {
+            (0, dc) => {
+                for side in [1, -1] {
+                    if self.forced((row + side, col), (row + side, col + dc)) {
+                        directions.push((side, dc));
+                    }
+                }
+            }
+            (dr, 0) => {
+                for side in [1, -1] {
+                    if self.forced((row, col + side), (row + dr, col + side)) {
+                        directions.push((dr, side));
+                    }
+                }
+            }
+            (dr, dc) => {
+                directions.extend([(dr, 0), (0, dc)]);
+                if self.forced((row, col - dc), (row + dr, col - dc)) {
+                    directions.push((dr, -dc));
+                }
+                if self.forced((row - dr, col), (row - dr, col + dc)) {
+                    directions.push((-dr, dc));
+                }
+            }
+        }
+        directions
+    }
+
+    /// Moves from `from` along `step` until reaching a jump point.
+    ///
+    /// A diagonal move stops wherever a straight jump along one of its
+    /// components would find a jump point.
+    fn jump(&self, from: Cell, (dr, dc): Cell, goal: Cell) -> Option<Cell> {
+        let mut current = from;
+        loop {
+            current = (current.0 + dr, current.1 + dc);
+            if !self.walkable(current) {
+                return None;
+            }
+            if current == goal || self.has_forced_neighbor(current, (dr, dc)) {
+                return Some(current);
+            }
+            if dr != 0
+                && dc != 0
+                && (self.jump(current, (dr, 0), goal).is_some()
+                    || self.jump(current, (0, dc), goal).is_some())
+            {
+                return Some(current);
+            }
+        }
+    }
+
+    /// Rebuilds the path from the jump point parents.
+    fn unfold(&self, parents: &HashMap<Cell, Cell>, goal: Cell, expanded: usize) -> JumpPointPath {
+        let mut jump_points = vec![goal];
+        while let Some(&parent) = parents.get(jump_points.last().unwrap()) {
+            jump_points.push(parent);
+        }
+        jump_points.reverse();
+
+        let mut nodes = vec![self.id(jump_points[0])];
+        for pair in jump_points.windows(2) {
+            let step = direction(pair[0], pair[1]);
+            let mut current = pair[0];
+            while current != pair[1] {
+                current = (current.0 + step.0, current.1 + step.1);
+                nodes.push(self.id(current));
+            }
+        }
+
+        JumpPointPath {
+            path: RankedPath {
+                cost: (nodes.len() - 1) as f64,
+                nodes,
+            },
+            jump_points: jump_points.into_iter().map(|cell| self.id(cell)).collect(),
+            expanded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::GraphBuilder;
+    use crate::frontier::Instrumented;
+    use crate::preset::policies::structural::DenyDanglingEdge;
+    use crate::preset::policies::traversal::GoalReached;
+    use crate::preset::policies::value::DenyNodeValue;
+    use crate::preset::samplers::Grid2DSampler;
+    use crate::preset::visitors::{TrackCost, WeightedVisitor};
+    use crate::rng::SeededRng;
+
+    fn parse(rows: &[&str]) -> Grid2D<char> {
+        rows.iter().map(|row| row.chars().collect()).collect()
+    }
+
+    /// Dijkstra on the sampled graph, returning the cost and the number of pops.
+    fn dijkstra(grid: &Grid2D<char>, start: u32, goal: u32) -> (Option<f64>, usize) {
+        let graph = GraphBuilder::new(
+            DenyDanglingEdge::default(),
+            DenyNodeValue::with_denied_values(vec!['#']),
+            Grid2DSampler::with_connect_eight(),
+        )
+        .build(grid);
+        let mut visitor = WeightedVisitor::new(GoalReached::new(goal));
+        let mut frontier = Instrumented::<MinHeap>::new();
+        graph.traverse(start, &mut frontier, &mut visitor);
+        (visitor.cost_to(goal), frontier.stats().pops)
+    }
+
+    fn assert_valid(grid: &Grid2D<char>, found: &JumpPointPath) {
+        let width = grid[0].len() as i64;
+        let cells: Vec<Cell> = found
+            .path
+            .nodes
+            .iter()
+            .map(|&id| (id as i64 / width, id as i64 % width))
+            .collect();
+        for &(row, col) in &cells {
+            assert_ne!(grid[row as usize][col as usize], '#');
+        }
+        for pair in cells.windows(2) {
+            assert_eq!(chebyshev(pair[0], pair[1]), 1.0);
+        }
+    }
+
+    #[test]
+    fn crosses_open_map_with_few_expansions() {
+        let grid = vec![vec!['.'; 60]; 60];
+
+        let found = jump_point_search(&grid, |&cell| cell != '#', 0, 60 * 60 - 1).unwrap();
+        let (cost, pops) = dijkstra(&grid, 0, 60 * 60 - 1);
+
+        assert_eq!(Some(found.path.cost), cost);
+        assert_eq!(found.jump_points, vec![0, 3599]);
+        assert!(found.expanded * 100 < pops);
+        assert_valid(&grid, &found);
+    }
+
+    #[test]
+    fn turns_around_walls() {
+        let grid = parse(&[
+            ".....#....",
+            ".###.#.##.",
+            ".#...#..#.",
+            ".#.###..#.",
+            ".#......#.",
+            ".########.",
+            "..........",
+        ]);
+
+        let found = jump_point_search(&grid, |&cell| cell != '#', 22, 27).unwrap();
+
+        assert_eq!(Some(found.path.cost), dijkstra(&grid, 22, 27).0);
+        assert_valid(&grid, &found);
+    }
+
+    #[test]
+    fn matches_dijkstra_on_random_grids() {
+        let mut rng = SeededRng::new(11);
+        for _ in 0..40 {
+            let grid: Grid2D<char> = (0..12)
+                .map(|_| {
+                    (0..15)
+                        .map(|_| if rng.next_below(10) < 3 { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            let (start, goal) = (rng.next_below(180) as u32, rng.next_below(180) as u32);
+            if grid[start as usize / 15][start as usize % 15] == '#'
+                || grid[goal as usize / 15][goal as usize % 15] == '#'
+            {
+                continue;
+            }
+
+            let found = jump_point_search(&grid, |&cell| cell != '#', start, goal);
+
+            assert_eq!(
+                found.as_ref().map(|f| f.path.cost),
+                dijkstra(&grid, start, goal).0
+            );
+            if let Some(found) = found {
+                assert_valid(&grid, &found);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_blocked_or_outside_cells() {
+        let grid = parse(&["..#", "..#", "###"]);
+        let walkable = |&cell: &char| cell != '#';
+
+        assert!(jump_point_search(&grid, walkable, 0, 2).is_none());
+        assert!(jump_point_search(&grid, walkable, 0, 9).is_none());
+        assert!(jump_point_search(&parse(&[".#", "#."]), walkable, 0, 3).is_some());
+        assert_eq!(
+            jump_point_search(&grid, walkable, 4, 4).unwrap().path.nodes,
+            vec![4]
+        );
+    }
+}
@@ -0,0 +1,458 @@
+use crate::frontier::MinHeap;
+use crate::graph::{Cost, Edge, Graph, Node};
+use crate::preset::policies::traversal::GoalReached;
+use crate::preset::visitors::{RestrictedVisitor, TrackCost, TrackParent, WeightedVisitor};
+use crate::preset::{EmptyNode, WeightedEdge};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+
+use super::all_pairs::cheapest_edges;
+
+/// A path with its total cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedPath<C = f64> {
+    /// Node IDs from the source to the target
+    pub nodes: Vec<u32>,
+    /// Sum of the edge weights along the path
+    pub cost: C,
+}
+
+impl<C: Cost> RankedPath<C> {
+    /// Orders paths by cost, then by number of nodes, then by node IDs.
+    fn rank(&self, other: &Self) -> Ordering {
+        self.cost
+            .compare(&other.cost)
+            .then_with(|| self.nodes.len().cmp(&other.nodes.len()))
+            .then_with(|| self.nodes.cmp(&other.nodes))
+    }
+}
+
+/// Computes the `k` shortest loopless paths between two nodes with Yen's algorithm.
+///
+/// Each new path deviates from a previous one at a spur node: the shortest
+/// path from the spur node is searched with a `WeightedVisitor` wrapped in a
+/// `RestrictedVisitor`, which hides the nodes of the shared prefix and the
+/// edges already used after it. Weights must be non-negative, and parallel
+/// edges are reduced to the cheapest one.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search
+/// * `source` - The start node ID
+/// * `target` - The goal node ID
+/// * `k` - The maximum number of paths
+///
+/// # Returns
+///
+/// Up to `k` distinct paths, by increasing cost. Fewer paths are returned
+/// when the graph does not contain `k` loopless paths.
+pub fn k_shortest_paths<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    source: u32,
+    target: u32,
+    k: usize,
+) -> Vec<RankedPath<TEdge::Weight>>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let weights = cheapest_edges(graph);
+    let simple = simple_graph(graph, &weights);
+
+    let mut accepted: Vec<RankedPath<TEdge::Weight>> = Vec::new();
+    if k == 0 || !graph.nodes.contains_key(&source) || !graph.nodes.contains_key(&target) {
+        return accepted;
+    }
+    let Some(first) = shortest_path(&simple, source, target, &[], &HashSet::new()) else {
+        return accepted;
+    };
+    accepted.push(ranked(first, &weights));
+
+    let mut candidates: Vec<RankedPath<TEdge::Weight>> = Vec::new();
+    while accepted.len() < k {
+        let previous = accepted.last().unwrap().nodes.clone();
+
+        for i in 0..previous.len() - 1 {
+            let (root, spur) = (&previous[..=i], previous[i]);
+
+            // Edges leaving the spur node along accepted paths sharing this root
+            let used: HashSet<(u32, u32)> = accepted
+                .iter()
+                .filter(|path| path.nodes.len() > i + 1 && path.nodes[..=i] == *root)
+                .map(|path| (spur, path.nodes[i + 1]))
+                .collect();
+
+            let Some(spur_path) = shortest_path(&simple, spur, target, &root[..i], &used) else {
+                continue;
+            };
+            let mut nodes = root[..i].to_vec();
+            nodes.extend(spur_path);
+
+            let known = |path: &RankedPath<TEdge::Weight>| path.nodes == nodes;
+            if !accepted.iter().any(known) && !candidates.iter().any(known) {
+                candidates.push(ranked(nodes, &weights));
+            }
+        }
+
+        let Some(best) = (0..candidates.len()).min_by(|&a, &b| candidates[a].rank(&candidates[b]))
+        else {
+            break;
+        };
+        accepted.push(candidates.swap_remove(best));
+    }
+
+    accepted
+}
+
+/// Settings of the penalty method generating alternative routes.
+///
+/// The shortest path is searched repeatedly, the weights of the edges of
+/// every path found being multiplied by `penalty` before the next search.
+/// This pushes later searches away from known routes. A path found this way
+/// is kept as an alternative when it is not much longer than the shortest
+/// path and does not share too much with the routes already kept.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::AlternativeRoutes;
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, WeightedEdge};
+///
+/// let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+/// for id in 0..4 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// for (from, to, weight) in [(0, 1, 1.0), (1, 3, 1.0), (0, 2, 1.5), (2, 3, 1.0)] {
+///     graph.add_edge(WeightedEdge::new(from, to, Some(weight)));
+/// }
+///
+/// let routes = AlternativeRoutes::default().compute(&graph, 0, 3);
+/// assert_eq!(routes.len(), 2);
+/// assert_eq!(routes[1].nodes, vec![0, 2, 3]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlternativeRoutes {
+    count: usize,
+    penalty: f64,
+    max_stretch: f64,
+    max_overlap: f64,
+    max_iterations: usize,
+}
+
+impl Default for AlternativeRoutes {
+    fn default() -> Self {
+        AlternativeRoutes {
+            count: 3,
+            penalty: 1.5,
+            max_stretch: 1.4,
+            max_overlap: 0.7,
+            max_iterations: 20,
+        }
+    }
+}
+
+impl AlternativeRoutes {
+    /// Sets the maximum number of routes, the shortest path included, `3` by default.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets the factor applied to the weights of found edges, `1.5` by default.
+    pub fn penalty(mut self, penalty: f64) -> Self {
+        self.penalty = penalty;
+        self
+    }
+
+    /// Sets the largest accepted ratio between a route's cost and the
+    /// shortest path's cost, `1.4` by default.
+    pub fn max_stretch(mut self, max_stretch: f64) -> Self {
+        self.max_stretch = max_stretch;
+        self
+    }
+
+    /// Sets the largest accepted fraction of a route's cost shared with any
+    /// kept route, `0.7` by default.
+    pub fn max_overlap(mut self, max_overlap: f64) -> Self {
+        self.max_overlap = max_overlap;
+        self
+    }
+
+    /// Sets the maximum number of searches, `20` by default.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Generates alternative routes between two nodes.
+    ///
+    /// Weights must be non-negative, and parallel edges are reduced to the
+    /// cheapest one.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to search
+    /// * `source` - The start node ID
+    /// * `target` - The goal node ID
+    ///
+    /// # Returns
+    ///
+    /// The shortest path followed by the accepted alternatives, in discovery
+    /// order, with their real costs. Empty if the target is unreachable.
+    pub fn compute<TNode, TEdge>(
+        &self,
+        graph: &Graph<TNode, TEdge>,
+        source: u32,
+        target: u32,
+    ) -> Vec<RankedPath>
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let weights = cheapest_edges(graph);
+        let mut penalized = weights.clone();
+        let mut routes: Vec<RankedPath> = Vec::new();
+        if !graph.nodes.contains_key(&source) || !graph.nodes.contains_key(&target) {
+            return routes;
+        }
+
+        for _ in 0..self.max_iterations {
+            if routes.len() >= self.count {
+                break;
+            }
+            let simple = simple_graph(graph, &penalized);
+            let Some(nodes) = shortest_path(&simple, source, target, &[], &HashSet::new()) else {
+                break;
+            };
+            for pair in nodes.windows(2) {
+                if let Some(weight) = penalized.get_mut(&(pair[0], pair[1])) {
+                    *weight *= self.penalty;
+                }
+            }
+
+            let candidate = ranked(nodes, &weights);
+            if self.accepts(&candidate, &routes, &weights) {
+                routes.push(candidate);
+            }
+        }
+
+        routes
+    }
+
+    fn accepts(
+        &self,
+        candidate: &RankedPath,
+        routes: &[RankedPath],
+        weights: &BTreeMap<(u32, u32), f64>,
+    ) -> bool {
+        let Some(shortest) = routes.first() else {
+            return true;
+        };
+        if candidate.cost > shortest.cost * self.max_stretch {
+            return false;
+        }
+
+        routes.iter().all(|route| {
+            if route.nodes == candidate.nodes {
+                return false;
+            }
+            let edges: HashSet<(u32, u32)> = route.nodes.windows(2).map(|p| (p[0], p[1])).collect();
+            let shared: f64 = candidate
+                .nodes
+                .windows(2)
+                .filter(|p| edges.contains(&(p[0], p[1])))
+                .map(|p| weights[&(p[0], p[1])])
+                .sum();
+            candidate.cost > 0.0 && shared / candidate.cost <= self.max_overlap
+        })
+    }
+}
+
+/// Rebuilds a graph with a single edge per connected pair and no self loops.
+fn simple_graph<TNode, TEdge, C>(
+    graph: &Graph<TNode, TEdge>,
+    weights: &BTreeMap<(u32, u32), C>,
+) -> Graph<EmptyNode, WeightedEdge<C>>
+where
+    TNode: Node,
+    TEdge: Edge,
+    C: Cost,
+{
+    let mut simple = Graph::new();
+    for &id in graph.nodes.keys() {
+        simple.add_node(EmptyNode::new(id, None));
+    }
+    for (&(from, to), &weight) in weights {
+        if from != to {
+            simple.add_edge(WeightedEdge::new(from, to, Some(weight)));
+        }
+    }
+    simple
+}
+
+/// Searches the shortest path avoiding banned nodes and edges.
+fn shortest_path<C: Cost>(
+    graph: &Graph<EmptyNode, WeightedEdge<C>>,
+    source: u32,
+    target: u32,
+    banned_nodes: &[u32],
+    banned_edges: &HashSet<(u32, u32)>,
+) -> Option<Vec<u32>> {
+    let mut visitor = RestrictedVisitor::new(WeightedVisitor::new(GoalReached::new(target)));
+    for &node in banned_nodes {
+        visitor.ban_node(node);
+    }
+    for &(from, to) in banned_edges {
+        visitor.ban_edge(from, to);
+    }
+
+    graph.traverse(source, &mut MinHeap::default(), &mut visitor);
+    visitor.cost_to(target)?;
+
+    let mut path = vec![target];
+    while let Some(parent) = visitor.get_parent(*path.last().unwrap()) {
+        path.push(parent);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Attaches the cost of a path, computed from the original weights.
+fn ranked<C: Cost>(nodes: Vec<u32>, weights: &BTreeMap<(u32, u32), C>) -> RankedPath<C> {
+    let cost = nodes.windows(2).fold(C::zero(), |cost, pair| {
+        cost.plus(weights[&(pair[0], pair[1])])
+    });
+    RankedPath { nodes, cost }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+
+    /// Classic example from Yen's paper, C=0 D=1 E=2 F=3 G=4 H=5.
+    fn yen_example() -> Graph<EmptyNode, WeightedEdge<u64>> {
+        graph(
+            6,
+            &[
+                (0, 1, 3),
+                (0, 2, 2),
+                (1, 3, 4),
+                (2, 1, 1),
+                (2, 3, 2),
+                (2, 4, 3),
+                (3, 4, 2),
+                (3, 5, 1),
+                (4, 5, 2),
+            ],
+        )
+    }
+
+    #[test]
+    fn finds_k_shortest_paths_in_order() {
+        let paths = k_shortest_paths(&yen_example(), 0, 5, 3);
+
+        let nodes: Vec<Vec<u32>> = paths.iter().map(|p| p.nodes.clone()).collect();
+        assert_eq!(
+            nodes,
+            vec![vec![0, 2, 3, 5], vec![0, 2, 4, 5], vec![0, 1, 3, 5]]
+        );
+        let costs: Vec<u64> = paths.iter().map(|p| p.cost).collect();
+        assert_eq!(costs, vec![5, 7, 8]);
+    }
+
+    #[test]
+    fn returns_every_loopless_path_when_k_is_large() {
+        let paths = k_shortest_paths(&yen_example(), 0, 5, 100);
+
+        assert_eq!(paths.len(), 7);
+        assert!(paths.windows(2).all(|p| p[0].cost <= p[1].cost));
+        for path in &paths {
+            let unique: HashSet<u32> = path.nodes.iter().copied().collect();
+            assert_eq!(unique.len(), path.nodes.len());
+        }
+    }
+
+    #[test]
+    fn uses_cheapest_parallel_edge_and_skips_self_loops() {
+        let graph = graph(2, &[(0, 0, 1.0), (0, 1, 4.0), (0, 1, 2.0)]);
+
+        let paths = k_shortest_paths(&graph, 0, 1, 2);
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].cost, 2.0);
+    }
+
+    #[test]
+    fn unreachable_target_has_no_path() {
+        let graph = graph(3, &[(0, 1, 1.0)]);
+
+        assert!(k_shortest_paths(&graph, 0, 2, 3).is_empty());
+        assert!(k_shortest_paths(&graph, 0, 9, 3).is_empty());
+        assert!(k_shortest_paths(&graph, 0, 1, 0).is_empty());
+    }
+
+    #[test]
+    fn source_is_its_own_shortest_path() {
+        let paths = k_shortest_paths(&yen_example(), 2, 2, 3);
+
+        assert_eq!(
+            paths,
+            vec![RankedPath {
+                nodes: vec![2],
+                cost: 0
+            }]
+        );
+    }
+
+    /// Two disjoint corridors 0-1-2-5 and 0-3-4-5, plus a shortcut 1-4.
+    fn corridors() -> Graph<EmptyNode, WeightedEdge> {
+        graph(
+            6,
+            &[
+                (0, 1, 1.0),
+                (1, 2, 1.0),
+                (2, 5, 1.0),
+                (0, 3, 1.2),
+                (3, 4, 1.0),
+                (4, 5, 1.0),
+                (1, 4, 0.9),
+            ],
+        )
+    }
+
+    #[test]
+    fn alternatives_avoid_known_routes() {
+        let routes = AlternativeRoutes::default()
+            .max_overlap(0.5)
+            .compute(&corridors(), 0, 5);
+
+        let nodes: Vec<Vec<u32>> = routes.iter().map(|r| r.nodes.clone()).collect();
+        // The shortcut route shares too much with both corridors
+        assert_eq!(
+            nodes,
+            vec![vec![0, 1, 4, 5], vec![0, 1, 2, 5], vec![0, 3, 4, 5]]
+        );
+        assert_eq!(routes[0].cost, 2.9);
+    }
+
+    #[test]
+    fn alternatives_respect_stretch() {
+        let routes = AlternativeRoutes::default()
+            .max_stretch(1.01)
+            .compute(&corridors(), 0, 5);
+
+        assert_eq!(routes.len(), 1);
+    }
+
+    #[test]
+    fn alternatives_respect_count() {
+        let routes = AlternativeRoutes::default()
+            .count(2)
+            .max_overlap(1.0)
+            .compute(&corridors(), 0, 5);
+
+        assert_eq!(routes.len(), 2);
+    }
+}
@@ -0,0 +1,705 @@
+use crate::frontier::MinHeap;
+use crate::graph::{Edge, Graph, Node};
+use crate::preset::policies::traversal::NoTermination;
+use crate::preset::visitors::{TrackCost, TrackParent, WeightedVisitor};
+use crate::preset::{EmptyNode, WeightedEdge};
+use crate::rng::SeededRng;
+use crate::strategy::{Heuristic, PairwiseHeuristic};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::all_pairs::cheapest_edges;
+
+/// Rule used to place landmarks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LandmarkSelection {
+    /// Each landmark is the node farthest from the landmarks already chosen
+    #[default]
+    Farthest,
+    /// Goldberg and Harrelson's avoid rule: each landmark is a leaf of a
+    /// shortest path tree, in the region where current bounds are weakest
+    Avoid,
+}
+
+/// Error returned when parsing a landmark table fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLandmarksError {
+    /// One-based line number of the offending line
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseLandmarksError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid landmark table at line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl Error for ParseLandmarksError {}
+
+/// Precomputed distances between landmarks and every node, for ALT searches.
+///
+/// By the triangle inequality, `d(L, t) - d(L, v)` and `d(v, L) - d(t, L)`
+/// are lower bounds of `d(v, t)` for any landmark `L`. Taking the best bound
+/// over a few well placed landmarks gives an admissible and consistent A*
+/// heuristic on any graph with non-negative weights, geometric or not.
+///
+/// Tables can be stored as text with `to_string` and loaded back with `parse`.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::Landmarks;
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, WeightedEdge};
+///
+/// let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+/// for id in 0..4 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// for id in 0..3 {
+///     graph.add_edge(WeightedEdge::new(id, id + 1, Some(2.0)));
+/// }
+///
+/// let landmarks = Landmarks::new(&graph, &[3]);
+/// assert_eq!(landmarks.lower_bound(0, 2), 4.0);
+///
+/// let stored: Landmarks = landmarks.to_string().parse().unwrap();
+/// assert_eq!(stored.lower_bound(0, 2), 4.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Landmarks {
+    landmarks: Vec<u32>,
+    /// Node IDs, sorted, giving the column order of the tables
+    ids: Vec<u32>,
+    index: HashMap<u32, usize>,
+    /// Distance from each landmark to each node
+    from_landmark: Vec<Vec<Option<f64>>>,
+    /// Distance from each node to each landmark
+    to_landmark: Vec<Vec<Option<f64>>>,
+}
+
+impl Landmarks {
+    /// Computes the distance tables of the given landmarks.
+    ///
+    /// Runs two Dijkstra searches per landmark, one on the graph and one on
+    /// its reverse. Unknown landmark IDs are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph, with non-negative weights
+    /// * `landmarks` - IDs of the landmark nodes
+    pub fn new<TNode, TEdge>(graph: &Graph<TNode, TEdge>, landmarks: &[u32]) -> Self
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let networks = Networks::new(graph);
+        let mut tables = Landmarks::empty(networks.ids.clone());
+        for &landmark in landmarks {
+            if tables.index.contains_key(&landmark) && !tables.landmarks.contains(&landmark) {
+                tables.push(&networks, landmark);
+            }
+        }
+        tables
+    }
+
+    fn empty(ids: Vec<u32>) -> Self {
+        let index = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        Landmarks {
+            landmarks: Vec::new(),
+            ids,
+            index,
+            from_landmark: Vec::new(),
+            to_landmark: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, networks: &Networks, landmark: u32) {
+        self.landmarks.push(landmark);
+        self.from_landmark
+            .push(networks.distances(&networks.forward, landmark));
+        self.to_landmark
+            .push(networks.distances(&networks.backward, landmark));
+    }
+
+    /// Returns the IDs of the landmarks, in selection order.
+    pub fn landmarks(&self) -> &[u32] {
+        &self.landmarks
+    }
+
+    /// Returns the distance from a landmark to a node, `None` if unreachable or unknown.
+    pub fn from_landmark(&self, landmark: u32, node_id: u32) -> Option<f64> {
+        let l = self.landmarks.iter().position(|&id| id == landmark)?;
+        self.from_landmark[l][*self.index.get(&node_id)?]
+    }
+
+    /// Returns the distance from a node to a landmark, `None` if unreachable or unknown.
+    pub fn to_landmark(&self, node_id: u32, landmark: u32) -> Option<f64> {
+        let l = self.landmarks.iter().position(|&id| id == landmark)?;
+        self.to_landmark[l][*self.index.get(&node_id)?]
+    }
+
+    /// Returns the best lower bound of the distance between two nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    ///
+    /// # Returns
+    ///
+    /// The bound, zero for unknown nodes, or infinity when some landmark
+    /// proves that `to` cannot be reached from `from`
+    pub fn lower_bound(&self, from: u32, to: u32) -> f64 {
+        match (self.index.get(&from), self.index.get(&to)) {
+            (Some(&i), Some(&j)) => self.bound(i, j),
+            _ => 0.0,
+        }
+    }
+
+    fn bound(&self, i: usize, j: usize) -> f64 {
+        let mut best = 0.0_f64;
+        for (from, to) in self.from_landmark.iter().zip(&self.to_landmark) {
+            // A landmark reaching i but not j proves j unreachable from i,
+            // and so does j reaching a landmark that i cannot reach
+            let forward = match (from[i], from[j]) {
+                (Some(li), Some(lj)) => lj - li,
+                (Some(_), None) => f64::INFINITY,
+                _ => 0.0,
+            };
+            let backward = match (to[i], to[j]) {
+                (Some(il), Some(jl)) => il - jl,
+                (None, Some(_)) => f64::INFINITY,
+                _ => 0.0,
+            };
+            best = best.max(forward).max(backward);
+        }
+        best
+    }
+
+    /// Returns the A* heuristic estimating distances to a target.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The goal node ID
+    pub fn towards(&self, target: u32) -> LandmarkHeuristic<'_> {
+        LandmarkHeuristic {
+            landmarks: self,
+            target: self.index.get(&target).copied(),
+        }
+    }
+}
+
+/// Landmark lower bounds between any two nodes, as returned by `lower_bound`.
+impl PairwiseHeuristic<f64> for Landmarks {
+    fn estimate_between(&self, from: u32, to: u32) -> f64 {
+        self.lower_bound(from, to)
+    }
+}
+
+impl fmt::Display for Landmarks {
+    /// Writes the tables as text.
+    ///
+    /// The first line lists the landmarks. Each following line holds a node
+    /// ID, then for every landmark the distances from and to it, with `-`
+    /// for unreachable pairs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "landmarks")?;
+        for landmark in &self.landmarks {
+            write!(f, " {landmark}")?;
+        }
+        for (i, id) in self.ids.iter().enumerate() {
+            write!(f, "\n{id}")?;
+            for (from, to) in self.from_landmark.iter().zip(&self.to_landmark) {
+                for distance in [from[i], to[i]] {
+                    match distance {
+                        Some(distance) => write!(f, " {distance}")?,
+                        None => write!(f, " -")?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Landmarks {
+    type Err = ParseLandmarksError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = |line: usize, message: &str| ParseLandmarksError {
+            line,
+            message: message.to_string(),
+        };
+        let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        let (_, header) = lines.next().ok_or_else(|| error(1, "missing header"))?;
+        let mut header = header.split_whitespace();
+        if header.next() != Some("landmarks") {
+            return Err(error(1, "expected `landmarks` header"));
+        }
+        let landmarks = header
+            .map(|id| id.parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|_| error(1, "invalid landmark ID"))?;
+
+        let mut rows: Vec<(u32, Vec<Option<f64>>)> = Vec::new();
+        for (line, row) in lines.filter(|(_, row)| !row.trim().is_empty()) {
+            let mut fields = row.split_whitespace();
+            let id = fields
+                .next()
+                .and_then(|id| id.parse::<u32>().ok())
+                .ok_or_else(|| error(line, "invalid node ID"))?;
+            let distances = fields
+                .map(|field| match field {
+                    "-" => Ok(None),
+                    distance => distance.parse::<f64>().map(Some),
+                })
+                .collect::<Result<Vec<Option<f64>>, _>>()
+                .map_err(|_| error(line, "invalid distance"))?;
+            if distances.len() != 2 * landmarks.len() {
+                return Err(error(line, "expected two distances per landmark"));
+            }
+            rows.push((id, distances));
+        }
+        rows.sort_unstable_by_key(|(id, _)| *id);
+        if rows.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(error(1, "duplicate node ID"));
+        }
+
+        let mut tables = Landmarks::empty(rows.iter().map(|(id, _)| *id).collect());
+        if let Some(missing) = landmarks.iter().find(|id| !tables.index.contains_key(id)) {
+            return Err(error(1, &format!("landmark {missing} has no row")));
+        }
+        for l in 0..landmarks.len() {
+            tables
+                .from_landmark
+                .push(rows.iter().map(|(_, d)| d[2 * l]).collect());
+            tables
+                .to_landmark
+                .push(rows.iter().map(|(_, d)| d[2 * l + 1]).collect());
+        }
+        tables.landmarks = landmarks;
+        Ok(tables)
+    }
+}
+
+/// Landmark lower bounds towards a fixed target, usable as an A* heuristic.
+///
+/// Created by `Landmarks::towards`.
+#[derive(Debug, Clone, Copy)]
+pub struct LandmarkHeuristic<'a> {
+    landmarks: &'a Landmarks,
+    target: Option<usize>,
+}
+
+impl Heuristic<f64> for LandmarkHeuristic<'_> {
+    fn estimate(&self, node_id: u32) -> f64 {
+        match (self.landmarks.index.get(&node_id), self.target) {
+            (Some(&i), Some(j)) => self.landmarks.bound(i, j),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Chooses landmarks and computes their distance tables.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::{LandmarkSelection, LandmarkSelector};
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, WeightedEdge};
+///
+/// let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+/// for id in 0..5 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// for id in 0..4 {
+///     graph.add_edge(WeightedEdge::new(id, id + 1, Some(1.0)));
+///     graph.add_edge(WeightedEdge::new(id + 1, id, Some(1.0)));
+/// }
+///
+/// let landmarks = LandmarkSelector::default()
+///     .count(2)
+///     .strategy(LandmarkSelection::Farthest)
+///     .select(&graph);
+/// assert_eq!(landmarks.landmarks().len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LandmarkSelector {
+    count: usize,
+    strategy: LandmarkSelection,
+    seed: u64,
+}
+
+impl Default for LandmarkSelector {
+    fn default() -> Self {
+        LandmarkSelector {
+            count: 8,
+            strategy: LandmarkSelection::default(),
+            seed: 0,
+        }
+    }
+}
+
+impl LandmarkSelector {
+    /// Sets the number of landmarks, capped by the number of nodes.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets the rule used to place landmarks.
+    pub fn strategy(mut self, strategy: LandmarkSelection) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the seed drawing the nodes selection starts from.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Selects landmarks on a graph and computes their distance tables.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph, with non-negative weights
+    pub fn select<TNode, TEdge>(&self, graph: &Graph<TNode, TEdge>) -> Landmarks
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let networks = Networks::new(graph);
+        let mut tables = Landmarks::empty(networks.ids.clone());
+        let mut rng = SeededRng::new(self.seed);
+        let count = self.count.min(networks.ids.len());
+
+        while tables.landmarks.len() < count {
+            let root = networks.ids[rng.next_below(networks.ids.len())];
+            let landmark = match self.strategy {
+                LandmarkSelection::Avoid => avoid(&networks, &tables, root),
+                LandmarkSelection::Farthest => None,
+            }
+            .unwrap_or_else(|| farthest(&networks, &tables, root));
+            tables.push(&networks, landmark);
+        }
+        tables
+    }
+}
+
+/// Returns the node farthest from the current landmarks, or from `root` if there are none.
+///
+/// Distances are taken from the landmarks to the nodes, unreachable nodes
+/// being the farthest of all.
+fn farthest(networks: &Networks, tables: &Landmarks, root: u32) -> u32 {
+    let scores: Vec<f64> = if tables.landmarks.is_empty() {
+        networks
+            .distances(&networks.forward, root)
+            .into_iter()
+            .map(|d| d.unwrap_or(f64::NEG_INFINITY))
+            .collect()
+    } else {
+        (0..networks.ids.len())
+            .map(|i| {
+                tables
+                    .from_landmark
+                    .iter()
+                    .map(|from| from[i].unwrap_or(f64::INFINITY))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .collect()
+    };
+
+    let mut best: Option<usize> = None;
+    for (i, &score) in scores.iter().enumerate() {
+        if tables.landmarks.contains(&networks.ids[i]) {
+            continue;
+        }
+        if best.is_none_or(|b| score > scores[b]) {
+            best = Some(i);
+        }
+    }
+    networks.ids[best.expect("fewer landmarks than nodes")]
+}
+
+/// Applies the avoid rule from `root`.
+///
+/// Each node of the shortest path tree from `root` weighs the gap between
+/// its distance and the current lower bound. Subtrees holding a landmark
+/// weigh nothing. Descending from the root into the heaviest subtree down
+/// to a leaf gives the next landmark.
+///
+/// # Returns
+///
+/// `None` when every subtree weighs nothing
+fn avoid(networks: &Networks, tables: &Landmarks, root: u32) -> Option<u32> {
+    let mut visitor = WeightedVisitor::new(NoTermination);
+    networks
+        .forward
+        .traverse(root, &mut MinHeap::new(), &mut visitor);
+    let r = networks.index[&root];
+
+    let n = networks.ids.len();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (i, &id) in networks.ids.iter().enumerate() {
+        if let Some(parent) = visitor.get_parent(id) {
+            children[networks.index[&parent]].push(i);
+        }
+    }
+
+    let mut order = vec![r];
+    let mut pending = vec![r];
+    while let Some(i) = pending.pop() {
+        for &child in &children[i] {
+            order.push(child);
+            pending.push(child);
+        }
+    }
+
+    let mut size = vec![0.0_f64; n];
+    let mut holds_landmark = vec![false; n];
+    for &i in order.iter().rev() {
+        let id = networks.ids[i];
+        let distance = visitor.cost_to(id).unwrap_or(0.0);
+        holds_landmark[i] = tables.landmarks.contains(&id)
+            || children[i].iter().any(|&child| holds_landmark[child]);
+        if !holds_landmark[i] {
+            let weight = distance - tables.bound(r, i);
+            size[i] = weight + children[i].iter().map(|&child| size[child]).sum::<f64>();
+        }
+    }
+
+    let mut current = r;
+    loop {
+        let heaviest = children[current]
+            .iter()
+            .copied()
+            .filter(|&child| size[child] > 0.0)
+            .max_by(|&a, &b| size[a].total_cmp(&size[b]).then(b.cmp(&a)));
+        match heaviest {
+            Some(child) => current = child,
+            None if current == r => return None,
+            None => return Some(networks.ids[current]),
+        }
+    }
+}
+
+/// Simple copies of a graph and of its reverse, searched from each landmark.
+///
+/// Parallel edges are reduced to the cheapest one and self loops dropped.
+struct Networks {
+    ids: Vec<u32>,
+    index: HashMap<u32, usize>,
+    forward: Graph<EmptyNode, WeightedEdge>,
+    backward: Graph<EmptyNode, WeightedEdge>,
+}
+
+impl Networks {
+    fn new<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Self
+    where
+        TNode: Node,
+        TEdge: Edge<Weight = f64>,
+    {
+        let mut ids: Vec<u32> = graph.nodes.keys().copied().collect();
+        ids.sort_unstable();
+        let index = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut forward = Graph::new();
+        let mut backward = Graph::new();
+        for &id in &ids {
+            forward.add_node(EmptyNode::new(id, None));
+            backward.add_node(EmptyNode::new(id, None));
+        }
+        for ((from, to), weight) in cheapest_edges(graph) {
+            if from != to {
+                forward.add_edge(WeightedEdge::new(from, to, Some(weight)));
+                backward.add_edge(WeightedEdge::new(to, from, Some(weight)));
+            }
+        }
+
+        Networks {
+            ids,
+            index,
+            forward,
+            backward,
+        }
+    }
+
+    /// Returns the distance from `source` to each node of a network.
+    fn distances(&self, network: &Graph<EmptyNode, WeightedEdge>, source: u32) -> Vec<Option<f64>> {
+        let mut visitor = WeightedVisitor::new(NoTermination);
+        network.traverse(source, &mut MinHeap::new(), &mut visitor);
+        self.ids.iter().map(|&id| visitor.cost_to(id)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::floyd_warshall;
+    use crate::frontier::{Frontier, Instrumented};
+    use crate::preset::policies::traversal::GoalReached;
+    use crate::preset::visitors::HeuristicVisitor;
+
+    /// Grid of `side * side` nodes with 4-connected edges in both directions,
+    /// weighted by a deterministic pattern.
+    fn grid(side: u32) -> Graph<EmptyNode, WeightedEdge> {
+        let mut graph = Graph::new();
+        for id in 0..side * side {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for id in 0..side * side {
+            let weight = 1.0 + ((id * 7) % 5) as f64;
+            if id % side + 1 < side {
+                graph.add_edge(WeightedEdge::new(id, id + 1, Some(weight)));
+                graph.add_edge(WeightedEdge::new(id + 1, id, Some(weight)));
+            }
+            if id + side < side * side {
+                graph.add_edge(WeightedEdge::new(id, id + side, Some(weight)));
+                graph.add_edge(WeightedEdge::new(id + side, id, Some(weight)));
+            }
+        }
+        graph
+    }
+
+    fn chain() -> Graph<EmptyNode, WeightedEdge> {
+        let mut graph = Graph::new();
+        for id in 0..4 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for id in 0..3 {
+            graph.add_edge(WeightedEdge::new(id, id + 1, Some(2.0)));
+        }
+        graph.add_edge(WeightedEdge::new(1, 1, Some(1.0)));
+        graph
+    }
+
+    #[test]
+    fn tables_hold_distances_both_ways() {
+        let landmarks = Landmarks::new(&chain(), &[1, 1, 9]);
+
+        assert_eq!(landmarks.landmarks(), &[1]);
+        assert_eq!(landmarks.from_landmark(1, 3), Some(4.0));
+        assert_eq!(landmarks.from_landmark(1, 0), None);
+        assert_eq!(landmarks.to_landmark(0, 1), Some(2.0));
+        assert_eq!(landmarks.to_landmark(1, 1), Some(0.0));
+    }
+
+    #[test]
+    fn bounds_detect_unreachable_targets() {
+        let landmarks = Landmarks::new(&chain(), &[1]);
+
+        assert_eq!(landmarks.lower_bound(1, 3), 4.0);
+        assert_eq!(landmarks.lower_bound(0, 1), 2.0);
+        assert_eq!(landmarks.lower_bound(3, 0), f64::INFINITY);
+        assert_eq!(landmarks.lower_bound(0, 9), 0.0);
+    }
+
+    #[test]
+    fn bounds_never_exceed_distances() {
+        let graph = grid(6);
+        let matrix = floyd_warshall(&graph).unwrap();
+
+        for strategy in [LandmarkSelection::Farthest, LandmarkSelection::Avoid] {
+            let landmarks = LandmarkSelector::default()
+                .count(4)
+                .strategy(strategy)
+                .seed(3)
+                .select(&graph);
+            assert_eq!(landmarks.landmarks().len(), 4);
+
+            for from in 0..36 {
+                for to in 0..36 {
+                    let distance = matrix.distance(from, to).unwrap();
+                    assert!(landmarks.lower_bound(from, to) <= distance + 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn farthest_selection_spreads_over_the_periphery() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        for id in 0..7 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for id in 0..6 {
+            graph.add_edge(WeightedEdge::new(id, id + 1, Some(1.0)));
+            graph.add_edge(WeightedEdge::new(id + 1, id, Some(1.0)));
+        }
+
+        let landmarks = LandmarkSelector::default().count(2).select(&graph);
+
+        let mut chosen = landmarks.landmarks().to_vec();
+        chosen.sort_unstable();
+        assert_eq!(chosen, vec![0, 6]);
+    }
+
+    #[test]
+    fn avoid_selection_picks_distinct_leaves() {
+        let graph = grid(5);
+
+        let landmarks = LandmarkSelector::default()
+            .count(5)
+            .strategy(LandmarkSelection::Avoid)
+            .select(&graph);
+
+        let mut chosen = landmarks.landmarks().to_vec();
+        chosen.sort_unstable();
+        chosen.dedup();
+        assert_eq!(chosen.len(), 5);
+    }
+
+    #[test]
+    fn heuristic_guides_astar_to_shortest_path() {
+        let graph = grid(8);
+        let landmarks = LandmarkSelector::default().count(4).select(&graph);
+
+        let mut dijkstra = WeightedVisitor::new(GoalReached::new(63));
+        let mut uninformed = Instrumented::<MinHeap>::new();
+        graph.traverse(0, &mut uninformed, &mut dijkstra);
+
+        let mut alt = HeuristicVisitor::new(
+            WeightedVisitor::new(GoalReached::new(63)),
+            landmarks.towards(63),
+        );
+        let mut informed = Instrumented::<MinHeap>::new();
+        graph.traverse(0, &mut informed, &mut alt);
+
+        assert_eq!(alt.cost_to(63), dijkstra.cost_to(63));
+        assert!(informed.stats().pops < uninformed.stats().pops);
+    }
+
+    #[test]
+    fn tables_round_trip_through_text() {
+        let landmarks = Landmarks::new(&chain(), &[1, 3]);
+
+        let text = landmarks.to_string();
+        assert!(text.starts_with("landmarks 1 3\n0 - 2 - 6\n"));
+        assert_eq!(text.parse::<Landmarks>(), Ok(landmarks));
+    }
+
+    #[test]
+    fn rejects_malformed_tables() {
+        let error = |text: &str| text.parse::<Landmarks>().unwrap_err();
+
+        assert_eq!(error("").line, 1);
+        assert_eq!(error("nodes 1").line, 1);
+        assert_eq!(error("landmarks 1\n1 0 0\n2 0").line, 3);
+        assert_eq!(error("landmarks 1\n1 0 x").line, 2);
+        assert_eq!(
+            error("landmarks 4\n1 0 0").to_string(),
+            "invalid landmark table at line 1: landmark 4 has no row"
+        );
+    }
+}
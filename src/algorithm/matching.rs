@@ -0,0 +1,609 @@
+use crate::graph::{Edge, Graph, Node};
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+use super::adjacency::Adjacency;
+
+/// Error reported when a graph is not bipartite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OddCycle {
+    /// Node IDs of a cycle of odd length, without repeating the first node
+    pub cycle: Vec<u32>,
+}
+
+impl fmt::Display for OddCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: Vec<String> = self.cycle.iter().map(u32::to_string).collect();
+        write!(
+            f,
+            "graph is not bipartite, odd cycle: {}",
+            nodes.join(" - ")
+        )
+    }
+}
+
+impl Error for OddCycle {}
+
+/// A 2-coloring of a graph: every edge links a left node to a right node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bipartition {
+    /// Color of each node, `0` for left and `1` for right
+    colors: HashMap<u32, usize>,
+}
+
+impl Bipartition {
+    /// Returns the color of a node: `0` for left, `1` for right, `None` if unknown.
+    pub fn color(&self, node_id: u32) -> Option<usize> {
+        self.colors.get(&node_id).copied()
+    }
+
+    /// Returns the IDs of the left nodes, sorted.
+    pub fn left(&self) -> Vec<u32> {
+        self.side(0)
+    }
+
+    /// Returns the IDs of the right nodes, sorted.
+    pub fn right(&self) -> Vec<u32> {
+        self.side(1)
+    }
+
+    fn side(&self, color: usize) -> Vec<u32> {
+        let mut side: Vec<u32> = self
+            .colors
+            .iter()
+            .filter(|&(_, &c)| c == color)
+            .map(|(&id, _)| id)
+            .collect();
+        side.sort_unstable();
+        side
+    }
+}
+
+/// A set of edges without common nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matching {
+    /// Matched pairs as `(left, right)`, sorted by left node
+    pairs: Vec<(u32, u32)>,
+}
+
+impl Matching {
+    /// Returns the matched pairs as `(left, right)`, sorted by left node.
+    pub fn pairs(&self) -> &[(u32, u32)] {
+        &self.pairs
+    }
+
+    /// Returns the number of matched pairs.
+    pub fn size(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns the node matched with a node, on either side.
+    pub fn mate(&self, node_id: u32) -> Option<u32> {
+        self.pairs.iter().find_map(|&(left, right)| match node_id {
+            id if id == left => Some(right),
+            id if id == right => Some(left),
+            _ => None,
+        })
+    }
+}
+
+/// A minimum cost matching.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    /// Assigned pairs as `(left, right, cost)`, sorted by left node
+    pairs: Vec<(u32, u32, f64)>,
+    total_cost: f64,
+}
+
+impl Assignment {
+    /// Returns the assigned pairs as `(left, right, cost)`, sorted by left node.
+    pub fn pairs(&self) -> &[(u32, u32, f64)] {
+        &self.pairs
+    }
+
+    /// Returns the number of assigned pairs.
+    pub fn size(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns the sum of the costs of the assigned pairs.
+    pub fn total_cost(&self) -> f64 {
+        self.total_cost
+    }
+}
+
+/// Undirected adjacency: each node lists every neighbor, whatever the edge direction.
+fn undirected<W: Copy>(adjacency: &Adjacency<W>) -> Vec<Vec<usize>> {
+    let mut neighbors = vec![Vec::new(); adjacency.len()];
+    for (i, targets) in adjacency.successors.iter().enumerate() {
+        for &(j, _) in targets {
+            neighbors[i].push(j);
+            neighbors[j].push(i);
+        }
+    }
+    neighbors
+}
+
+/// Colors nodes by index, or returns an odd cycle as indices.
+fn two_color(neighbors: &[Vec<usize>]) -> Result<Vec<usize>, Vec<usize>> {
+    let n = neighbors.len();
+    let mut colors: Vec<Option<usize>> = vec![None; n];
+    let mut parents: Vec<Option<usize>> = vec![None; n];
+
+    for root in 0..n {
+        if colors[root].is_some() {
+            continue;
+        }
+        colors[root] = Some(0);
+        let mut pending = VecDeque::from([root]);
+
+        while let Some(node) = pending.pop_front() {
+            let color = colors[node].unwrap();
+            for &next in &neighbors[node] {
+                match colors[next] {
+                    None => {
+                        colors[next] = Some(1 - color);
+                        parents[next] = Some(node);
+                        pending.push_back(next);
+                    }
+                    Some(c) if c == color => {
+                        return Err(odd_cycle(&parents, node, next));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Ok(colors.into_iter().flatten().collect())
+}
+
+/// Joins the tree paths of two same-colored neighbors into an odd cycle.
+fn odd_cycle(parents: &[Option<usize>], a: usize, b: usize) -> Vec<usize> {
+    let ancestors = |mut node: usize| {
+        let mut chain = vec![node];
+        while let Some(parent) = parents[node] {
+            chain.push(parent);
+            node = parent;
+        }
+        chain
+    };
+    let (mut from_a, mut from_b) = (ancestors(a), ancestors(b));
+
+    // Drop the common ancestors but the lowest one
+    while from_a.len() > 1
+        && from_b.len() > 1
+        && from_a[from_a.len() - 2] == from_b[from_b.len() - 2]
+    {
+        from_a.pop();
+        from_b.pop();
+    }
+    from_b.pop();
+    from_b.reverse();
+    from_a.extend(from_b);
+    from_a
+}
+
+/// Checks whether a graph is bipartite, ignoring edge directions.
+///
+/// Each connected component is colored with a breadth-first search, its
+/// smallest node ID being colored left.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to color
+///
+/// # Errors
+///
+/// Returns an `OddCycle` witness if the graph is not bipartite. Self loops
+/// are odd cycles of length one.
+pub fn bipartition<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Result<Bipartition, OddCycle>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+
+    match two_color(&undirected(&adjacency)) {
+        Ok(colors) => Ok(Bipartition {
+            colors: adjacency.ids.iter().copied().zip(colors).collect(),
+        }),
+        Err(cycle) => Err(OddCycle {
+            cycle: cycle.into_iter().map(|i| adjacency.ids[i]).collect(),
+        }),
+    }
+}
+
+/// Computes a maximum cardinality matching with the Hopcroft–Karp algorithm.
+///
+/// The graph must be bipartite, edge directions and weights are ignored.
+/// Each phase finds a maximal set of shortest augmenting paths, so the
+/// algorithm runs in `O(m·√n)`.
+///
+/// # Arguments
+///
+/// * `graph` - The bipartite graph
+///
+/// # Errors
+///
+/// Returns an `OddCycle` witness if the graph is not bipartite.
+pub fn hopcroft_karp<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Result<Matching, OddCycle>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let neighbors = undirected(&adjacency);
+    let colors = two_color(&neighbors).map_err(|cycle| OddCycle {
+        cycle: cycle.into_iter().map(|i| adjacency.ids[i]).collect(),
+    })?;
+
+    let n = adjacency.len();
+    let left: Vec<usize> = (0..n).filter(|&i| colors[i] == 0).collect();
+    let mut mates: Vec<Option<usize>> = vec![None; n];
+    let mut distances: Vec<Option<usize>> = vec![None; n];
+
+    loop {
+        // Layer left nodes by alternating path length from free left nodes
+        let mut pending = VecDeque::new();
+        for &u in &left {
+            distances[u] = match mates[u] {
+                None => {
+                    pending.push_back(u);
+                    Some(0)
+                }
+                Some(_) => None,
+            };
+        }
+        let mut found = false;
+        while let Some(u) = pending.pop_front() {
+            for &v in &neighbors[u] {
+                match mates[v] {
+                    None => found = true,
+                    Some(w) if distances[w].is_none() => {
+                        distances[w] = distances[u].map(|d| d + 1);
+                        pending.push_back(w);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+
+        for &root in &left {
+            if mates[root].is_none() {
+                augment_from(root, &neighbors, &mut mates, &mut distances);
+            }
+        }
+    }
+
+    let mut pairs: Vec<(u32, u32)> = left
+        .iter()
+        .filter_map(|&u| mates[u].map(|v| (adjacency.ids[u], adjacency.ids[v])))
+        .collect();
+    pairs.sort_unstable();
+    Ok(Matching { pairs })
+}
+
+/// Searches an augmenting path along the layers and applies it.
+fn augment_from(
+    root: usize,
+    neighbors: &[Vec<usize>],
+    mates: &mut [Option<usize>],
+    distances: &mut [Option<usize>],
+) -> bool {
+    let mut stack = vec![(root, 0)];
+
+    while let Some((u, position)) = stack.last_mut() {
+        let u = *u;
+        let Some(&v) = neighbors[u].get(*position) else {
+            // Dead end: exclude this node from the rest of the phase
+            distances[u] = None;
+            stack.pop();
+            continue;
+        };
+        *position += 1;
+
+        match mates[v] {
+            None => {
+                for &(u, position) in &stack {
+                    let v = neighbors[u][position - 1];
+                    mates[u] = Some(v);
+                    mates[v] = Some(u);
+                }
+                return true;
+            }
+            Some(w) if distances[w].is_some() && distances[w] == distances[u].map(|d| d + 1) => {
+                stack.push((w, 0));
+            }
+            Some(_) => {}
+        }
+    }
+    false
+}
+
+/// Computes a minimum cost assignment with the Hungarian algorithm.
+///
+/// The graph must be bipartite, edge directions are ignored and weights are
+/// assignment costs (use negated weights to maximize). The assignment has
+/// maximum cardinality first, and minimum total cost among those. When
+/// several edges link the same pair, the cheapest one is used.
+///
+/// Runs in `O(n²·m)` where `n` and `m` are the sizes of the smaller and
+/// larger sides.
+///
+/// # Arguments
+///
+/// * `graph` - The bipartite graph
+///
+/// # Errors
+///
+/// Returns an `OddCycle` witness if the graph is not bipartite.
+pub fn hungarian<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Result<Assignment, OddCycle>
+where
+    TNode: Node,
+    TEdge: Edge<Weight = f64>,
+{
+    let partition = bipartition(graph)?;
+    let (left, right) = (partition.left(), partition.right());
+    let transposed = left.len() > right.len();
+    let (rows, columns) = if transposed {
+        (&right, &left)
+    } else {
+        (&left, &right)
+    };
+
+    let row_index: HashMap<u32, usize> = rows.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+    let column_index: HashMap<u32, usize> =
+        columns.iter().enumerate().map(|(j, &id)| (id, j)).collect();
+
+    let mut costs: Vec<Vec<Option<f64>>> = vec![vec![None; columns.len()]; rows.len()];
+    let mut penalty = 1.0;
+    for edge in graph.edges.values().flatten() {
+        let (a, b) = (edge.from(), edge.to());
+        let (i, j) = match (row_index.get(&a), column_index.get(&b)) {
+            (Some(&i), Some(&j)) => (i, j),
+            _ => match (row_index.get(&b), column_index.get(&a)) {
+                (Some(&i), Some(&j)) => (i, j),
+                _ => continue,
+            },
+        };
+        let weight = edge.weight();
+        penalty += weight.abs();
+        costs[i][j] = Some(costs[i][j].map_or(weight, |current: f64| current.min(weight)));
+    }
+
+    // Missing pairs cost more than any set of real pairs, so they are only
+    // used when a row cannot be assigned otherwise, and then discarded.
+    let matrix: Vec<Vec<f64>> = costs
+        .iter()
+        .map(|row| row.iter().map(|cost| cost.unwrap_or(penalty)).collect())
+        .collect();
+
+    let mut pairs: Vec<(u32, u32, f64)> = kuhn_munkres(&matrix)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, j)| {
+            let cost = costs[i][j?]?;
+            let (row, column) = (rows[i], columns[j?]);
+            Some(if transposed {
+                (column, row, cost)
+            } else {
+                (row, column, cost)
+            })
+        })
+        .collect();
+    pairs.sort_by_key(|&(left, _, _)| left);
+
+    let total_cost = pairs.iter().map(|&(_, _, cost)| cost).sum();
+    Ok(Assignment { pairs, total_cost })
+}
+
+/// Assigns each row of a cost matrix to a distinct column, minimizing the total cost.
+///
+/// Requires no more rows than columns. Returns the column of each row.
+fn kuhn_munkres(costs: &[Vec<f64>]) -> Vec<Option<usize>> {
+    let n = costs.len();
+    let m = costs.first().map_or(0, Vec::len);
+
+    // Potentials and column assignments use 1-based indices, 0 being a sentinel
+    let mut row_potentials = vec![0.0; n + 1];
+    let mut column_potentials = vec![0.0; m + 1];
+    let mut column_rows = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        column_rows[0] = i;
+        let mut j0 = 0;
+        let mut min_slack = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = column_rows[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let slack = costs[i0 - 1][j - 1] - row_potentials[i0] - column_potentials[j];
+                if slack < min_slack[j] {
+                    min_slack[j] = slack;
+                    way[j] = j0;
+                }
+                if min_slack[j] < delta {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+
+            for j in 0..=m {
+                if used[j] {
+                    row_potentials[column_rows[j]] += delta;
+                    column_potentials[j] -= delta;
+                } else {
+                    min_slack[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if column_rows[j0] == 0 {
+                break;
+            }
+        }
+
+        loop {
+            let j1 = way[j0];
+            column_rows[j0] = column_rows[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut assignment = vec![None; n];
+    for j in 1..=m {
+        if column_rows[j] != 0 {
+            assignment[column_rows[j] - 1] = Some(j - 1);
+        }
+    }
+    assignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    fn unit(node_count: u32, edges: &[(u32, u32)]) -> Graph<EmptyNode, WeightedEdge> {
+        let edges: Vec<(u32, u32, f64)> = edges.iter().map(|&(a, b)| (a, b, 1.0)).collect();
+        graph(node_count, &edges)
+    }
+
+    #[test]
+    fn colors_bipartite_graphs() {
+        let partition = bipartition(&unit(5, &[(0, 1), (2, 1), (2, 3), (4, 3)])).unwrap();
+
+        assert_eq!(partition.left(), vec![0, 2, 4]);
+        assert_eq!(partition.right(), vec![1, 3]);
+        assert_eq!(partition.color(3), Some(1));
+        assert_eq!(partition.color(7), None);
+    }
+
+    #[test]
+    fn even_cycles_are_bipartite() {
+        assert!(bipartition(&unit(4, &[(0, 1), (1, 2), (2, 3), (3, 0)])).is_ok());
+    }
+
+    #[test]
+    fn reports_odd_cycle_witness() {
+        let graph = unit(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (4, 5)]);
+
+        let cycle = bipartition(&graph).unwrap_err().cycle;
+
+        assert_eq!(cycle.len() % 2, 1);
+        for (i, &node) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            let linked = graph
+                .get_edges()
+                .iter()
+                .any(|e| (e.from(), e.to()) == (node, next) || (e.from(), e.to()) == (next, node));
+            assert!(linked, "{node} and {next} are not adjacent");
+        }
+    }
+
+    #[test]
+    fn self_loop_is_odd_cycle() {
+        let error = bipartition(&unit(2, &[(0, 1), (1, 1)])).unwrap_err();
+
+        assert_eq!(error.cycle, vec![1]);
+        assert_eq!(error.to_string(), "graph is not bipartite, odd cycle: 1");
+    }
+
+    #[test]
+    fn hopcroft_karp_finds_maximum_matching() {
+        // Greedy matching 0-3, 1-4 blocks node 2; the maximum uses an augmenting path
+        let graph = unit(6, &[(0, 3), (0, 4), (1, 3), (2, 4), (2, 5), (1, 5)]);
+
+        let matching = hopcroft_karp(&graph).unwrap();
+
+        assert_eq!(matching.size(), 3);
+        for &(left, right) in matching.pairs() {
+            assert_eq!(matching.mate(right), Some(left));
+        }
+    }
+
+    #[test]
+    fn hopcroft_karp_leaves_unmatchable_nodes() {
+        // Three drivers competing for a single job
+        let matching = hopcroft_karp(&unit(4, &[(0, 3), (1, 3), (2, 3)])).unwrap();
+
+        assert_eq!(matching.size(), 1);
+        assert_eq!(matching.mate(3), Some(0));
+        assert_eq!(matching.mate(1), None);
+    }
+
+    #[test]
+    fn hopcroft_karp_requires_bipartite_graph() {
+        assert!(hopcroft_karp(&unit(3, &[(0, 1), (1, 2), (2, 0)])).is_err());
+    }
+
+    #[test]
+    fn hungarian_minimizes_total_cost() {
+        // Drivers 0..3, jobs 3..6
+        let graph = graph(
+            6,
+            &[
+                (0, 3, 4.0),
+                (0, 4, 1.0),
+                (0, 5, 3.0),
+                (1, 3, 2.0),
+                (1, 4, 0.0),
+                (1, 5, 5.0),
+                (2, 3, 3.0),
+                (2, 4, 2.0),
+                (2, 5, 2.0),
+            ],
+        );
+
+        let assignment = hungarian(&graph).unwrap();
+
+        assert_eq!(assignment.total_cost(), 5.0);
+        assert_eq!(assignment.pairs(), &[(0, 4, 1.0), (1, 3, 2.0), (2, 5, 2.0)]);
+    }
+
+    #[test]
+    fn hungarian_prefers_cardinality_over_cost() {
+        // Taking the cheap 0-2 pair would leave node 1 unassigned
+        let graph = graph(4, &[(0, 2, 1.0), (0, 3, 10.0), (1, 2, 10.0)]);
+
+        let assignment = hungarian(&graph).unwrap();
+
+        assert_eq!(assignment.size(), 2);
+        assert_eq!(assignment.total_cost(), 20.0);
+    }
+
+    #[test]
+    fn hungarian_handles_unbalanced_sides() {
+        // One driver, three jobs, with a parallel cheaper edge
+        let graph = graph(4, &[(0, 1, 5.0), (0, 2, 3.0), (0, 3, 4.0), (0, 2, 6.0)]);
+
+        let assignment = hungarian(&graph).unwrap();
+
+        assert_eq!(assignment.pairs(), &[(0, 2, 3.0)]);
+    }
+
+    #[test]
+    fn hungarian_supports_negative_costs() {
+        let graph = graph(4, &[(0, 2, -1.0), (0, 3, -5.0), (1, 2, -4.0), (1, 3, -3.0)]);
+
+        assert_eq!(hungarian(&graph).unwrap().total_cost(), -9.0);
+    }
+}
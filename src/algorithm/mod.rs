@@ -0,0 +1,64 @@
+mod adjacency;
+#[cfg(test)]
+pub(crate) mod test_support;
+
+pub mod all_pairs;
+pub mod any_angle;
+pub mod biconnected;
+pub mod centrality;
+pub mod coloring;
+pub mod components;
+pub mod contraction;
+pub mod disjoint_set;
+pub mod euler;
+pub mod flow;
+pub mod hierarchical;
+pub mod incremental;
+pub mod jump_point;
+pub mod k_shortest;
+pub mod landmarks;
+pub mod matching;
+pub mod path_tree;
+pub mod reachability;
+pub mod spanning_tree;
+pub mod topological;
+pub mod tsp;
+
+pub use all_pairs::{DistanceMatrix, floyd_warshall, johnson};
+pub use any_angle::{AnyAnglePath, lazy_theta_star, theta_star};
+pub use biconnected::{Biconnectivity, articulation_points, biconnected_components, bridges};
+pub use centrality::{
+    Direction, PageRank, PathMetric, betweenness_centrality, closeness_centrality,
+    degree_centrality, sampled_betweenness_centrality,
+};
+pub use coloring::{
+    Coloring, ColoringOrder, greedy_coloring, maximal_cliques, maximal_independent_set,
+    maximal_independent_sets, maximum_clique,
+};
+pub use components::{
+    Components, condensation, kosaraju_scc, tarjan_scc, weakly_connected_components,
+};
+pub use contraction::{ContractionHierarchy, HierarchyBuilder};
+pub use disjoint_set::DisjointSet;
+pub use euler::{
+    NotEulerian, Orientation, PostmanTour, chinese_postman, eulerian_circuit, eulerian_path,
+};
+pub use flow::{MaxFlow, ResidualEdge, dinic, residual_graph};
+pub use hierarchical::{HierarchicalGrid, HierarchicalPath};
+pub use incremental::{DStarLite, LpaStar, WeightChange};
+pub use jump_point::{JumpPointPath, jump_point_search};
+pub use k_shortest::{AlternativeRoutes, RankedPath, k_shortest_paths};
+pub use landmarks::{
+    LandmarkHeuristic, LandmarkSelection, LandmarkSelector, Landmarks, ParseLandmarksError,
+};
+pub use matching::{
+    Assignment, Bipartition, Matching, OddCycle, bipartition, hopcroft_karp, hungarian,
+};
+pub use path_tree::PathTree;
+pub use reachability::{ReachabilityIndex, transitive_closure, transitive_reduction};
+pub use spanning_tree::{SpanningForest, kruskal, minimum_spanning_tree, prim};
+pub use topological::{
+    CycleDetected, dag_longest_paths, dag_shortest_paths, find_cycle, topological_order,
+    topological_order_dfs,
+};
+pub use tsp::{nearest_neighbor_tour, two_opt};
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+/// Single-source paths computed by an algorithm: a distance and a parent
+/// for every reached node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathTree<C = f64> {
+    source: u32,
+    distances: HashMap<u32, C>,
+    parents: HashMap<u32, u32>,
+}
+
+impl<C: Copy> PathTree<C> {
+    /// Creates a tree only reaching its source.
+    pub(crate) fn new(source: u32, zero: C) -> Self {
+        PathTree {
+            source,
+            distances: HashMap::from([(source, zero)]),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// Records the distance and parent of a node.
+    pub(crate) fn set(&mut self, node_id: u32, distance: C, parent: u32) {
+        self.distances.insert(node_id, distance);
+        self.parents.insert(node_id, parent);
+    }
+
+    /// Returns the source node of the paths.
+    pub fn source(&self) -> u32 {
+        self.source
+    }
+
+    /// Returns the distance from the source to a node, or `None` if unreached.
+    pub fn distance(&self, node_id: u32) -> Option<C> {
+        self.distances.get(&node_id).copied()
+    }
+
+    /// Returns the node preceding a node on its path, or `None` for the source
+    /// and unreached nodes.
+    pub fn parent(&self, node_id: u32) -> Option<u32> {
+        self.parents.get(&node_id).copied()
+    }
+
+    /// Reconstructs the path from the source to a node.
+    ///
+    /// # Returns
+    ///
+    /// The node IDs from the source to the target, or `None` if unreached
+    pub fn path(&self, node_id: u32) -> Option<Vec<u32>> {
+        self.distances.get(&node_id)?;
+
+        let mut path = vec![node_id];
+        let mut current = node_id;
+        while let Some(&parent) = self.parents.get(&current) {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
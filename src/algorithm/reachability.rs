@@ -0,0 +1,369 @@
+use crate::graph::{Edge, Graph, Node};
+use crate::preset::{EmptyNode, UnweightedEdge};
+use std::collections::BTreeSet;
+
+use super::adjacency::Adjacency;
+use super::{Components, tarjan_scc};
+
+/// Fixed size set of small integers, one bit each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    fn new(len: usize) -> Self {
+        BitSet {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, bit: usize) {
+        self.words[bit / 64] |= 1 << (bit % 64);
+    }
+
+    fn contains(&self, bit: usize) -> bool {
+        self.words[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    fn union_with(&mut self, other: &BitSet) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| w * 64 + bit)
+        })
+    }
+}
+
+/// Precomputed answers to "can A reach B?" on a static graph.
+///
+/// Strongly connected components are condensed into a DAG, and each
+/// component stores the set of components it reaches as a bitset. Queries
+/// then take constant time, at the cost of `c²` bits of memory for `c`
+/// components. The index must be rebuilt when the graph changes.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::algorithm::ReachabilityIndex;
+/// use hodos::graph::{Edge, Graph, Node};
+/// use hodos::preset::{EmptyNode, UnweightedEdge};
+///
+/// let mut graph = Graph::<EmptyNode, UnweightedEdge>::new();
+/// for id in 0..3 {
+///     graph.add_node(EmptyNode::new(id, None));
+/// }
+/// graph.add_edge(UnweightedEdge::new(0, 1, None));
+/// graph.add_edge(UnweightedEdge::new(1, 2, None));
+///
+/// let index = ReachabilityIndex::new(&graph);
+/// assert!(index.can_reach(0, 2));
+/// assert!(!index.can_reach(2, 0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReachabilityIndex {
+    components: Components,
+    /// Node IDs of each component, sorted
+    members: Vec<Vec<u32>>,
+    /// Components reached by each component, itself included
+    reach: Vec<BitSet>,
+    /// Distinct successor components of each component, in topological order
+    successors: Vec<Vec<usize>>,
+}
+
+impl ReachabilityIndex {
+    /// Builds the index of a graph.
+    ///
+    /// Runs in `O(n + m + c·e/64)` where `c` and `e` are the number of
+    /// components and of edges between them. Dangling edges are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph to index
+    pub fn new<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Self
+    where
+        TNode: Node,
+        TEdge: Edge,
+    {
+        let adjacency = Adjacency::from_graph(graph);
+        let components = tarjan_scc(graph);
+        let count = components.count();
+        let component = |i: usize| components.component(adjacency.ids[i]).unwrap();
+
+        let mut links: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); count];
+        for (i, targets) in adjacency.successors.iter().enumerate() {
+            for &(j, _) in targets {
+                let (from, to) = (component(i), component(j));
+                if from != to {
+                    links[from].insert(to);
+                }
+            }
+        }
+        let successors: Vec<Vec<usize>> = links.into_iter().map(Vec::from_iter).collect();
+
+        // Components are topologically ordered, so successors come later
+        let mut reach: Vec<BitSet> = vec![BitSet::new(count); count];
+        for c in (0..count).rev() {
+            let mut set = BitSet::new(count);
+            set.insert(c);
+            for &d in &successors[c] {
+                set.union_with(&reach[d]);
+            }
+            reach[c] = set;
+        }
+
+        let mut members: Vec<Vec<u32>> = vec![Vec::new(); count];
+        for &id in &adjacency.ids {
+            members[components.component(id).unwrap()].push(id);
+        }
+
+        ReachabilityIndex {
+            components,
+            members,
+            reach,
+            successors,
+        }
+    }
+
+    /// Returns `true` if a path leads from a node to another.
+    ///
+    /// Every known node reaches itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    ///
+    /// # Returns
+    ///
+    /// `false` if either node is unknown
+    pub fn can_reach(&self, from: u32, to: u32) -> bool {
+        match (
+            self.components.component(from),
+            self.components.component(to),
+        ) {
+            (Some(a), Some(b)) => self.reach[a].contains(b),
+            _ => false,
+        }
+    }
+
+    /// Returns the IDs of the nodes reachable from a node, itself included, sorted.
+    pub fn reachable_from(&self, node_id: u32) -> Vec<u32> {
+        let Some(component) = self.components.component(node_id) else {
+            return Vec::new();
+        };
+        let mut nodes: Vec<u32> = self.reach[component]
+            .iter()
+            .flat_map(|c| self.members[c].iter().copied())
+            .collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    /// Returns the strongly connected components the index is built on.
+    pub fn components(&self) -> &Components {
+        &self.components
+    }
+}
+
+/// Builds the transitive closure of a graph.
+///
+/// The closure has the same nodes, and an edge `a -> b` for every pair of
+/// distinct nodes such that `a` reaches `b`.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to close
+pub fn transitive_closure<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> Graph<EmptyNode, UnweightedEdge>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let index = ReachabilityIndex::new(graph);
+    let mut closure = empty_copy(graph);
+
+    let mut ids: Vec<u32> = graph.nodes.keys().copied().collect();
+    ids.sort_unstable();
+    for from in ids {
+        for to in index.reachable_from(from) {
+            if to != from {
+                closure.add_edge(UnweightedEdge::new(from, to, None));
+            }
+        }
+    }
+    closure
+}
+
+/// Builds the transitive reduction of a graph: the fewest edges preserving reachability.
+///
+/// For a DAG the reduction is unique, and keeps an edge `a -> b` only when
+/// no other path leads from `a` to `b`. In a cyclic graph, each strongly
+/// connected component becomes a single cycle through its members by
+/// increasing ID, and components are linked through their smallest member.
+/// Parallel edges and self loops are dropped.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to reduce
+pub fn transitive_reduction<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> Graph<EmptyNode, UnweightedEdge>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let index = ReachabilityIndex::new(graph);
+    let members = &index.members;
+    let mut reduction = empty_copy(graph);
+
+    for cycle in members.iter().filter(|members| members.len() > 1) {
+        for (i, &from) in cycle.iter().enumerate() {
+            let to = cycle[(i + 1) % cycle.len()];
+            reduction.add_edge(UnweightedEdge::new(from, to, None));
+        }
+    }
+
+    for (c, successors) in index.successors.iter().enumerate() {
+        // A successor already reached through an earlier one is redundant
+        let mut covered = BitSet::new(members.len());
+        for &d in successors {
+            if !covered.contains(d) {
+                covered.union_with(&index.reach[d]);
+                reduction.add_edge(UnweightedEdge::new(members[c][0], members[d][0], None));
+            }
+        }
+    }
+    reduction
+}
+
+fn empty_copy<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Graph<EmptyNode, UnweightedEdge>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let mut copy = Graph::new();
+    for &id in graph.nodes.keys() {
+        copy.add_node(EmptyNode::new(id, None));
+    }
+    copy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::unweighted_graph;
+
+    fn sorted_edges(graph: &Graph<EmptyNode, UnweightedEdge>) -> Vec<(u32, u32)> {
+        let mut edges: Vec<(u32, u32)> = graph
+            .get_edges()
+            .iter()
+            .map(|e| (e.from(), e.to()))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn bitset_tracks_bits_across_words() {
+        let mut set = BitSet::new(130);
+        set.insert(3);
+        set.insert(129);
+        let mut other = BitSet::new(130);
+        other.insert(64);
+        set.union_with(&other);
+
+        assert!(set.contains(64));
+        assert!(!set.contains(65));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 64, 129]);
+    }
+
+    #[test]
+    fn answers_reachability_through_cycles() {
+        // Cycle {1, 2} between 0 and 3, and an isolated node 4
+        let index = ReachabilityIndex::new(&unweighted_graph(5, &[(0, 1), (1, 2), (2, 1), (2, 3)]));
+
+        assert!(index.can_reach(0, 3));
+        assert!(index.can_reach(2, 1));
+        assert!(!index.can_reach(3, 0));
+        assert!(!index.can_reach(0, 4));
+        assert!(index.can_reach(4, 4));
+        assert!(!index.can_reach(0, 9));
+        assert_eq!(index.reachable_from(1), vec![1, 2, 3]);
+        assert_eq!(index.components().count(), 4);
+    }
+
+    #[test]
+    fn matches_traversal_on_larger_graph() {
+        // Every node i links to 2i + 1 and 3i, modulo 200
+        let edges: Vec<(u32, u32)> = (0..200)
+            .flat_map(|i| [(i, (2 * i + 1) % 200), (i, (3 * i) % 200)])
+            .collect();
+        let graph = unweighted_graph(200, &edges);
+        let index = ReachabilityIndex::new(&graph);
+
+        for from in [0, 7, 99, 150] {
+            let expected: BTreeSet<u32> = {
+                let mut seen = BTreeSet::from([from]);
+                let mut pending = vec![from];
+                while let Some(node) = pending.pop() {
+                    for edge in &graph.edges[&node] {
+                        if seen.insert(edge.to()) {
+                            pending.push(edge.to());
+                        }
+                    }
+                }
+                seen
+            };
+            assert_eq!(index.reachable_from(from), Vec::from_iter(expected));
+        }
+    }
+
+    #[test]
+    fn closure_links_every_reachable_pair() {
+        let closure = transitive_closure(&unweighted_graph(3, &[(0, 1), (1, 2), (2, 2)]));
+
+        assert_eq!(sorted_edges(&closure), vec![(0, 1), (0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn reduction_of_dag_drops_shortcuts() {
+        let dag = unweighted_graph(4, &[(0, 1), (1, 2), (0, 2), (2, 3), (0, 3), (1, 3), (1, 2)]);
+
+        let reduction = transitive_reduction(&dag);
+
+        assert_eq!(sorted_edges(&reduction), vec![(0, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn reduction_of_cyclic_graph_keeps_one_cycle_per_component() {
+        // Component {0, 1, 2} fully linked, then 2 -> 3 and a shortcut 0 -> 3
+        let edges = [
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (2, 1),
+            (0, 2),
+            (2, 0),
+            (2, 3),
+            (0, 3),
+        ];
+
+        let reduction = transitive_reduction(&unweighted_graph(4, &edges));
+
+        assert_eq!(
+            sorted_edges(&reduction),
+            vec![(0, 1), (0, 3), (1, 2), (2, 0)]
+        );
+        let index = ReachabilityIndex::new(&reduction);
+        assert!(index.can_reach(2, 1));
+        assert!(index.can_reach(1, 3));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::frontier::{Frontier, MinHeap};
+use crate::graph::{Cost, Edge, Graph, Node};
+use crate::preset::{EmptyNode, WeightedEdge};
+use std::cmp::Ordering;
+
+use super::DisjointSet;
+use super::adjacency::Adjacency;
+
+/// A minimum spanning forest: one minimum spanning tree per connected component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanningForest<C = f64> {
+    /// Node IDs covered by the forest, sorted
+    nodes: Vec<u32>,
+    edges: Vec<(u32, u32, C)>,
+    total_weight: C,
+}
+
+impl<C: Cost> SpanningForest<C> {
+    fn new(nodes: Vec<u32>) -> Self {
+        SpanningForest {
+            nodes,
+            edges: Vec::new(),
+            total_weight: C::zero(),
+        }
+    }
+
+    fn add(&mut self, from: u32, to: u32, weight: C) {
+        self.edges.push((from, to, weight));
+        self.total_weight = self.total_weight.plus(weight);
+    }
+
+    /// Returns the chosen edges as `(from, to, weight)`, in selection order.
+    pub fn edges(&self) -> &[(u32, u32, C)] {
+        &self.edges
+    }
+
+    /// Returns the sum of the chosen edge weights.
+    pub fn total_weight(&self) -> C {
+        self.total_weight
+    }
+
+    /// Returns the number of trees, i.e. of connected components of the input graph.
+    pub fn tree_count(&self) -> usize {
+        self.nodes.len() - self.edges.len()
+    }
+
+    /// Returns `true` if the forest is a single tree spanning every node.
+    pub fn is_spanning_tree(&self) -> bool {
+        self.tree_count() <= 1
+    }
+
+    /// Converts the forest into a graph.
+    ///
+    /// Every chosen edge is added in both directions, so the forest can be
+    /// traversed from any of its nodes with the usual frontiers and visitors.
+    pub fn into_graph(self) -> Graph<EmptyNode, WeightedEdge<C>> {
+        let mut graph = Graph::new();
+        for id in self.nodes {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for (from, to, weight) in self.edges {
+            graph.add_edge(WeightedEdge::new(from, to, Some(weight)));
+            graph.add_edge(WeightedEdge::new(to, from, Some(weight)));
+        }
+        graph
+    }
+}
+
+/// Computes a minimum spanning forest with Kruskal's algorithm.
+///
+/// The graph is considered undirected: an edge `from -> to` connects both
+/// nodes, and storing both directions is harmless. Edges are considered by
+/// increasing weight and kept when they join two different trees, tracked
+/// with a `DisjointSet`. Self loops are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to span
+pub fn kruskal<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> SpanningForest<TEdge::Weight>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+
+    let mut candidates: Vec<(usize, usize, TEdge::Weight)> = adjacency
+        .successors
+        .iter()
+        .enumerate()
+        .flat_map(|(i, targets)| targets.iter().map(move |&(j, weight)| (i, j, weight)))
+        .collect();
+    candidates.sort_by(|a, b| a.2.compare(&b.2).then_with(|| (a.0, a.1).cmp(&(b.0, b.1))));
+
+    let mut sets = DisjointSet::new(adjacency.len());
+    let mut forest = SpanningForest::new(adjacency.ids.clone());
+    for (i, j, weight) in candidates {
+        if sets.union(i, j) {
+            forest.add(adjacency.ids[i], adjacency.ids[j], weight);
+        }
+    }
+    forest
+}
+
+/// Computes a minimum spanning forest with Prim's algorithm.
+///
+/// The graph is considered undirected. Each tree grows from its smallest
+/// node ID by repeatedly attaching the cheapest node connected to it, using
+/// a `MinHeap` frontier. A new tree is started whenever the current one
+/// cannot grow anymore, so disconnected graphs yield a forest.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to span
+pub fn prim<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> SpanningForest<TEdge::Weight>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let reversed = adjacency.reversed();
+    let n = adjacency.len();
+
+    let mut in_tree = vec![false; n];
+    // Cheapest known connection of each node to the tree, as (tree node, weight)
+    let mut best: Vec<Option<(usize, TEdge::Weight)>> = vec![None; n];
+    let mut forest = SpanningForest::new(adjacency.ids.clone());
+
+    for root in 0..n {
+        if in_tree[root] {
+            continue;
+        }
+
+        let mut frontier = MinHeap::default();
+        frontier.push(root as u32, Some(TEdge::Weight::zero()));
+
+        while let Some(node) = frontier.pop() {
+            let node = node as usize;
+            if in_tree[node] {
+                continue;
+            }
+            in_tree[node] = true;
+            if let Some((parent, weight)) = best[node] {
+                forest.add(adjacency.ids[parent], adjacency.ids[node], weight);
+            }
+
+            let neighbors = adjacency.successors[node]
+                .iter()
+                .chain(&reversed.successors[node]);
+            for &(next, weight) in neighbors {
+                let improves = best[next]
+                    .is_none_or(|(_, current)| weight.compare(&current) == Ordering::Less);
+                if !in_tree[next] && improves {
+                    best[next] = Some((node, weight));
+                    frontier.push(next as u32, Some(weight));
+                }
+            }
+        }
+    }
+    forest
+}
+
+/// Computes a minimum spanning tree, if the graph is connected.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to span
+///
+/// # Returns
+///
+/// The tree computed by `kruskal`, or `None` if the graph is disconnected
+pub fn minimum_spanning_tree<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> Option<SpanningForest<TEdge::Weight>>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    Some(kruskal(graph)).filter(SpanningForest::is_spanning_tree)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+
+    fn network() -> Graph<EmptyNode, WeightedEdge> {
+        graph(
+            5,
+            &[
+                (0, 1, 2.0),
+                (0, 3, 6.0),
+                (1, 2, 3.0),
+                (1, 3, 8.0),
+                (1, 4, 5.0),
+                (2, 4, 7.0),
+                (3, 4, 9.0),
+            ],
+        )
+    }
+
+    fn sorted_pairs<C>(forest: &SpanningForest<C>) -> Vec<(u32, u32)> {
+        let mut pairs: Vec<(u32, u32)> = forest
+            .edges
+            .iter()
+            .map(|&(a, b, _)| (a.min(b), a.max(b)))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn kruskal_and_prim_agree() {
+        let graph = network();
+
+        for forest in [kruskal(&graph), prim(&graph)] {
+            assert_eq!(forest.total_weight(), 16.0);
+            assert_eq!(sorted_pairs(&forest), vec![(0, 1), (0, 3), (1, 2), (1, 4)]);
+            assert!(forest.is_spanning_tree());
+        }
+    }
+
+    #[test]
+    fn edge_direction_is_ignored() {
+        // Every edge points towards a lower ID
+        let graph = graph(3, &[(1, 0, 4u64), (2, 1, 1), (2, 0, 2)]);
+
+        for forest in [kruskal(&graph), prim(&graph)] {
+            assert_eq!(forest.total_weight(), 3);
+            assert_eq!(sorted_pairs(&forest), vec![(0, 2), (1, 2)]);
+        }
+    }
+
+    #[test]
+    fn disconnected_graphs_yield_forests() {
+        let graph = graph(5, &[(0, 1, 1.0), (2, 3, 2.0), (3, 2, 2.0)]);
+
+        for forest in [kruskal(&graph), prim(&graph)] {
+            assert_eq!(forest.tree_count(), 3);
+            assert_eq!(forest.total_weight(), 3.0);
+            assert!(!forest.is_spanning_tree());
+        }
+        assert_eq!(minimum_spanning_tree(&graph), None);
+        assert!(minimum_spanning_tree(&network()).is_some());
+    }
+
+    #[test]
+    fn self_loops_are_ignored() {
+        let graph = graph(2, &[(0, 0, -5.0), (0, 1, 1.0)]);
+
+        assert_eq!(kruskal(&graph).edges(), &[(0, 1, 1.0)]);
+        assert_eq!(prim(&graph).edges(), &[(0, 1, 1.0)]);
+    }
+
+    #[test]
+    fn forest_converts_into_traversable_graph() {
+        let tree = kruskal(&network()).into_graph();
+
+        assert_eq!(tree.nodes.len(), 5);
+        assert_eq!(tree.get_edges().len(), 8);
+        assert_eq!(tree.edges[&4].len(), 1);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::graph::{Cost, Edge, Graph, Node};
+use crate::preset::{EmptyNode, UnweightedEdge, WeightedEdge};
+
+/// Builds a graph with nodes `0..node_count` and the given weighted edges.
+pub(crate) fn graph<C: Cost>(
+    node_count: u32,
+    edges: &[(u32, u32, C)],
+) -> Graph<EmptyNode, WeightedEdge<C>> {
+    let mut graph = Graph::new();
+    for id in 0..node_count {
+        graph.add_node(EmptyNode::new(id, None));
+    }
+    for &(from, to, weight) in edges {
+        graph.add_edge(WeightedEdge::new(from, to, Some(weight)));
+    }
+    graph
+}
+
+/// Builds a graph with nodes `0..node_count` and the given unweighted edges.
+pub(crate) fn unweighted_graph(
+    node_count: u32,
+    edges: &[(u32, u32)],
+) -> Graph<EmptyNode, UnweightedEdge> {
+    let mut graph = Graph::new();
+    for id in 0..node_count {
+        graph.add_node(EmptyNode::new(id, None));
+    }
+    for &(from, to) in edges {
+        graph.add_edge(UnweightedEdge::new(from, to, None));
+    }
+    graph
+}
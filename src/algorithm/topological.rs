@@ -0,0 +1,366 @@
+use crate::graph::{Cost, Edge, Graph, Node};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+
+use super::PathTree;
+use super::adjacency::Adjacency;
+
+/// Error reported when an algorithm requiring a directed acyclic graph meets a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleDetected {
+    /// Node IDs of the cycle in edge order, without repeating the first node
+    pub cycle: Vec<u32>,
+}
+
+impl fmt::Display for CycleDetected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: Vec<String> = self.cycle.iter().map(u32::to_string).collect();
+        write!(f, "cycle detected: {}", nodes.join(" -> "))
+    }
+}
+
+impl Error for CycleDetected {}
+
+/// Finds a directed cycle in a graph.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to analyze
+///
+/// # Returns
+///
+/// The node IDs of a cycle in edge order, or `None` if the graph is acyclic
+pub fn find_cycle<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Option<Vec<u32>>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    dfs_order(&adjacency).err().map(|error| error.cycle)
+}
+
+/// Orders the nodes of a DAG so that every edge goes from an earlier to a later node.
+///
+/// Uses Kahn's algorithm: nodes without remaining incoming edges are
+/// repeatedly removed, smallest ID first, which makes the order deterministic.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sort
+///
+/// # Errors
+///
+/// Returns `CycleDetected` with one of the graph's cycles if it is not acyclic.
+pub fn topological_order<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> Result<Vec<u32>, CycleDetected>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    kahn_order(&adjacency).map(|order| order.into_iter().map(|i| adjacency.ids[i]).collect())
+}
+
+/// Orders the nodes of a DAG with a depth-first search.
+///
+/// Nodes are emitted in reverse finish order. The search is iterative, so
+/// long dependency chains cannot overflow the stack.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to sort
+///
+/// # Errors
+///
+/// Returns `CycleDetected` with the first cycle met by the search.
+pub fn topological_order_dfs<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+) -> Result<Vec<u32>, CycleDetected>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    dfs_order(&adjacency).map(|order| order.into_iter().map(|i| adjacency.ids[i]).collect())
+}
+
+/// Computes the shortest paths from a source in a DAG.
+///
+/// Edges are relaxed once each, in topological order, so the computation
+/// runs in linear time and supports negative weights.
+///
+/// # Arguments
+///
+/// * `graph` - The DAG to analyze
+/// * `source` - The start node ID
+///
+/// # Errors
+///
+/// Returns `CycleDetected` if the graph is not acyclic.
+pub fn dag_shortest_paths<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    source: u32,
+) -> Result<PathTree<TEdge::Weight>, CycleDetected>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    dag_paths(graph, source, Ordering::Less)
+}
+
+/// Computes the longest paths from a source in a DAG.
+///
+/// Useful for critical path analysis, where the longest chain of dependent
+/// tasks bounds the total duration. Runs in linear time.
+///
+/// # Arguments
+///
+/// * `graph` - The DAG to analyze
+/// * `source` - The start node ID
+///
+/// # Errors
+///
+/// Returns `CycleDetected` if the graph is not acyclic.
+pub fn dag_longest_paths<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    source: u32,
+) -> Result<PathTree<TEdge::Weight>, CycleDetected>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    dag_paths(graph, source, Ordering::Greater)
+}
+
+/// Relaxes edges in topological order, keeping distances that compare as `better`.
+fn dag_paths<TNode, TEdge>(
+    graph: &Graph<TNode, TEdge>,
+    source: u32,
+    better: Ordering,
+) -> Result<PathTree<TEdge::Weight>, CycleDetected>
+where
+    TNode: Node,
+    TEdge: Edge,
+{
+    let adjacency = Adjacency::from_graph(graph);
+    let order = kahn_order(&adjacency)?;
+
+    let mut tree = PathTree::new(source, TEdge::Weight::zero());
+    for i in order {
+        let Some(distance) = tree.distance(adjacency.ids[i]) else {
+            continue;
+        };
+        for &(j, weight) in &adjacency.successors[i] {
+            let candidate = distance.plus(weight);
+            let target = adjacency.ids[j];
+            if tree
+                .distance(target)
+                .is_none_or(|current| candidate.compare(&current) == better)
+            {
+                tree.set(target, candidate, adjacency.ids[i]);
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+/// Kahn's algorithm over node indices, smallest index first.
+fn kahn_order<W: Copy>(adjacency: &Adjacency<W>) -> Result<Vec<usize>, CycleDetected> {
+    let n = adjacency.len();
+    let mut in_degrees = vec![0; n];
+    for targets in &adjacency.successors {
+        for &(j, _) in targets {
+            in_degrees[j] += 1;
+        }
+    }
+
+    let mut ready: BTreeSet<usize> = (0..n).filter(|&i| in_degrees[i] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(i) = ready.pop_first() {
+        order.push(i);
+        for &(j, _) in &adjacency.successors[i] {
+            in_degrees[j] -= 1;
+            if in_degrees[j] == 0 {
+                ready.insert(j);
+            }
+        }
+    }
+
+    if order.len() < n {
+        return Err(dfs_order(adjacency).expect_err("Remaining nodes must contain a cycle"));
+    }
+    Ok(order)
+}
+
+/// Depth-first topological sort over node indices, reporting the first back edge.
+fn dfs_order<W: Copy>(adjacency: &Adjacency<W>) -> Result<Vec<usize>, CycleDetected> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        Open,
+        Done,
+    }
+
+    let n = adjacency.len();
+    let mut states = vec![State::New; n];
+    let mut finished = Vec::with_capacity(n);
+
+    for root in 0..n {
+        if states[root] != State::New {
+            continue;
+        }
+        states[root] = State::Open;
+        let mut calls = vec![(root, 0)];
+
+        while let Some((node, position)) = calls.last_mut() {
+            let node = *node;
+            let Some(&(next, _)) = adjacency.successors[node].get(*position) else {
+                states[node] = State::Done;
+                finished.push(node);
+                calls.pop();
+                continue;
+            };
+            *position += 1;
+
+            match states[next] {
+                State::New => {
+                    states[next] = State::Open;
+                    calls.push((next, 0));
+                }
+                State::Open => {
+                    // The open nodes from `next` to `node` form the cycle
+                    let start = calls.iter().position(|&(open, _)| open == next).unwrap();
+                    let cycle = calls[start..]
+                        .iter()
+                        .map(|&(open, _)| adjacency.ids[open])
+                        .collect();
+                    return Err(CycleDetected { cycle });
+                }
+                State::Done => {}
+            }
+        }
+    }
+
+    finished.reverse();
+    Ok(finished)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::test_support::graph;
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    /// Build pipeline: 0 fetch, 1 compile, 2 assets, 3 link, 4 package.
+    fn pipeline() -> Graph<EmptyNode, WeightedEdge> {
+        graph(
+            5,
+            &[
+                (0, 1, 3.0),
+                (0, 2, 1.0),
+                (1, 3, 4.0),
+                (2, 3, 1.0),
+                (3, 4, 2.0),
+                (2, 4, 1.0),
+            ],
+        )
+    }
+
+    fn assert_topological(graph: &Graph<EmptyNode, WeightedEdge>, order: &[u32]) {
+        assert_eq!(order.len(), graph.nodes.len());
+        let position = |id: u32| order.iter().position(|&o| o == id).unwrap();
+        for edge in graph.get_edges() {
+            assert!(position(edge.from()) < position(edge.to()));
+        }
+    }
+
+    #[test]
+    fn kahn_order_is_topological_and_deterministic() {
+        let order = topological_order(&pipeline()).unwrap();
+
+        assert_topological(&pipeline(), &order);
+        assert_eq!(order, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn dfs_order_is_topological() {
+        let order = topological_order_dfs(&pipeline()).unwrap();
+
+        assert_topological(&pipeline(), &order);
+    }
+
+    #[test]
+    fn both_orders_report_cycles() {
+        let graph = graph(4, &[(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (3, 1, 1.0)]);
+
+        for error in [
+            topological_order(&graph).unwrap_err(),
+            topological_order_dfs(&graph).unwrap_err(),
+        ] {
+            assert_eq!(error.cycle, vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn finds_self_loops() {
+        let graph = graph(2, &[(0, 1, 1.0), (1, 1, 1.0)]);
+
+        assert_eq!(find_cycle(&graph), Some(vec![1]));
+        assert_eq!(find_cycle(&pipeline()), None);
+    }
+
+    #[test]
+    fn cycle_error_displays_its_nodes() {
+        let error = CycleDetected { cycle: vec![4, 2] };
+
+        assert_eq!(error.to_string(), "cycle detected: 4 -> 2");
+    }
+
+    #[test]
+    fn shortest_paths_relax_in_topological_order() {
+        let tree = dag_shortest_paths(&pipeline(), 0).unwrap();
+
+        assert_eq!(tree.distance(4), Some(2.0));
+        assert_eq!(tree.path(4), Some(vec![0, 2, 4]));
+        assert_eq!(tree.distance(3), Some(2.0));
+    }
+
+    #[test]
+    fn shortest_paths_support_negative_weights() {
+        let graph = graph(3, &[(0, 1, 2.0), (1, 2, -5.0), (0, 2, 1.0)]);
+
+        let tree = dag_shortest_paths(&graph, 0).unwrap();
+
+        assert_eq!(tree.distance(2), Some(-3.0));
+        assert_eq!(tree.parent(2), Some(1));
+    }
+
+    #[test]
+    fn longest_paths_give_critical_path() {
+        let tree = dag_longest_paths(&pipeline(), 0).unwrap();
+
+        assert_eq!(tree.distance(4), Some(9.0));
+        assert_eq!(tree.path(4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(tree.source(), 0);
+    }
+
+    #[test]
+    fn paths_ignore_unreachable_nodes() {
+        let tree = dag_longest_paths(&pipeline(), 2).unwrap();
+
+        assert_eq!(tree.distance(0), None);
+        assert_eq!(tree.path(1), None);
+        assert_eq!(tree.path(2), Some(vec![2]));
+    }
+
+    #[test]
+    fn paths_require_acyclic_graph() {
+        let graph = graph(2, &[(0, 1, 1.0), (1, 0, 1.0)]);
+
+        assert!(dag_shortest_paths(&graph, 0).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::graph::Cost;
+use std::cmp::Ordering;
+
+use super::{DistanceMatrix, RankedPath};
+
+/// Builds a tour visiting every node of a distance matrix with the nearest neighbor heuristic.
+///
+/// Starting from `start`, the closest unvisited node is appended until
+/// every node is visited, then the tour returns to `start`. Distances are
+/// shortest path distances, so consecutive tour nodes need not be adjacent
+/// in the graph: `DistanceMatrix::path` expands each leg into graph nodes.
+///
+/// # Arguments
+///
+/// * `matrix` - Distances between every pair of nodes, e.g. from `floyd_warshall`
+/// * `start` - The node ID where the tour starts and ends
+///
+/// # Returns
+///
+/// The tour, its nodes starting and ending with `start`, or `None` if
+/// `start` is unknown or some node cannot be reached along the way.
+pub fn nearest_neighbor_tour<C: Cost>(
+    matrix: &DistanceMatrix<C>,
+    start: u32,
+) -> Option<RankedPath<C>> {
+    let nodes = matrix.nodes();
+    nodes.binary_search(&start).ok()?;
+
+    let mut visited = vec![false; nodes.len()];
+    let mut tour = vec![start];
+    let mut cost = C::zero();
+    visited[nodes.binary_search(&start).ok()?] = true;
+
+    for _ in 1..nodes.len() {
+        let current = *tour.last().unwrap();
+        let (next, distance) = nodes
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| !visited[i])
+            .filter_map(|(i, &id)| Some((i, matrix.distance(current, id)?)))
+            .min_by(|a, b| a.1.compare(&b.1))?;
+        visited[next] = true;
+        tour.push(nodes[next]);
+        cost = cost.plus(distance);
+    }
+
+    cost = cost.plus(matrix.distance(*tour.last().unwrap(), start)?);
+    tour.push(start);
+    Some(RankedPath { nodes: tour, cost })
+}
+
+/// Improves a tour with the 2-opt local search.
+///
+/// Repeatedly reverses the tour segment between two legs when it shortens
+/// the tour, until no reversal helps. Reversed segments are costed in their
+/// new direction, so asymmetric distances are supported. The start node
+/// keeps its place.
+///
+/// # Arguments
+///
+/// * `matrix` - Distances between every pair of nodes
+/// * `tour` - A closed tour, e.g. from `nearest_neighbor_tour`
+///
+/// # Returns
+///
+/// A tour at most as long as the given one
+pub fn two_opt<C: Cost>(matrix: &DistanceMatrix<C>, tour: &RankedPath<C>) -> RankedPath<C> {
+    let mut nodes = tour.nodes.clone();
+    let mut cost = tour.cost;
+    let leg = |from: u32, to: u32| matrix.distance(from, to);
+    let length = |nodes: &[u32]| -> Option<C> {
+        nodes.windows(2).try_fold(C::zero(), |total, pair| {
+            Some(total.plus(leg(pair[0], pair[1])?))
+        })
+    };
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        // Reverses nodes[i..=j], keeping the start and end in place
+        for i in 1..nodes.len().saturating_sub(2) {
+            for j in i + 1..nodes.len() - 1 {
+                let Some(before) = length(&nodes[i - 1..=j + 1]) else {
+                    continue;
+                };
+                let mut candidate = nodes[i - 1..=j + 1].to_vec();
+                candidate[1..=j - i + 1].reverse();
+                let Some(after) = length(&candidate) else {
+                    continue;
+                };
+
+                if after.compare(&before) == Ordering::Less {
+                    nodes[i..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+        if improved {
+            cost = length(&nodes).unwrap_or(cost);
+        }
+    }
+
+    RankedPath { nodes, cost }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::floyd_warshall;
+    use crate::graph::{Edge, Graph, Node};
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    /// Complete graph of points on a line at the given positions.
+    fn line(positions: &[f64]) -> DistanceMatrix {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        for id in 0..positions.len() as u32 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for (a, &x) in positions.iter().enumerate() {
+            for (b, &y) in positions.iter().enumerate() {
+                if a != b {
+                    graph.add_edge(WeightedEdge::new(a as u32, b as u32, Some((x - y).abs())));
+                }
+            }
+        }
+        floyd_warshall(&graph).unwrap()
+    }
+
+    #[test]
+    fn nearest_neighbor_visits_every_node_once() {
+        let matrix = line(&[0.0, 3.0, 1.0, 7.0]);
+
+        let tour = nearest_neighbor_tour(&matrix, 0).unwrap();
+
+        assert_eq!(tour.nodes, vec![0, 2, 1, 3, 0]);
+        assert_eq!(tour.cost, 14.0);
+    }
+
+    #[test]
+    fn two_opt_removes_crossings() {
+        // Points on a line: nearest neighbor from 1 zigzags
+        let matrix = line(&[0.0, 2.0, 3.0, 5.0, 10.0]);
+        let greedy = nearest_neighbor_tour(&matrix, 1).unwrap();
+
+        let improved = two_opt(&matrix, &greedy);
+
+        // Any tour covering the segment twice is optimal
+        assert_eq!(improved.cost, 20.0);
+        assert!(improved.cost <= greedy.cost);
+        assert_eq!(improved.nodes.first(), Some(&1));
+        assert_eq!(improved.nodes.last(), Some(&1));
+    }
+
+    #[test]
+    fn two_opt_fixes_bad_tour() {
+        let matrix = line(&[0.0, 1.0, 2.0, 3.0]);
+        let bad = RankedPath {
+            nodes: vec![0, 2, 1, 3, 0],
+            cost: 8.0,
+        };
+
+        assert_eq!(two_opt(&matrix, &bad).cost, 6.0);
+    }
+
+    #[test]
+    fn unreachable_nodes_have_no_tour() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        for id in 0..3 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        graph.add_edge(WeightedEdge::new(0, 1, Some(1.0)));
+        graph.add_edge(WeightedEdge::new(1, 0, Some(1.0)));
+        let matrix = floyd_warshall(&graph).unwrap();
+
+        assert_eq!(nearest_neighbor_tour(&matrix, 0), None);
+        assert_eq!(nearest_neighbor_tour(&matrix, 9), None);
+    }
+}
@@ -100,11 +100,8 @@ mod tests {
 
     #[test]
     fn builder_should_stop_when_sampler_returns_none() {
-        let mut builder = GraphBuilder::new(
-            AcceptAllPolicy::default(),
-            AcceptAllPolicy::default(),
-            MockSampler::default(),
-        );
+        let mut builder =
+            GraphBuilder::new(AcceptAllPolicy, AcceptAllPolicy, MockSampler::default());
 
         let graph = builder.build(&vec![0, 1, 2]);
         assert_eq!(graph.nodes.len(), 3);
@@ -113,11 +110,8 @@ mod tests {
 
     #[test]
     fn builder_should_respect_node_policy_rejection() {
-        let mut builder = GraphBuilder::new(
-            AcceptAllPolicy::default(),
-            RejectAllPolicy::default(),
-            MockSampler::default(),
-        );
+        let mut builder =
+            GraphBuilder::new(AcceptAllPolicy, RejectAllPolicy, MockSampler::default());
 
         let graph = builder.build(&vec![0, 1, 2]);
         assert_eq!(graph.nodes.len(), 0);
@@ -126,11 +120,8 @@ mod tests {
 
     #[test]
     fn builder_should_respect_edge_policy_rejection() {
-        let mut builder = GraphBuilder::new(
-            RejectAllPolicy::default(),
-            AcceptAllPolicy::default(),
-            MockSampler::default(),
-        );
+        let mut builder =
+            GraphBuilder::new(RejectAllPolicy, AcceptAllPolicy, MockSampler::default());
 
         let graph = builder.build(&vec![0, 1, 2]);
         assert_eq!(graph.nodes.len(), 3);
@@ -139,11 +130,8 @@ mod tests {
 
     #[test]
     fn builder_should_provide_sampler_with_context() {
-        let mut builder = GraphBuilder::new(
-            AcceptAllPolicy::default(),
-            AcceptAllPolicy::default(),
-            MockSampler::default(),
-        );
+        let mut builder =
+            GraphBuilder::new(AcceptAllPolicy, AcceptAllPolicy, MockSampler::default());
 
         let graph = builder.build(&vec![0, 1]);
         assert_eq!(graph.nodes.len(), 2);
@@ -171,8 +159,10 @@ mod tests {
     }
 
     impl Edge for MockEdge {
+        type Weight = f64;
+
         fn new(from: u32, to: u32, _weight: Option<f64>) -> Self {
-            MockEdge { from: from, to: to }
+            MockEdge { from, to }
         }
         fn to(&self) -> u32 {
             self.to
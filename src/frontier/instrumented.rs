@@ -0,0 +1,294 @@
+use super::Frontier;
+use crate::graph::Cost;
+use std::collections::{HashMap, HashSet};
+
+/// Metrics collected by an `Instrumented` frontier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontierStats<C = f64> {
+    /// Total number of push operations
+    pub pushes: usize,
+    /// Total number of successful pop operations
+    pub pops: usize,
+    /// Number of pushes of a node ID that had already been pushed before
+    pub duplicate_pushes: usize,
+    /// Largest number of pending nodes observed at once
+    pub peak_size: usize,
+    /// Costs of the popped nodes, in pop order
+    ///
+    /// A popped node is attributed the cheapest of its pending costs, which
+    /// is the cost of the removed push for frontiers popping the cheapest
+    /// push of a node first, such as `MinHeap` or a `Queue` fed with
+    /// increasing costs. Nodes pushed without cost are not recorded.
+    pub popped_costs: Vec<C>,
+}
+
+impl<C> Default for FrontierStats<C> {
+    fn default() -> Self {
+        FrontierStats {
+            pushes: 0,
+            pops: 0,
+            duplicate_pushes: 0,
+            peak_size: 0,
+            popped_costs: Vec::new(),
+        }
+    }
+}
+
+impl<C: Cost> FrontierStats<C> {
+    /// Returns the number of nodes still pending in the frontier.
+    pub fn pending(&self) -> usize {
+        self.pushes - self.pops
+    }
+
+    /// Returns the lowest popped cost, if any.
+    pub fn min_popped_cost(&self) -> Option<C> {
+        self.popped_costs.iter().copied().min_by(C::compare)
+    }
+
+    /// Returns the highest popped cost, if any.
+    pub fn max_popped_cost(&self) -> Option<C> {
+        self.popped_costs.iter().copied().max_by(C::compare)
+    }
+}
+
+impl FrontierStats<f64> {
+    /// Returns the mean popped cost, if any.
+    pub fn mean_popped_cost(&self) -> Option<f64> {
+        if self.popped_costs.is_empty() {
+            return None;
+        }
+        Some(self.popped_costs.iter().sum::<f64>() / self.popped_costs.len() as f64)
+    }
+}
+
+/// A frontier decorator collecting exploration metrics.
+///
+/// Wraps any frontier and forwards every operation to it while recording
+/// push/pop counts, duplicate pushes, peak size and popped costs.
+/// The wrapped frontier's ordering is left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::frontier::{Frontier, Instrumented, Queue};
+///
+/// let mut frontier = Instrumented::<Queue>::new();
+/// frontier.push(0, None);
+/// frontier.push(0, None);
+/// frontier.pop();
+///
+/// assert_eq!(frontier.stats().peak_size, 2);
+/// assert_eq!(frontier.stats().duplicate_pushes, 1);
+/// ```
+pub struct Instrumented<F, C = f64> {
+    inner: F,
+    stats: FrontierStats<C>,
+    pushed: HashSet<u32>,
+    pending_costs: HashMap<u32, Vec<C>>,
+}
+
+impl<F, C> Instrumented<F, C> {
+    /// Wraps an existing frontier.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The frontier deciding the exploration order
+    pub fn wrap(inner: F) -> Self {
+        Instrumented {
+            inner,
+            stats: FrontierStats::default(),
+            pushed: HashSet::new(),
+            pending_costs: HashMap::new(),
+        }
+    }
+
+    /// Returns the metrics collected so far.
+    pub fn stats(&self) -> &FrontierStats<C> {
+        &self.stats
+    }
+
+    /// Returns a reference to the wrapped frontier.
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+
+    /// Consumes the decorator, returning the wrapped frontier and the collected metrics.
+    pub fn into_parts(self) -> (F, FrontierStats<C>) {
+        (self.inner, self.stats)
+    }
+}
+
+impl<C: Cost, F: Frontier<C>> Frontier<C> for Instrumented<F, C> {
+    fn new() -> Self {
+        Self::wrap(F::new())
+    }
+
+    fn push(&mut self, id: u32, cost: Option<C>) {
+        self.inner.push(id, cost);
+
+        self.stats.pushes += 1;
+        if !self.pushed.insert(id) {
+            self.stats.duplicate_pushes += 1;
+        }
+        self.stats.peak_size = self.stats.peak_size.max(self.stats.pending());
+
+        if let Some(cost) = cost {
+            self.pending_costs.entry(id).or_default().push(cost);
+        }
+    }
+
+    fn pop(&mut self) -> Option<u32> {
+        let id = self.inner.pop()?;
+
+        self.stats.pops += 1;
+        if let Some(costs) = self.pending_costs.get_mut(&id) {
+            let cheapest = (0..costs.len()).min_by(|&a, &b| costs[a].compare(&costs[b]));
+            if let Some(index) = cheapest {
+                self.stats.popped_costs.push(costs.swap_remove(index));
+            }
+            if costs.is_empty() {
+                self.pending_costs.remove(&id);
+            }
+        }
+
+        Some(id)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::{MinHeap, Queue, Stack};
+
+    #[test]
+    fn test_instrumented_new_should_be_empty() {
+        let frontier = Instrumented::<Queue>::new();
+        assert!(frontier.is_empty());
+        assert_eq!(frontier.stats(), &FrontierStats::default());
+    }
+
+    #[test]
+    fn test_instrumented_should_preserve_inner_order() {
+        let mut queue = Instrumented::<Queue>::new();
+        let mut stack = Instrumented::<Stack>::new();
+
+        for id in 0..3 {
+            queue.push(id, None);
+            stack.push(id, None);
+        }
+
+        assert_eq!(queue.pop(), Some(0));
+        assert_eq!(stack.pop(), Some(2));
+    }
+
+    #[test]
+    fn test_instrumented_should_count_operations() {
+        let mut frontier = Instrumented::<Queue>::new();
+
+        frontier.push(0, None);
+        frontier.push(1, None);
+        frontier.pop();
+        frontier.push(2, None);
+        frontier.pop();
+        frontier.pop();
+        assert_eq!(frontier.pop(), None);
+
+        let stats = frontier.stats();
+        assert_eq!(stats.pushes, 3);
+        assert_eq!(stats.pops, 3);
+        assert_eq!(stats.pending(), 0);
+    }
+
+    #[test]
+    fn test_instrumented_should_track_peak_size() {
+        let mut frontier = Instrumented::<Stack>::new();
+
+        frontier.push(0, None);
+        frontier.push(1, None);
+        frontier.push(2, None);
+        frontier.pop();
+        frontier.pop();
+        frontier.push(3, None);
+
+        assert_eq!(frontier.stats().peak_size, 3);
+    }
+
+    #[test]
+    fn test_instrumented_should_count_duplicate_pushes() {
+        let mut frontier = Instrumented::<Queue>::new();
+
+        frontier.push(0, None);
+        frontier.pop();
+        frontier.push(0, None);
+        frontier.push(1, None);
+        frontier.push(1, None);
+
+        assert_eq!(frontier.stats().duplicate_pushes, 2);
+    }
+
+    #[test]
+    fn test_instrumented_should_record_popped_costs() {
+        let mut frontier = Instrumented::<MinHeap>::new();
+
+        frontier.push(0, Some(3.0));
+        frontier.push(1, Some(1.0));
+        frontier.push(2, None);
+        while frontier.pop().is_some() {}
+
+        let stats = frontier.stats();
+        assert_eq!(stats.popped_costs, vec![1.0, 3.0]);
+        assert_eq!(stats.min_popped_cost(), Some(1.0));
+        assert_eq!(stats.max_popped_cost(), Some(3.0));
+        assert_eq!(stats.mean_popped_cost(), Some(2.0));
+    }
+
+    #[test]
+    fn test_instrumented_should_attribute_cheapest_cost_to_reopened_node_on_min_heap() {
+        let mut frontier = Instrumented::<MinHeap>::new();
+
+        frontier.push(0, Some(2.0));
+        frontier.push(0, Some(5.0));
+        frontier.pop();
+
+        assert_eq!(frontier.stats().popped_costs, vec![2.0]);
+    }
+
+    #[test]
+    fn test_instrumented_should_attribute_cheapest_cost_to_node_reopened_cheaper() {
+        let mut frontier = Instrumented::<MinHeap>::new();
+
+        frontier.push(0, Some(5.0));
+        frontier.push(0, Some(2.0));
+        frontier.pop();
+        frontier.pop();
+
+        assert_eq!(frontier.stats().popped_costs, vec![2.0, 5.0]);
+    }
+
+    #[test]
+    fn test_instrumented_should_attribute_first_cost_to_reopened_node_on_queue() {
+        let mut frontier = Instrumented::<Queue>::new();
+
+        frontier.push(0, Some(2.0));
+        frontier.push(0, Some(5.0));
+        frontier.push(1, Some(1.0));
+        frontier.pop();
+        frontier.pop();
+
+        assert_eq!(frontier.stats().popped_costs, vec![2.0, 5.0]);
+    }
+
+    #[test]
+    fn test_instrumented_into_parts_should_return_stats() {
+        let mut frontier = Instrumented::wrap(Queue::new());
+        frontier.push(4, None);
+
+        let (mut queue, stats) = frontier.into_parts();
+        assert_eq!(stats.pushes, 1);
+        assert_eq!(queue.pop(), Some(4));
+    }
+}
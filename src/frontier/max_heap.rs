@@ -1,24 +1,63 @@
 use super::Frontier;
+use crate::graph::Cost;
+use crate::rng::SeededRng;
 use std::{cmp::Ordering, collections::BinaryHeap};
 
 // A MaxHeap (highest-value first out) frontier implementation for graph traversal.
-pub struct MaxHeap {
-    pub data: BinaryHeap<MaxHeapItem>,
+pub struct MaxHeap<C = f64> {
+    pub data: BinaryHeap<MaxHeapItem<C>>,
+    tie_breaker: Option<SeededRng>,
 }
 
-impl Frontier for MaxHeap {
-    fn new() -> Self {
+impl MaxHeap {
+    /// Creates a new empty heap using `f64` costs.
+    ///
+    /// Use `Frontier::new` or `Default::default` for another cost type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Cost> MaxHeap<C> {
+    /// Creates an empty heap breaking cost ties randomly.
+    ///
+    /// By default, nodes sharing the same cost are popped in an unspecified but
+    /// deterministic order. With random tie-breaking, each pushed node draws a
+    /// random rank used to order equal costs, driven by the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed of the internal random number generator
+    pub fn with_random_ties(seed: u64) -> Self {
         MaxHeap {
-            data: BinaryHeap::<MaxHeapItem>::new(),
+            data: BinaryHeap::new(),
+            tie_breaker: Some(SeededRng::new(seed)),
         }
     }
+}
 
-    fn push(&mut self, id: u32, _cost: Option<f64>) {
-        self.data.push(MaxHeapItem(_cost.unwrap_or(0.0), id));
+impl<C: Cost> Default for MaxHeap<C> {
+    fn default() -> Self {
+        MaxHeap {
+            data: BinaryHeap::new(),
+            tie_breaker: None,
+        }
+    }
+}
+
+impl<C: Cost> Frontier<C> for MaxHeap<C> {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, id: u32, cost: Option<C>) {
+        let rank = self.tie_breaker.as_mut().map_or(0, |rng| rng.next_u64());
+        self.data
+            .push(MaxHeapItem(cost.unwrap_or(C::zero()), rank, id));
     }
 
     fn pop(&mut self) -> Option<u32> {
-        Some(self.data.pop().unwrap().1)
+        self.data.pop().map(|item| item.2)
     }
 
     fn is_empty(&self) -> bool {
@@ -27,25 +66,25 @@ impl Frontier for MaxHeap {
 }
 
 #[derive(Debug)]
-pub struct MaxHeapItem(f64, u32);
+pub struct MaxHeapItem<C>(C, u64, u32);
 
-impl PartialEq for MaxHeapItem {
+impl<C: Cost> PartialEq for MaxHeapItem<C> {
     fn eq(&self, other: &Self) -> bool {
-        self.0.to_bits() == other.0.to_bits()
+        self.cmp(other) == Ordering::Equal
     }
 }
 
-impl Eq for MaxHeapItem {}
+impl<C: Cost> Eq for MaxHeapItem<C> {}
 
-impl PartialOrd for MaxHeapItem {
+impl<C: Cost> PartialOrd for MaxHeapItem<C> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for MaxHeapItem {
+impl<C: Cost> Ord for MaxHeapItem<C> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.total_cmp(&other.0)
+        self.0.compare(&other.0).then(self.1.cmp(&other.1))
     }
 }
 
@@ -105,4 +144,66 @@ mod tests {
         assert_eq!(min_heap.pop(), Some(id));
         assert!(min_heap.is_empty());
     }
+
+    #[test]
+    fn test_min_heap_pop_on_empty_should_return_none() {
+        let mut min_heap = MaxHeap::new();
+        assert_eq!(min_heap.pop(), None);
+    }
+
+    #[test]
+    fn test_min_heap_random_ties_should_keep_cost_order() {
+        let mut min_heap = MaxHeap::with_random_ties(1);
+
+        for id in 0..10 {
+            min_heap.push(id, Some((id % 2) as f64));
+        }
+
+        let popped: Vec<u32> = std::iter::from_fn(|| min_heap.pop()).collect();
+        let costs: Vec<u32> = popped.iter().map(|id| id % 2).collect();
+
+        assert!(costs[..5].iter().all(|&c| c == costs[0]));
+        assert!(costs[5..].iter().all(|&c| c != costs[0]));
+    }
+
+    #[test]
+    fn test_min_heap_random_ties_should_depend_on_seed_only() {
+        let orders: Vec<Vec<u32>> = [5, 5, 6]
+            .iter()
+            .map(|&seed| {
+                let mut min_heap = MaxHeap::with_random_ties(seed);
+                for id in 0..20 {
+                    min_heap.push(id, Some(1.0));
+                }
+                std::iter::from_fn(|| min_heap.pop()).collect()
+            })
+            .collect();
+
+        assert_eq!(orders[0], orders[1]);
+        assert_ne!(orders[0], orders[2]);
+    }
+
+    #[test]
+    fn test_min_heap_should_support_integer_costs() {
+        let mut min_heap = MaxHeap::<u64>::default();
+
+        min_heap.push(0, Some(2));
+        min_heap.push(1, Some(1));
+        min_heap.push(2, Some(3));
+
+        let popped: Vec<u32> = std::iter::from_fn(|| min_heap.pop()).collect();
+        assert_eq!(popped, vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn test_min_heap_should_order_tuples_lexicographically() {
+        let mut min_heap = MaxHeap::<(u64, f64)>::default();
+
+        min_heap.push(0, Some((1, 5.0)));
+        min_heap.push(1, Some((0, 9.0)));
+        min_heap.push(2, Some((1, 2.0)));
+
+        let popped: Vec<u32> = std::iter::from_fn(|| min_heap.pop()).collect();
+        assert_eq!(popped, vec![0, 2, 1]);
+    }
 }
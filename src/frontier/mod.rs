@@ -1,18 +1,28 @@
+pub mod instrumented;
 pub mod max_heap;
 pub mod min_heap;
 pub mod queue;
+pub mod random;
+pub mod softmax_heap;
 pub mod stack;
 
+pub use instrumented::{FrontierStats, Instrumented};
 pub use max_heap::MaxHeap;
 pub use min_heap::MinHeap;
 pub use queue::Queue;
+pub use random::RandomFrontier;
+pub use softmax_heap::SoftmaxHeap;
 pub use stack::Stack;
 
+use crate::graph::Cost;
+
 /// A strategy for managing which nodes to explore next during graph traversal.
 ///
 /// Frontiers determine the order in which nodes are visited. Different implementations
 /// can be used to match predefined search algorith (BFS, DFS, Dijkstra...)
-pub trait Frontier {
+///
+/// Frontiers are generic over the `Cost` type used for priorities (`f64` by default).
+pub trait Frontier<C: Cost = f64> {
     /// Creates a new empty frontier.
     fn new() -> Self
     where
@@ -24,7 +34,7 @@ pub trait Frontier {
     ///
     /// * `id`   - Id of the node to add
     /// * `cost` - Optional cost to handle priority
-    fn push(&mut self, id: u32, cost: Option<f64>);
+    fn push(&mut self, id: u32, cost: Option<C>);
 
     /// Removes and returns the next node ID to visit.
     ///
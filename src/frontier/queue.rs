@@ -1,19 +1,38 @@
 use super::Frontier;
+use crate::graph::Cost;
 use std::collections::VecDeque;
+use std::marker::PhantomData;
 
 /// A FIFO (First-In-First-Out) frontier implementation for graph traversal.
-pub struct Queue {
+pub struct Queue<C = f64> {
     pub data: VecDeque<u32>,
+    _cost: PhantomData<C>,
 }
 
-impl Frontier for Queue {
-    fn new() -> Self {
+impl Queue {
+    /// Creates a new empty frontier using `f64` costs.
+    ///
+    /// Use `Frontier::new` or `Default::default` for another cost type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C> Default for Queue<C> {
+    fn default() -> Self {
         Queue {
             data: VecDeque::new(),
+            _cost: PhantomData,
         }
     }
+}
+
+impl<C: Cost> Frontier<C> for Queue<C> {
+    fn new() -> Self {
+        Self::default()
+    }
 
-    fn push(&mut self, id: u32, _cost: Option<f64>) {
+    fn push(&mut self, id: u32, _cost: Option<C>) {
         self.data.push_back(id);
     }
 
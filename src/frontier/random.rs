@@ -0,0 +1,132 @@
+use super::Frontier;
+use crate::graph::Cost;
+use crate::rng::SeededRng;
+use std::marker::PhantomData;
+
+/// A frontier popping a uniformly random pending node.
+///
+/// Useful for randomized explorations such as maze generation or Monte-Carlo
+/// reachability estimates. The random sequence is driven by a `SeededRng`,
+/// so two frontiers created with the same seed behave identically.
+pub struct RandomFrontier<C = f64> {
+    pub data: Vec<u32>,
+    rng: SeededRng,
+    _cost: PhantomData<C>,
+}
+
+impl RandomFrontier {
+    /// Creates an empty random frontier using `f64` costs and the default seed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C> RandomFrontier<C> {
+    /// Creates an empty random frontier driven by the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed of the internal random number generator
+    pub fn with_seed(seed: u64) -> Self {
+        RandomFrontier {
+            data: Vec::new(),
+            rng: SeededRng::new(seed),
+            _cost: PhantomData,
+        }
+    }
+}
+
+impl<C> Default for RandomFrontier<C> {
+    fn default() -> Self {
+        RandomFrontier {
+            data: Vec::new(),
+            rng: SeededRng::default(),
+            _cost: PhantomData,
+        }
+    }
+}
+
+impl<C: Cost> Frontier<C> for RandomFrontier<C> {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&mut self, id: u32, _cost: Option<C>) {
+        self.data.push(id);
+    }
+
+    fn pop(&mut self) -> Option<u32> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let index = self.rng.next_below(self.data.len());
+        Some(self.data.swap_remove(index))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(frontier: &mut RandomFrontier) -> Vec<u32> {
+        let mut popped = Vec::new();
+        while let Some(id) = frontier.pop() {
+            popped.push(id);
+        }
+        popped
+    }
+
+    #[test]
+    fn test_random_frontier_new_should_be_empty() {
+        let frontier = RandomFrontier::new();
+        assert!(frontier.is_empty());
+    }
+
+    #[test]
+    fn test_random_frontier_pop_on_empty_should_return_none() {
+        let mut frontier = RandomFrontier::new();
+        assert_eq!(frontier.pop(), None);
+    }
+
+    #[test]
+    fn test_random_frontier_pop_should_return_every_pushed_id_once() {
+        let mut frontier: RandomFrontier = RandomFrontier::with_seed(1);
+        for id in 0..10 {
+            frontier.push(id, None);
+        }
+
+        let mut popped = drain(&mut frontier);
+        popped.sort();
+
+        assert_eq!(popped, (0..10).collect::<Vec<_>>());
+        assert!(frontier.is_empty());
+    }
+
+    #[test]
+    fn test_random_frontier_same_seed_should_reproduce_order() {
+        let mut a: RandomFrontier = RandomFrontier::with_seed(42);
+        let mut b: RandomFrontier = RandomFrontier::with_seed(42);
+        for id in 0..20 {
+            a.push(id, None);
+            b.push(id, None);
+        }
+
+        assert_eq!(drain(&mut a), drain(&mut b));
+    }
+
+    #[test]
+    fn test_random_frontier_should_not_follow_insertion_order() {
+        let mut frontier: RandomFrontier = RandomFrontier::with_seed(42);
+        for id in 0..20 {
+            frontier.push(id, None);
+        }
+
+        let popped = drain(&mut frontier);
+        assert_ne!(popped, (0..20).collect::<Vec<_>>());
+        assert_ne!(popped, (0..20).rev().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,191 @@
+use super::Frontier;
+use crate::rng::SeededRng;
+
+/// A stochastic priority frontier sampling nodes according to their cost.
+///
+/// Each pending node is popped with a probability proportional to
+/// `exp(-cost / temperature)` (Boltzmann distribution), so cheaper nodes are
+/// favored without being systematically chosen:
+/// - a temperature close to zero behaves like a `MinHeap`
+/// - a very high temperature behaves like a `RandomFrontier`
+///
+/// A non-positive temperature always pops the cheapest node.
+///
+/// Popping is linear in the number of pending nodes.
+pub struct SoftmaxHeap {
+    pub data: Vec<(f64, u32)>,
+    temperature: f64,
+    rng: SeededRng,
+}
+
+impl SoftmaxHeap {
+    /// Creates an empty softmax heap.
+    ///
+    /// # Arguments
+    ///
+    /// * `temperature` - Controls how strongly sampling favors cheap nodes
+    /// * `seed`        - Seed of the internal random number generator
+    pub fn with_temperature(temperature: f64, seed: u64) -> Self {
+        SoftmaxHeap {
+            data: Vec::new(),
+            temperature,
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    /// Returns the sampling temperature.
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    fn cheapest_index(&self) -> usize {
+        self.data
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.0.total_cmp(&b.1.0))
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    fn sample_index(&mut self) -> usize {
+        let min_cost = self.data[self.cheapest_index()].0;
+
+        // Costs are shifted by the minimum so the cheapest weight is exactly 1.0,
+        // preventing underflow when every cost is large.
+        let weights: Vec<f64> = self
+            .data
+            .iter()
+            .map(|(cost, _)| (-(cost - min_cost) / self.temperature).exp())
+            .collect();
+        let total: f64 = weights.iter().sum();
+
+        let mut target = self.rng.next_f64() * total;
+        for (i, weight) in weights.iter().enumerate() {
+            if target < *weight {
+                return i;
+            }
+            target -= weight;
+        }
+        self.data.len() - 1
+    }
+}
+
+impl Frontier for SoftmaxHeap {
+    fn new() -> Self {
+        SoftmaxHeap {
+            data: Vec::new(),
+            temperature: 1.0,
+            rng: SeededRng::default(),
+        }
+    }
+
+    fn push(&mut self, id: u32, cost: Option<f64>) {
+        self.data.push((cost.unwrap_or(0.0), id));
+    }
+
+    fn pop(&mut self) -> Option<u32> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let index = if self.temperature > 0.0 {
+            self.sample_index()
+        } else {
+            self.cheapest_index()
+        };
+
+        Some(self.data.swap_remove(index).1)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_softmax_heap_new_should_be_empty() {
+        let heap = SoftmaxHeap::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.temperature(), 1.0);
+    }
+
+    #[test]
+    fn test_softmax_heap_pop_on_empty_should_return_none() {
+        let mut heap = SoftmaxHeap::new();
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_softmax_heap_zero_temperature_should_follow_min_cost_order() {
+        let mut heap = SoftmaxHeap::with_temperature(0.0, 1);
+        heap.push(0, Some(3.0));
+        heap.push(1, Some(1.0));
+        heap.push(2, Some(2.0));
+
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(0));
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_softmax_heap_low_temperature_should_favor_cheap_nodes() {
+        let mut cheap_first = 0;
+
+        for seed in 0..200 {
+            let mut heap = SoftmaxHeap::with_temperature(0.5, seed);
+            heap.push(0, Some(10.0));
+            heap.push(1, Some(0.0));
+
+            if heap.pop() == Some(1) {
+                cheap_first += 1;
+            }
+        }
+
+        assert!(cheap_first > 190);
+    }
+
+    #[test]
+    fn test_softmax_heap_high_temperature_should_sample_both_nodes() {
+        let mut cheap_first = 0;
+
+        for seed in 0..200 {
+            let mut heap = SoftmaxHeap::with_temperature(1000.0, seed);
+            heap.push(0, Some(10.0));
+            heap.push(1, Some(0.0));
+
+            if heap.pop() == Some(1) {
+                cheap_first += 1;
+            }
+        }
+
+        assert!(cheap_first > 60 && cheap_first < 140);
+    }
+
+    #[test]
+    fn test_softmax_heap_should_handle_large_costs() {
+        let mut heap = SoftmaxHeap::with_temperature(1.0, 3);
+        heap.push(0, Some(1e6));
+        heap.push(1, Some(1e6 + 100.0));
+
+        assert_eq!(heap.pop(), Some(0));
+    }
+
+    #[test]
+    fn test_softmax_heap_same_seed_should_reproduce_order() {
+        let mut a = SoftmaxHeap::with_temperature(2.0, 9);
+        let mut b = SoftmaxHeap::with_temperature(2.0, 9);
+        for id in 0..20 {
+            a.push(id, Some((id % 4) as f64));
+            b.push(id, Some((id % 4) as f64));
+        }
+
+        while !a.is_empty() {
+            assert_eq!(a.pop(), b.pop());
+        }
+    }
+}
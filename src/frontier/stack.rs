@@ -1,16 +1,37 @@
 use super::Frontier;
+use crate::graph::Cost;
+use std::marker::PhantomData;
 
 /// A LIFO (Last-In-First-Out) frontier implementation for graph traversal.
-pub struct Stack {
+pub struct Stack<C = f64> {
     pub data: Vec<u32>,
+    _cost: PhantomData<C>,
 }
 
-impl Frontier for Stack {
+impl Stack {
+    /// Creates a new empty frontier using `f64` costs.
+    ///
+    /// Use `Frontier::new` or `Default::default` for another cost type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C> Default for Stack<C> {
+    fn default() -> Self {
+        Stack {
+            data: Vec::new(),
+            _cost: PhantomData,
+        }
+    }
+}
+
+impl<C: Cost> Frontier<C> for Stack<C> {
     fn new() -> Self {
-        Stack { data: Vec::new() }
+        Self::default()
     }
 
-    fn push(&mut self, id: u32, _cost: Option<f64>) {
+    fn push(&mut self, id: u32, _cost: Option<C>) {
         self.data.push(id);
     }
 
@@ -0,0 +1,223 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// A value that can be accumulated and compared along a path.
+///
+/// Costs are carried by edge weights, pushed to frontiers and computed by
+/// visitors. `f64` is the default cost type throughout the crate, integer
+/// types avoid floating point accumulation drift on long paths, while tuples
+/// and arrays provide lexicographic multi-level costs.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::graph::Cost;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(3u64.plus(4), 7);
+/// assert_eq!((1u64, 9.0).compare(&(2, 0.0)), Ordering::Less);
+/// ```
+pub trait Cost: Copy + PartialOrd + Debug {
+    /// Returns the neutral cost, used for path origins.
+    fn zero() -> Self;
+
+    /// Returns the unit cost, used for unweighted connections.
+    fn one() -> Self;
+
+    /// Accumulates two costs.
+    ///
+    /// Integer costs saturate, so their `MAX` acts as an infinite cost like
+    /// `f64::INFINITY` does.
+    fn plus(self, other: Self) -> Self;
+
+    /// Totally orders two costs.
+    ///
+    /// Must be consistent with `PartialOrd` whenever the latter is defined.
+    fn compare(&self, other: &Self) -> Ordering;
+}
+
+impl Cost for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn plus(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+macro_rules! impl_integer_cost {
+    ($($t:ty),*) => {
+        $(
+            impl Cost for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn plus(self, other: Self) -> Self {
+                    self.saturating_add(other)
+                }
+
+                fn compare(&self, other: &Self) -> Ordering {
+                    self.cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_cost!(u64, i64);
+
+/// Tuples are compared lexicographically and accumulated component-wise.
+impl<A: Cost, B: Cost> Cost for (A, B) {
+    fn zero() -> Self {
+        (A::zero(), B::zero())
+    }
+
+    fn one() -> Self {
+        (A::one(), B::one())
+    }
+
+    fn plus(self, other: Self) -> Self {
+        (self.0.plus(other.0), self.1.plus(other.1))
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        self.0
+            .compare(&other.0)
+            .then_with(|| self.1.compare(&other.1))
+    }
+}
+
+/// Tuples are compared lexicographically and accumulated component-wise.
+impl<A: Cost, B: Cost, C: Cost> Cost for (A, B, C) {
+    fn zero() -> Self {
+        (A::zero(), B::zero(), C::zero())
+    }
+
+    fn one() -> Self {
+        (A::one(), B::one(), C::one())
+    }
+
+    fn plus(self, other: Self) -> Self {
+        (
+            self.0.plus(other.0),
+            self.1.plus(other.1),
+            self.2.plus(other.2),
+        )
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        self.0
+            .compare(&other.0)
+            .then_with(|| self.1.compare(&other.1))
+            .then_with(|| self.2.compare(&other.2))
+    }
+}
+
+/// Arrays are compared lexicographically and accumulated component-wise.
+///
+/// They model multi-criteria costs, such as `[distance, toll, time]`.
+impl<T: Cost, const N: usize> Cost for [T; N] {
+    fn zero() -> Self {
+        [T::zero(); N]
+    }
+
+    fn one() -> Self {
+        [T::one(); N]
+    }
+
+    fn plus(self, other: Self) -> Self {
+        let mut sum = self;
+        for (value, other) in sum.iter_mut().zip(other) {
+            *value = value.plus(other);
+        }
+        sum
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        self.iter()
+            .zip(other)
+            .map(|(a, b)| a.compare(b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_cost_uses_total_order() {
+        assert_eq!(f64::zero(), 0.0);
+        assert_eq!(1.5.plus(2.0), 3.5);
+        assert_eq!(1.0.compare(&2.0), Ordering::Less);
+        assert_eq!(f64::NAN.compare(&1.0), Ordering::Greater);
+    }
+
+    #[test]
+    fn integer_costs_accumulate_exactly() {
+        let mut total = u64::zero();
+        for _ in 0..1000 {
+            total = total.plus(u64::one());
+        }
+        assert_eq!(total, 1000);
+        assert_eq!((-3i64).plus(5), 2);
+        assert_eq!((-3i64).compare(&2), Ordering::Less);
+    }
+
+    #[test]
+    fn integer_costs_saturate_instead_of_overflowing() {
+        assert_eq!(u64::MAX.plus(1), u64::MAX);
+        assert_eq!((u64::MAX - 2).plus(5), u64::MAX);
+        assert_eq!(i64::MAX.plus(i64::MAX), i64::MAX);
+        assert_eq!(i64::MIN.plus(-1), i64::MIN);
+        assert_eq!((u64::MAX, 1u64).plus((1, 1)), (u64::MAX, 2));
+    }
+
+    #[test]
+    fn pairs_compare_lexicographically() {
+        assert_eq!((1u64, 5u64).compare(&(2, 0)), Ordering::Less);
+        assert_eq!((2u64, 0u64).compare(&(1, 5)), Ordering::Greater);
+        assert_eq!((1u64, 5u64).compare(&(1, 6)), Ordering::Less);
+        assert_eq!((1u64, 5u64).compare(&(1, 5)), Ordering::Equal);
+    }
+
+    #[test]
+    fn triples_compare_lexicographically() {
+        assert_eq!((1u64, 2i64, 3.0).compare(&(1, 2, 4.0)), Ordering::Less);
+        assert_eq!((1u64, 3i64, 0.0).compare(&(1, 2, 4.0)), Ordering::Greater);
+    }
+
+    #[test]
+    fn tuples_accumulate_component_wise() {
+        assert_eq!((1u64, 2.0).plus((3, 0.5)), (4, 2.5));
+        assert_eq!(
+            (1u64, 1i64, 1.0).plus(<(u64, i64, f64)>::one()),
+            (2, 2, 2.0)
+        );
+        assert_eq!(<(u64, f64)>::zero(), (0, 0.0));
+    }
+
+    #[test]
+    fn arrays_compare_lexicographically_and_accumulate_component_wise() {
+        assert_eq!([1.0, 5.0, 0.0].compare(&[1.0, 6.0, -1.0]), Ordering::Less);
+        assert_eq!([2u64, 0].compare(&[1, 9]), Ordering::Greater);
+        assert_eq!([1u64, 2].compare(&[1, 2]), Ordering::Equal);
+        assert_eq!([1.0, 2.0].plus([0.5, 0.5]), [1.5, 2.5]);
+        assert_eq!(<[u64; 3]>::one(), [1, 1, 1]);
+    }
+}
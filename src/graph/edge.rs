@@ -1,10 +1,16 @@
+use super::Cost;
+
 /// Represents an edge connecting two nodes in a graph.
 ///
 /// Edges are directed connections with optional weights. Implementations can
-/// choose to store weight or use the default unit weight of 1.0.
+/// choose to store weight or use the default unit weight (`Cost::one`).
 ///
-/// Implementations define how edges are created and whether they store weights.
+/// Implementations define how edges are created, whether they store weights
+/// and which `Cost` type their weights use (usually `f64`).
 pub trait Edge {
+    /// The type of the edge weight.
+    type Weight: Cost;
+
     /// Creates a new edge from source to destination with optional weight.
     ///
     /// # Arguments
@@ -12,7 +18,7 @@ pub trait Edge {
     /// * `from` - Source node ID
     /// * `to` - Destination node ID
     /// * `weight` - Optional edge weight (ignored for unweighted edges)
-    fn new(from: u32, to: u32, weight: Option<f64>) -> Self;
+    fn new(from: u32, to: u32, weight: Option<Self::Weight>) -> Self;
 
     /// Returns the destination node ID.
     fn to(&self) -> u32 {
@@ -25,10 +31,10 @@ pub trait Edge {
     }
 
     /// Returns the weight of the connection.
-    fn weight(&self) -> f64 {
-        1.0
+    fn weight(&self) -> Self::Weight {
+        Self::Weight::one()
     }
 
-    /// Set the weight of the
-    fn set_weight(&mut self, _weight: f64) {}
+    /// Set the weight of the connection (ignored for unweighted edges).
+    fn set_weight(&mut self, _weight: Self::Weight) {}
 }
@@ -0,0 +1,18 @@
+use super::Edge;
+
+/// A traversal context giving access to the edges leaving each node.
+///
+/// Visitors reading edge weights are written against this trait rather than
+/// a concrete graph, so they run unchanged on a materialized `Graph` and on
+/// an `ImplicitGraph` generating its edges on demand.
+pub trait EdgeContext {
+    type Edge: Edge;
+
+    /// Calls `f` with the edges leaving a node, empty if it has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The source node ID
+    /// * `f` - Receives the outgoing edges
+    fn with_edges_from<R>(&self, node_id: u32, f: impl FnOnce(&[Self::Edge]) -> R) -> R;
+}
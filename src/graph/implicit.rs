@@ -0,0 +1,274 @@
+use super::{Cost, Edge, EdgeContext};
+use crate::frontier::Frontier;
+use crate::strategy::{NeighborProvider, Visitor};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// A graph whose edges are generated during traversal.
+///
+/// Wraps a `NeighborProvider` and asks it for the successors of each node
+/// when the traversal reaches it, so nothing is materialized up front.
+/// Frontiers, visitors and termination policies are the same as for a
+/// `Graph`.
+///
+/// Generated edges can be memoized, trading memory for the cost of
+/// generating them again when a node is expanded several times. Without
+/// memoization, only the edges of the last expanded node are kept, which
+/// is enough for visitors looking up the weights of the edges being
+/// explored.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::frontier::Queue;
+/// use hodos::graph::{Edge, ImplicitGraph};
+/// use hodos::preset::UnweightedEdge;
+/// use hodos::preset::policies::traversal::GoalReached;
+/// use hodos::preset::visitors::{SimpleVisitor, TrackParent};
+///
+/// // Reach 10 from 1 with the moves `+1` and `*2`
+/// let graph = ImplicitGraph::new(|n: u32| {
+///     vec![
+///         UnweightedEdge::new(n, n + 1, None),
+///         UnweightedEdge::new(n, n * 2, None),
+///     ]
+/// });
+///
+/// let mut visitor = SimpleVisitor::new(GoalReached::new(10));
+/// graph.traverse(1, &mut Queue::new(), &mut visitor);
+///
+/// let mut path = vec![10];
+/// while let Some(parent) = visitor.get_parent(path[path.len() - 1]) {
+///     path.push(parent);
+/// }
+/// assert_eq!(path, vec![10, 5, 4, 2, 1]);
+/// ```
+pub struct ImplicitGraph<P: NeighborProvider> {
+    provider: P,
+    memoize: bool,
+    cache: RefCell<HashMap<u32, Vec<P::Edge>>>,
+}
+
+impl<P: NeighborProvider> ImplicitGraph<P> {
+    /// Creates an implicit graph generating edges again on each expansion.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Generates the edges leaving each node
+    pub fn new(provider: P) -> Self {
+        ImplicitGraph {
+            provider,
+            memoize: false,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Creates an implicit graph keeping every generated edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Generates the edges leaving each node
+    pub fn memoized(provider: P) -> Self {
+        ImplicitGraph {
+            memoize: true,
+            ..Self::new(provider)
+        }
+    }
+
+    /// Returns the wrapped provider.
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Returns the number of nodes whose edges are currently stored.
+    pub fn cached_count(&self) -> usize {
+        self.cache.borrow().len()
+    }
+
+    /// Returns the edges leaving a node, generating them if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The source node ID
+    pub fn get_edges(&self, node_id: u32) -> Vec<P::Edge>
+    where
+        P::Edge: Clone,
+    {
+        self.with_edges_from(node_id, <[P::Edge]>::to_vec)
+    }
+
+    /// Generates and stores the edges leaving a node, unless already stored.
+    fn ensure_cached(&self, node_id: u32) {
+        if self.cache.borrow().contains_key(&node_id) {
+            return;
+        }
+
+        let edges = self.provider.neighbors(node_id);
+        let mut cache = self.cache.borrow_mut();
+        if !self.memoize {
+            cache.clear();
+        }
+        cache.insert(node_id, edges);
+    }
+
+    /// Traverses the graph using pluggable exploration strategies.
+    ///
+    /// Follows the same flow as `Graph::traverse`, generating the edges of
+    /// each node when it is popped from the frontier.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - ID of the starting node
+    /// * `frontier` - Strategy controlling which nodes to explore next
+    /// * `visitor` - Logic for exploration decisions and node processing
+    pub fn traverse<C: Cost>(
+        &self,
+        start: u32,
+        frontier: &mut dyn Frontier<C>,
+        visitor: &mut dyn Visitor<Self, C>,
+    ) {
+        frontier.push(start, Some(visitor.init_cost(start, self)));
+
+        while let Some(current_id) = frontier.pop() {
+            let connections: Vec<(u32, u32)> = self.with_edges_from(current_id, |edges| {
+                edges.iter().map(|edge| (edge.from(), edge.to())).collect()
+            });
+            for (from, to) in connections {
+                if visitor.should_explore(from, to, self) {
+                    frontier.push(to, Some(visitor.exploration_cost(from, to, self)));
+                }
+            }
+
+            visitor.visit(current_id, self);
+
+            if visitor.should_stop(current_id, self) {
+                break;
+            }
+        }
+    }
+}
+
+impl<P: NeighborProvider> EdgeContext for ImplicitGraph<P> {
+    type Edge = P::Edge;
+
+    /// Lends the stored edges of a node, generating them if needed.
+    ///
+    /// `f` must not ask the same graph for the edges of another node.
+    fn with_edges_from<R>(&self, node_id: u32, f: impl FnOnce(&[P::Edge]) -> R) -> R {
+        self.ensure_cached(node_id);
+        f(&self.cache.borrow()[&node_id])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::{MinHeap, Queue};
+    use crate::preset::policies::traversal::{GoalReached, NoTermination};
+    use crate::preset::visitors::{SimpleVisitor, TrackCost, TrackParent, WeightedVisitor};
+    use crate::preset::{UnweightedEdge, WeightedEdge};
+    use std::cell::Cell;
+
+    /// Counts how many times edges are generated.
+    struct Counting<'a> {
+        calls: &'a Cell<usize>,
+    }
+
+    impl NeighborProvider for Counting<'_> {
+        type Edge = UnweightedEdge;
+
+        fn neighbors(&self, node_id: u32) -> Vec<UnweightedEdge> {
+            self.calls.set(self.calls.get() + 1);
+            (node_id < 20)
+                .then(|| UnweightedEdge::new(node_id, node_id + 1, None))
+                .into_iter()
+                .collect()
+        }
+    }
+
+    #[test]
+    fn generates_only_expanded_nodes() {
+        let calls = Cell::new(0);
+        let graph = ImplicitGraph::new(Counting { calls: &calls });
+
+        let mut visitor = SimpleVisitor::new(GoalReached::new(5));
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert_eq!(visitor.get_parent(5), Some(4));
+        assert_eq!(calls.get(), 6);
+        assert_eq!(graph.cached_count(), 1);
+    }
+
+    #[test]
+    fn visitor_lookups_reuse_generated_edges() {
+        let calls = Cell::new(0);
+        let graph = ImplicitGraph::new(Counting { calls: &calls });
+
+        let mut visitor = WeightedVisitor::new(GoalReached::new(5));
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(5), Some(5.0));
+        assert_eq!(calls.get(), 6);
+    }
+
+    #[test]
+    fn memoization_keeps_generated_edges() {
+        let calls = Cell::new(0);
+        let graph = ImplicitGraph::memoized(Counting { calls: &calls });
+
+        graph.get_edges(3);
+        graph.get_edges(4);
+        graph.get_edges(3);
+
+        assert_eq!(calls.get(), 2);
+        assert_eq!(graph.cached_count(), 2);
+    }
+
+    #[test]
+    fn weighted_visitor_runs_dijkstra_on_generated_edges() {
+        // Moving right costs 1, jumping two steps costs 3
+        let graph = ImplicitGraph::new(|n: u32| {
+            vec![
+                WeightedEdge::new(n, n + 1, Some(1.0)),
+                WeightedEdge::new(n, n + 2, Some(3.0)),
+            ]
+        });
+
+        let mut visitor = WeightedVisitor::new(GoalReached::new(6));
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(6), Some(6.0));
+    }
+
+    #[test]
+    fn memoized_and_plain_graphs_agree() {
+        // A 6x6 grid with a wall on column 2 except at the bottom row
+        let grid = |n: u32| {
+            let (row, col) = (n / 6, n % 6);
+            let open = |r: u32, c: u32| c != 2 || r == 5;
+            let mut edges = Vec::new();
+            for (dr, dc) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+                let (r, c) = (row as i32 + dr, col as i32 + dc);
+                if (0..6).contains(&r) && (0..6).contains(&c) && open(r as u32, c as u32) {
+                    edges.push(WeightedEdge::new(n, r as u32 * 6 + c as u32, Some(1.0)));
+                }
+            }
+            edges
+        };
+
+        let plain = ImplicitGraph::new(grid);
+        let memoized = ImplicitGraph::memoized(grid);
+
+        let mut first = WeightedVisitor::new(NoTermination);
+        plain.traverse(0, &mut MinHeap::new(), &mut first);
+        let mut second = WeightedVisitor::new(NoTermination);
+        memoized.traverse(0, &mut MinHeap::new(), &mut second);
+
+        for id in 0..36 {
+            assert_eq!(first.cost_to(id), second.cost_to(id));
+        }
+        assert_eq!(first.cost_to(5), Some(15.0));
+        assert_eq!(first.cost_to(14), None);
+        assert_eq!(memoized.cached_count(), 36 - 5);
+    }
+}
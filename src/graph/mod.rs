@@ -1,7 +1,13 @@
+pub mod cost;
 pub mod edge;
+pub mod edge_context;
+pub mod implicit;
 pub mod node;
 
+pub use cost::Cost;
 pub use edge::Edge;
+pub use edge_context::EdgeContext;
+pub use implicit::ImplicitGraph;
 pub use node::Node;
 
 use crate::frontier::Frontier;
@@ -82,6 +88,9 @@ where
     /// - A `Visitor` to make exploration decisions and perform per-node operations
     /// - A `Terminate` policy to decide when to stop traversal
     ///
+    /// Frontier and visitor must agree on the `Cost` type used to prioritize
+    /// nodes, which defaults to `f64` and is independent from the edge weight type.
+    ///
     /// # Arguments
     ///
     /// * `start` - ID of the starting node
@@ -97,11 +106,11 @@ where
     ///    - Push unexplored neighbors to frontier with visitor-computed costs
     ///    - Visit the current node (perform side effects, logging, etc.)
     ///    - Ask visitor about termination condition
-    pub fn traverse(
+    pub fn traverse<C: Cost>(
         &self,
         start: u32,
-        frontier: &mut dyn Frontier,
-        visitor: &mut dyn Visitor<Self>,
+        frontier: &mut dyn Frontier<C>,
+        visitor: &mut dyn Visitor<Self, C>,
     ) {
         frontier.push(start, Some(visitor.init_cost(start, self)));
 
@@ -131,3 +140,14 @@ where
         }
     }
 }
+
+impl<TNode, TEdge> EdgeContext for Graph<TNode, TEdge>
+where
+    TEdge: Edge,
+{
+    type Edge = TEdge;
+
+    fn with_edges_from<R>(&self, node_id: u32, f: impl FnOnce(&[TEdge]) -> R) -> R {
+        f(self.edges.get(&node_id).map_or(&[], Vec::as_slice))
+    }
+}
@@ -1,6 +1,8 @@
+pub mod algorithm;
 pub mod builder;
 pub mod frontier;
 pub mod graph;
 pub mod policy;
 pub mod preset;
+pub mod rng;
 pub mod strategy;
@@ -1,5 +1,7 @@
+pub mod resource_edge;
 pub mod unweighted_edge;
 pub mod weighted_edge;
 
+pub use resource_edge::{ConsumeResources, ResourceEdge};
 pub use unweighted_edge::UnweightedEdge;
-pub use weighted_edge::WeightedEdge;
+pub use weighted_edge::{MultiWeightedEdge, WeightedEdge};
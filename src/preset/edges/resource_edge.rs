@@ -0,0 +1,88 @@
+use crate::graph::Edge;
+
+/// An edge that consumes resources when traversed.
+///
+/// Resource consumption is tracked independently of the edge weight, which
+/// remains the cost being minimized.
+pub trait ConsumeResources<const R: usize>: Edge {
+    /// Returns the amount of each resource consumed by traversing the edge.
+    fn consumption(&self) -> [f64; R];
+}
+
+/// A weighted edge consuming `R` resources (fuel, transfers, time windows...).
+///
+/// Suitable for resource-constrained shortest path searches, where the weight
+/// is minimized while the cumulated consumption must stay within limits.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::preset::edges::{ConsumeResources, ResourceEdge};
+/// use hodos::graph::Edge;
+///
+/// let edge = ResourceEdge::with_consumption(0, 1, 5.0, [2.0, 1.0]);
+/// assert_eq!(edge.weight(), 5.0);
+/// assert_eq!(edge.consumption(), [2.0, 1.0]);
+///
+/// let free = ResourceEdge::<2>::new(0, 1, Some(5.0));
+/// assert_eq!(free.consumption(), [0.0, 0.0]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ResourceEdge<const R: usize> {
+    to: u32,
+    from: u32,
+    weight: f64,
+    consumption: [f64; R],
+}
+
+impl<const R: usize> ResourceEdge<R> {
+    /// Creates a new edge with the given weight and resource consumption.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Destination node ID
+    /// * `weight` - Cost of the connection
+    /// * `consumption` - Amount of each resource consumed by the connection
+    pub fn with_consumption(from: u32, to: u32, weight: f64, consumption: [f64; R]) -> Self {
+        ResourceEdge {
+            from,
+            to,
+            weight,
+            consumption,
+        }
+    }
+}
+
+impl<const R: usize> Default for ResourceEdge<R> {
+    fn default() -> Self {
+        ResourceEdge::with_consumption(0, 0, 0.0, [0.0; R])
+    }
+}
+
+impl<const R: usize> Edge for ResourceEdge<R> {
+    type Weight = f64;
+
+    /// Creates an edge consuming no resource.
+    fn new(from: u32, to: u32, weight: Option<f64>) -> Self {
+        ResourceEdge::with_consumption(from, to, weight.unwrap_or(1.0), [0.0; R])
+    }
+    fn to(&self) -> u32 {
+        self.to
+    }
+    fn from(&self) -> u32 {
+        self.from
+    }
+    fn weight(&self) -> f64 {
+        self.weight
+    }
+    fn set_weight(&mut self, weight: f64) {
+        self.weight = weight;
+    }
+}
+
+impl<const R: usize> ConsumeResources<R> for ResourceEdge<R> {
+    fn consumption(&self) -> [f64; R] {
+        self.consumption
+    }
+}
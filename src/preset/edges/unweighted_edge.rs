@@ -23,6 +23,8 @@ pub struct UnweightedEdge {
 }
 
 impl Edge for UnweightedEdge {
+    type Weight = f64;
+
     fn new(from: u32, to: u32, _weight: Option<f64>) -> Self {
         UnweightedEdge { from, to }
     }
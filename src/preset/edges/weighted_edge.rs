@@ -1,10 +1,18 @@
-use crate::graph::Edge;
+use crate::graph::{Cost, Edge};
+
+/// A weighted edge carrying `N` independent criteria (distance, toll, time...).
+///
+/// Weights are compared lexicographically and accumulated component-wise.
+pub type MultiWeightedEdge<const N: usize> = WeightedEdge<[f64; N]>;
 
 /// A weighted edge connecting two nodes.
 ///
 /// Represents a directional connection between nodes with a given weight.
 /// Suitable for algorithms like Dijkstra and A* when weight influences priority.
 ///
+/// The weight type defaults to `f64` and can be any `Cost`, such as integers to
+/// avoid floating point drift along long paths.
+///
 /// # Examples
 ///
 /// ```
@@ -15,20 +23,25 @@ use crate::graph::Edge;
 /// assert_eq!(edge.from(), 0);
 /// assert_eq!(edge.to(), 1);
 /// assert_eq!(edge.weight(), 5.0);
+///
+/// let exact = WeightedEdge::<u64>::new(0, 1, Some(5));
+/// assert_eq!(exact.weight(), 5);
 /// ```
 #[derive(Copy, Clone, Debug, Default)]
-pub struct WeightedEdge {
+pub struct WeightedEdge<C = f64> {
     to: u32,
     from: u32,
-    weight: f64,
+    weight: C,
 }
 
-impl Edge for WeightedEdge {
-    fn new(from: u32, to: u32, weight: Option<f64>) -> Self {
+impl<C: Cost> Edge for WeightedEdge<C> {
+    type Weight = C;
+
+    fn new(from: u32, to: u32, weight: Option<C>) -> Self {
         WeightedEdge {
             from,
             to,
-            weight: weight.unwrap_or(1.0),
+            weight: weight.unwrap_or(C::one()),
         }
     }
     fn to(&self) -> u32 {
@@ -37,7 +50,10 @@ impl Edge for WeightedEdge {
     fn from(&self) -> u32 {
         self.from
     }
-    fn weight(&self) -> f64 {
+    fn weight(&self) -> C {
         self.weight
     }
+    fn set_weight(&mut self, weight: C) {
+        self.weight = weight;
+    }
 }
@@ -4,7 +4,8 @@ pub mod policies;
 pub mod samplers;
 pub mod visitors;
 
+pub use edges::resource_edge::ResourceEdge;
 pub use edges::unweighted_edge::UnweightedEdge;
-pub use edges::weighted_edge::WeightedEdge;
+pub use edges::weighted_edge::{MultiWeightedEdge, WeightedEdge};
 pub use nodes::data_node::DataNode;
 pub use nodes::empty_node::EmptyNode;
@@ -59,6 +59,8 @@ mod tests {
     }
 
     impl Edge for MockEdge {
+        type Weight = f64;
+
         fn new(_from: u32, _to: u32, _weight: Option<f64>) -> Self {
             MockEdge {}
         }
@@ -0,0 +1,84 @@
+use crate::frontier::Queue;
+use crate::graph::{Edge, Graph, Node};
+use crate::policy::Policy;
+use crate::preset::policies::traversal::GoalReached;
+use crate::preset::visitors::{SimpleVisitor, TrackParent};
+
+/// Authorization policy that forbids edges closing a directed cycle.
+///
+/// An edge `from -> to` closes a cycle if `from` is already reachable from
+/// `to` in the graph being built, which is checked with a breadth-first
+/// traversal. Graphs built with this policy are directed acyclic graphs.
+///
+/// Each check costs a traversal of the graph, so this policy suits graphs
+/// built once, such as dependency or pipeline graphs.
+#[derive(Debug, Default)]
+pub struct DenyCycle {}
+
+impl<Entity, TNode, TEdge> Policy<Entity, Graph<TNode, TEdge>> for DenyCycle
+where
+    Entity: Edge,
+    TNode: Node,
+    TEdge: Edge,
+{
+    /// Allows an edge if its source cannot be reached from its destination.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The edge to allow
+    /// * `context` - Stateful graph being built
+    ///
+    /// # Returns
+    ///
+    /// `true` if adding the edge keeps the graph acyclic, `false` otherwise
+    fn is_compliant(&self, entity: &Entity, context: &Graph<TNode, TEdge>) -> bool {
+        if entity.from() == entity.to() {
+            return false;
+        }
+
+        let mut visitor = SimpleVisitor::new(GoalReached::new(entity.from()));
+        context.traverse(entity.to(), &mut Queue::new(), &mut visitor);
+
+        visitor.get_parent(entity.from()).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preset::{EmptyNode, UnweightedEdge};
+
+    fn graph(edges: &[(u32, u32)]) -> Graph<EmptyNode, UnweightedEdge> {
+        let mut graph = Graph::new();
+        for id in 0..4 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for &(from, to) in edges {
+            graph.add_edge(UnweightedEdge::new(from, to, None));
+        }
+        graph
+    }
+
+    #[test]
+    fn allows_edges_keeping_graph_acyclic() {
+        let graph = graph(&[(0, 1), (1, 2)]);
+
+        assert!(DenyCycle::default().is_compliant(&UnweightedEdge::new(0, 2, None), &graph));
+        assert!(DenyCycle::default().is_compliant(&UnweightedEdge::new(3, 0, None), &graph));
+    }
+
+    #[test]
+    fn denies_edges_closing_a_cycle() {
+        let graph = graph(&[(0, 1), (1, 2)]);
+
+        assert!(!DenyCycle::default().is_compliant(&UnweightedEdge::new(2, 0, None), &graph));
+        assert!(!DenyCycle::default().is_compliant(&UnweightedEdge::new(1, 0, None), &graph));
+    }
+
+    #[test]
+    fn denies_self_loops() {
+        let graph = graph(&[]);
+
+        assert!(!DenyCycle::default().is_compliant(&UnweightedEdge::new(3, 3, None), &graph));
+    }
+}
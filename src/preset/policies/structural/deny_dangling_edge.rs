@@ -43,6 +43,8 @@ mod tests {
     }
 
     impl Edge for MockEdge {
+        type Weight = f64;
+
         fn new(from: u32, to: u32, _weight: Option<f64>) -> Self {
             MockEdge { from, to }
         }
@@ -56,8 +56,10 @@ mod tests {
     }
 
     impl Edge for MockEdge {
+        type Weight = f64;
+
         fn new(from: u32, to: u32, _weight: Option<f64>) -> Self {
-            MockEdge { from: from, to: to }
+            MockEdge { from, to }
         }
         fn to(&self) -> u32 {
             self.to
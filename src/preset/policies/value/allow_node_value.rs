@@ -100,6 +100,8 @@ mod tests {
     pub struct MockEdge;
 
     impl Edge for MockEdge {
+        type Weight = f64;
+
         fn new(_from: u32, _to: u32, _weight: Option<f64>) -> Self {
             MockEdge
         }
@@ -1,28 +1,32 @@
-use crate::graph::{Edge, Graph, Node};
+use crate::graph::{Cost, Edge, Graph, Node};
 use crate::policy::Policy;
+use std::cmp::Ordering;
 
 /// Authorization policy that only allows edges with weight above a threshold.
 ///
 /// Useful for filtering out low-cost connections or focusing on high-priority
 /// paths in weighted graphs.
-pub struct AllowWeightAbove {
-    threshold: f64,
+///
+/// The threshold shares the `Cost` type of the filtered edge weights (`f64` by default).
+pub struct AllowWeightAbove<C = f64> {
+    threshold: C,
 }
 
-impl AllowWeightAbove {
+impl<C: Cost> AllowWeightAbove<C> {
     /// Creates a new policy with the specified threshold.
     ///
     /// # Arguments
     ///
     /// * `threshold` - Minimum weight (exclusive) for edges to be accepted
-    pub fn new(threshold: f64) -> Self {
+    pub fn new(threshold: C) -> Self {
         AllowWeightAbove { threshold }
     }
 }
 
-impl<Entity, TNode, TEdge> Policy<Entity, Graph<TNode, TEdge>> for AllowWeightAbove
+impl<Entity, TNode, TEdge, C> Policy<Entity, Graph<TNode, TEdge>> for AllowWeightAbove<C>
 where
-    Entity: Edge,
+    C: Cost,
+    Entity: Edge<Weight = C>,
     TNode: Node,
     TEdge: Edge,
 {
@@ -37,7 +41,7 @@ where
     ///
     /// `true` if `edge.weight() > threshold`, `false` otherwise
     fn is_compliant(&self, entity: &Entity, _context: &Graph<TNode, TEdge>) -> bool {
-        entity.weight() > self.threshold
+        entity.weight().compare(&self.threshold) == Ordering::Greater
     }
 }
 
@@ -66,6 +70,8 @@ mod tests {
     pub struct MockWeightedEdge;
 
     impl Edge for MockWeightedEdge {
+        type Weight = f64;
+
         fn new(_from: u32, _to: u32, _weight: Option<f64>) -> Self {
             MockWeightedEdge
         }
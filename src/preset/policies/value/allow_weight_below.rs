@@ -1,28 +1,32 @@
-use crate::graph::{Edge, Graph, Node};
+use crate::graph::{Cost, Edge, Graph, Node};
 use crate::policy::Policy;
+use std::cmp::Ordering;
 
 /// Authorization policy that only allows edges with weight below a threshold.
 ///
 /// Useful for filtering out expensive connections or focusing on low-cost
 /// paths in weighted graphs.
-pub struct AllowWeightBelow {
-    threshold: f64,
+///
+/// The threshold shares the `Cost` type of the filtered edge weights (`f64` by default).
+pub struct AllowWeightBelow<C = f64> {
+    threshold: C,
 }
 
-impl AllowWeightBelow {
+impl<C: Cost> AllowWeightBelow<C> {
     /// Creates a new policy with the specified threshold.
     ///
     /// # Arguments
     ///
     /// * `threshold` - Maximum weight (exclusive) for edges to be accepted
-    pub fn new(threshold: f64) -> Self {
+    pub fn new(threshold: C) -> Self {
         AllowWeightBelow { threshold }
     }
 }
 
-impl<Entity, TNode, TEdge> Policy<Entity, Graph<TNode, TEdge>> for AllowWeightBelow
+impl<Entity, TNode, TEdge, C> Policy<Entity, Graph<TNode, TEdge>> for AllowWeightBelow<C>
 where
-    Entity: Edge,
+    C: Cost,
+    Entity: Edge<Weight = C>,
     TNode: Node,
     TEdge: Edge,
 {
@@ -37,7 +41,7 @@ where
     ///
     /// `true` if `edge.weight() < threshold`, `false` otherwise
     fn is_compliant(&self, entity: &Entity, _context: &Graph<TNode, TEdge>) -> bool {
-        entity.weight() < self.threshold
+        entity.weight().compare(&self.threshold) == Ordering::Less
     }
 }
 
@@ -66,6 +70,8 @@ mod tests {
     pub struct MockWeightedEdge;
 
     impl Edge for MockWeightedEdge {
+        type Weight = f64;
+
         fn new(_from: u32, _to: u32, _weight: Option<f64>) -> Self {
             MockWeightedEdge
         }
@@ -0,0 +1,138 @@
+use crate::graph::{Cost, Edge, Graph, Node};
+use crate::policy::Policy;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+/// Error reported when a graph meant for Dijkstra has negative edge weights.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeWeights {
+    /// Source and target IDs of the offending edges, sorted
+    pub edges: Vec<(u32, u32)>,
+}
+
+impl fmt::Display for NegativeWeights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let edges: Vec<String> = self
+            .edges
+            .iter()
+            .map(|(from, to)| format!("{from} -> {to}"))
+            .collect();
+        write!(f, "negative edge weights: {}", edges.join(", "))
+    }
+}
+
+impl Error for NegativeWeights {}
+
+/// Authorization policy that rejects edges with a negative weight.
+///
+/// Guards graphs meant to be traversed with `WeightedVisitor` and a `MinHeap`
+/// (Dijkstra), which silently returns wrong distances on negative weights.
+/// Graphs with negative weights should use `BellmanFordVisitor` instead.
+///
+/// Used as a build policy, it drops the negative edges and the graph is
+/// built without them, so searches on it answer for a different graph. To
+/// reject the graph as a whole instead, build it without this policy and
+/// call `DenyNegativeWeight::check` before running Dijkstra.
+#[derive(Default)]
+pub struct DenyNegativeWeight {}
+
+impl DenyNegativeWeight {
+    /// Checks that no edge of a graph has a negative weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph about to be searched with Dijkstra
+    ///
+    /// # Errors
+    ///
+    /// Returns `NegativeWeights` listing every edge whose weight is strictly
+    /// below zero.
+    pub fn check<TNode, TEdge>(graph: &Graph<TNode, TEdge>) -> Result<(), NegativeWeights>
+    where
+        TNode: Node,
+        TEdge: Edge,
+    {
+        let mut edges: Vec<(u32, u32)> = graph
+            .edges
+            .values()
+            .flatten()
+            .filter(|edge| edge.weight().compare(&TEdge::Weight::zero()) == Ordering::Less)
+            .map(|edge| (edge.from(), edge.to()))
+            .collect();
+        if edges.is_empty() {
+            return Ok(());
+        }
+        edges.sort_unstable();
+        edges.dedup();
+        Err(NegativeWeights { edges })
+    }
+}
+
+impl<Entity, TNode, TEdge> Policy<Entity, Graph<TNode, TEdge>> for DenyNegativeWeight
+where
+    Entity: Edge,
+    TNode: Node,
+    TEdge: Edge,
+{
+    /// Denies an edge if its weight is strictly below zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The edge to check
+    /// * `_context` - Context (unused)
+    ///
+    /// # Returns
+    ///
+    /// `true` if `edge.weight() >= 0`, `false` otherwise
+    fn is_compliant(&self, entity: &Entity, _context: &Graph<TNode, TEdge>) -> bool {
+        entity.weight().compare(&Entity::Weight::zero()) != Ordering::Less
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    #[test]
+    fn allows_non_negative_weights() {
+        let graph = Graph::<EmptyNode, WeightedEdge>::new();
+        let policy = DenyNegativeWeight::default();
+
+        assert!(policy.is_compliant(&WeightedEdge::new(0, 1, Some(2.0)), &graph));
+        assert!(policy.is_compliant(&WeightedEdge::new(0, 1, Some(0.0)), &graph));
+    }
+
+    #[test]
+    fn denies_negative_weights() {
+        let graph = Graph::<EmptyNode, WeightedEdge<i64>>::new();
+        let policy = DenyNegativeWeight::default();
+
+        assert!(!policy.is_compliant(&WeightedEdge::new(0, 1, Some(-1i64)), &graph));
+    }
+
+    #[test]
+    fn check_lists_negative_edges() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        for id in 0..3 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        graph.add_edge(WeightedEdge::new(2, 0, Some(-0.5)));
+        graph.add_edge(WeightedEdge::new(0, 1, Some(1.0)));
+        graph.add_edge(WeightedEdge::new(1, 2, Some(-3.0)));
+
+        let error = DenyNegativeWeight::check(&graph).unwrap_err();
+        assert_eq!(error.edges, vec![(1, 2), (2, 0)]);
+        assert_eq!(error.to_string(), "negative edge weights: 1 -> 2, 2 -> 0");
+    }
+
+    #[test]
+    fn check_accepts_non_negative_graphs() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge<u64>>::new();
+        graph.add_node(EmptyNode::new(0, None));
+        graph.add_edge(WeightedEdge::new(0, 0, Some(0)));
+
+        assert_eq!(DenyNegativeWeight::check(&graph), Ok(()));
+    }
+}
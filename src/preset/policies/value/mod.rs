@@ -3,11 +3,15 @@ pub mod allow_node_value;
 pub mod allow_weight_above;
 pub mod allow_weight_below;
 pub mod deny_all;
+pub mod deny_negative_weight;
 pub mod deny_node_value;
+pub mod resource_limit;
 
 pub use allow_all::AllowAll;
 pub use allow_node_value::AllowNodeValue;
 pub use allow_weight_above::AllowWeightAbove;
 pub use allow_weight_below::AllowWeightBelow;
 pub use deny_all::DenyAll;
+pub use deny_negative_weight::{DenyNegativeWeight, NegativeWeights};
 pub use deny_node_value::DenyNodeValue;
+pub use resource_limit::ResourceLimit;
@@ -0,0 +1,77 @@
+use crate::policy::Policy;
+use crate::preset::visitors::ResourceLabel;
+
+/// Feasibility policy bounding the consumption of one resource.
+///
+/// Evaluated on the labels of a `ResourceConstrainedVisitor`. Several limits
+/// are combined with `Composite` to constrain several resources at once.
+pub struct ResourceLimit {
+    resource: usize,
+    max: f64,
+}
+
+impl ResourceLimit {
+    /// Creates a new limit on the given resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - Index of the constrained resource
+    /// * `max` - Maximum (inclusive) cumulated consumption
+    pub fn new(resource: usize, max: f64) -> Self {
+        ResourceLimit { resource, max }
+    }
+}
+
+impl<const R: usize, Ctx> Policy<ResourceLabel<R>, Ctx> for ResourceLimit {
+    /// Allows a label if its consumption of the resource does not exceed the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The label to allow
+    /// * `_context` - Context (unused)
+    ///
+    /// # Returns
+    ///
+    /// `true` if `label.resources[resource] <= max`, `false` otherwise
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource index is out of the label's bounds.
+    fn is_compliant(&self, entity: &ResourceLabel<R>, _context: &Ctx) -> bool {
+        entity.resources[self.resource] <= self.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(resources: [f64; 2]) -> ResourceLabel<2> {
+        ResourceLabel {
+            node: 0,
+            cost: 0.0,
+            resources,
+        }
+    }
+
+    #[test]
+    fn allows_consumption_up_to_limit() {
+        let policy = ResourceLimit::new(1, 3.0);
+
+        assert!(policy.is_compliant(&label([9.0, 2.0]), &()));
+        assert!(policy.is_compliant(&label([9.0, 3.0]), &()));
+    }
+
+    #[test]
+    fn rejects_consumption_above_limit() {
+        let policy = ResourceLimit::new(0, 3.0);
+
+        assert!(!policy.is_compliant(&label([3.5, 0.0]), &()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_unknown_resource() {
+        ResourceLimit::new(2, 1.0).is_compliant(&label([0.0, 0.0]), &());
+    }
+}
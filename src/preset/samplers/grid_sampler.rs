@@ -214,13 +214,13 @@ mod tests {
         let mut sampler = Grid2DSampler::<char>::default();
         let context = test_context();
 
-        for i in 0..expected.len() {
+        for (i, targets) in expected.iter().enumerate() {
             let (_, edges) = sampler.next(&context).unwrap();
-            assert_eq!(edges.len(), expected[i].len());
+            assert_eq!(edges.len(), targets.len());
 
-            for j in 0..expected[i].len() {
-                assert_eq!(edges[j].from(), i as u32);
-                assert_eq!(edges[j].to(), expected[i][j]);
+            for (edge, &target) in edges.iter().zip(targets) {
+                assert_eq!(edge.from(), i as u32);
+                assert_eq!(edge.to(), target);
             }
         }
     }
@@ -242,13 +242,13 @@ mod tests {
         let mut sampler = Grid2DSampler::<char>::with_connect_eight();
         let context = test_context();
 
-        for i in 0..expected.len() {
+        for (i, targets) in expected.iter().enumerate() {
             let (_, edges) = sampler.next(&context).unwrap();
-            assert_eq!(edges.len(), expected[i].len());
+            assert_eq!(edges.len(), targets.len());
 
-            for j in 0..expected[i].len() {
-                assert_eq!(edges[j].from(), i as u32);
-                assert_eq!(edges[j].to(), expected[i][j]);
+            for (edge, &target) in edges.iter().zip(targets) {
+                assert_eq!(edge.from(), i as u32);
+                assert_eq!(edge.to(), target);
             }
         }
     }
@@ -0,0 +1,405 @@
+use crate::graph::{Cost, Edge, EdgeContext};
+use crate::policy::Policy;
+use crate::strategy::Visitor;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+
+use super::{CountVisited, TrackCost, TrackParent};
+
+/// Error reported when a negative-weight cycle is reachable from the start node.
+///
+/// Shortest distances are undefined in that case, as every lap around the
+/// cycle lowers the cost of the paths going through it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle {
+    /// Node IDs of the cycle in traversal order, without repeating the first node
+    pub cycle: Vec<u32>,
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: Vec<String> = self.cycle.iter().map(u32::to_string).collect();
+        write!(f, "negative cycle detected: {}", nodes.join(" -> "))
+    }
+}
+
+impl Error for NegativeCycle {}
+
+/// Visitor for shortest paths with negative edge weights (Bellman-Ford).
+///
+/// Relaxes edges like `WeightedVisitor`, but lets a node be expanded again
+/// whenever its distance improves, which is required for negative weights to
+/// propagate. A node already waiting in the frontier is not pushed twice.
+///
+/// Each node also tracks the number of edges of its current shortest path.
+/// A path with as many edges as there are nodes reached so far must contain
+/// a cycle, and since it keeps improving, that cycle has a negative weight:
+/// the traversal is then halted and the cycle reported as a `NegativeCycle`.
+/// Only reached node IDs are counted, so edges to nodes missing from
+/// `graph.nodes` are handled like any other.
+///
+/// # Usage
+///
+/// Pair this visitor with a `Queue` frontier to implement the SPFA variant
+/// of Bellman-Ford. Distances are only final once the frontier is exhausted,
+/// so a non-terminating policy such as `NoTermination` is usually used, and
+/// `result()` should be checked before reading them.
+#[derive(Debug, Default)]
+pub struct BellmanFordVisitor<P, C = f64> {
+    distances: HashMap<u32, C>,
+    parents: HashMap<u32, Option<u32>>,
+    /// Number of edges of the current shortest path to each node
+    path_lengths: HashMap<u32, usize>,
+    /// Nodes currently waiting in the frontier
+    queued: HashSet<u32>,
+    negative_cycle: Option<NegativeCycle>,
+    terminate: P,
+}
+
+impl<P, C> BellmanFordVisitor<P, C>
+where
+    P: Policy<u32, Self>,
+{
+    pub fn new(terminate: P) -> Self {
+        BellmanFordVisitor {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            path_lengths: HashMap::new(),
+            queued: HashSet::new(),
+            negative_cycle: None,
+            terminate,
+        }
+    }
+}
+
+impl<P, C> BellmanFordVisitor<P, C> {
+    /// Returns the negative cycle found during traversal, if any.
+    pub fn negative_cycle(&self) -> Option<&NegativeCycle> {
+        self.negative_cycle.as_ref()
+    }
+
+    /// Returns `Ok` if the computed distances are valid shortest distances.
+    ///
+    /// # Errors
+    ///
+    /// Returns the detected `NegativeCycle` if one is reachable from the start node.
+    pub fn result(&self) -> Result<(), NegativeCycle> {
+        match &self.negative_cycle {
+            Some(cycle) => Err(cycle.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Extracts the cycle from the parent chain of a node whose path is too long.
+    fn find_cycle(&self, node_id: u32, node_count: usize) -> NegativeCycle {
+        // Walking back as many steps as there are nodes lands inside the cycle
+        let mut current = node_id;
+        for _ in 0..node_count {
+            match self.get_parent(current) {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        let start = current;
+        let mut cycle = vec![start];
+        while let Some(parent) = self.get_parent(current) {
+            if parent == start {
+                break;
+            }
+            cycle.push(parent);
+            current = parent;
+        }
+        cycle.reverse();
+        NegativeCycle { cycle }
+    }
+}
+
+impl<P, C> CountVisited for BellmanFordVisitor<P, C> {
+    fn visited_count(&self) -> usize {
+        self.distances.len()
+    }
+}
+
+impl<P, C: Cost> TrackCost<C> for BellmanFordVisitor<P, C> {
+    fn cost_to(&self, node_id: u32) -> Option<C> {
+        self.distances.get(&node_id).copied()
+    }
+}
+
+impl<P, C> TrackParent for BellmanFordVisitor<P, C> {
+    fn get_parent(&self, node_id: u32) -> Option<u32> {
+        self.parents.get(&node_id).copied().flatten()
+    }
+}
+
+impl<Ctx, P, C> Visitor<Ctx, C> for BellmanFordVisitor<P, C>
+where
+    Ctx: EdgeContext,
+    Ctx::Edge: Edge<Weight = C>,
+    C: Cost,
+    P: Policy<u32, Self>,
+{
+    /// Computes the cumulative cost to reach a target node through its cheapest edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    /// * `context` - The graph being traversed
+    fn exploration_cost(&self, from: u32, to: u32, context: &Ctx) -> C {
+        let from_dist = self.distances.get(&from).copied().unwrap_or(C::zero());
+
+        let edge_weight = context.with_edges_from(from, |edges| {
+            edges
+                .iter()
+                .filter(|e| e.to() == to)
+                .map(|e| e.weight())
+                .min_by(|a, b| a.compare(b))
+                .unwrap_or(C::zero())
+        });
+
+        from_dist.plus(edge_weight)
+    }
+
+    /// Relaxes the connection between two nodes.
+    ///
+    /// The target node's distance is updated whenever the connection improves
+    /// it, but the target is only pushed if it is not already in the frontier.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    /// * `context` - The graph being traversed
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target node must be pushed, `false` otherwise
+    fn should_explore(&mut self, from: u32, to: u32, context: &Ctx) -> bool {
+        if self.negative_cycle.is_some() {
+            return false;
+        }
+
+        // The source node has been popped: its improvements must be pushed again
+        self.queued.remove(&from);
+        self.distances.entry(from).or_insert(C::zero());
+        self.parents.entry(from).or_insert(None);
+
+        let new_dist = self.exploration_cost(from, to, context);
+        let improves = self
+            .distances
+            .get(&to)
+            .is_none_or(|current| new_dist.compare(current) == Ordering::Less);
+        if !improves {
+            return false;
+        }
+
+        let length = self.path_lengths.get(&from).copied().unwrap_or(0) + 1;
+        self.distances.insert(to, new_dist);
+        self.parents.insert(to, Some(from));
+        self.path_lengths.insert(to, length);
+
+        let reached = self.distances.len();
+        if length >= reached {
+            self.negative_cycle = Some(self.find_cycle(to, reached));
+            return false;
+        }
+
+        self.queued.insert(to)
+    }
+
+    /// Marks a node as visited.
+    ///
+    /// Initializes the distance of the start node to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The ID of the node being visited
+    /// * `_context` - The graph being traversed (unused)
+    fn visit(&mut self, node_id: u32, _context: &Ctx) {
+        self.queued.remove(&node_id);
+        self.distances.entry(node_id).or_insert(C::zero());
+        self.parents.entry(node_id).or_insert(None);
+    }
+
+    /// Stops on the termination policy or as soon as a negative cycle is found.
+    fn should_stop(&self, node_id: u32, _context: &Ctx) -> bool {
+        self.negative_cycle.is_some() || self.terminate.is_compliant(&node_id, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::Queue;
+    use crate::graph::{Graph, ImplicitGraph, Node};
+    use crate::preset::policies::traversal::NoTermination;
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    fn graph<C: Cost>(
+        node_count: u32,
+        edges: &[(u32, u32, C)],
+    ) -> Graph<EmptyNode, WeightedEdge<C>> {
+        let mut graph = Graph::new();
+        for id in 0..node_count {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for &(from, to, weight) in edges {
+            graph.add_edge(WeightedEdge::new(from, to, Some(weight)));
+        }
+        graph
+    }
+
+    #[test]
+    fn handles_negative_weights() {
+        // Dijkstra would settle 2 through the direct edge before seeing 0 -> 1 -> 2
+        let graph = graph(3, &[(0, 2, 2.0), (0, 1, 5.0), (1, 2, -4.0)]);
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert_eq!(visitor.result(), Ok(()));
+        assert_eq!(visitor.cost_to(2), Some(1.0));
+        assert_eq!(visitor.get_parent(2), Some(1));
+        assert_eq!(visitor.get_parent(0), None);
+    }
+
+    #[test]
+    fn propagates_improvements_to_descendants() {
+        let graph = graph(4, &[(0, 1, 1i64), (1, 3, 1), (0, 2, 4), (2, 1, -5)]);
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::default(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(1), Some(-1));
+        assert_eq!(visitor.cost_to(3), Some(0));
+        assert_eq!(visitor.get_parent(1), Some(2));
+    }
+
+    #[test]
+    fn uses_cheapest_parallel_edge() {
+        let graph = graph(2, &[(0, 1, 3.0), (0, 1, -1.0)]);
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(1), Some(-1.0));
+    }
+
+    #[test]
+    fn reports_negative_cycle() {
+        let graph = graph(
+            5,
+            &[
+                (0, 1, 1.0),
+                (1, 2, 1.0),
+                (2, 3, -2.0),
+                (3, 1, 0.5),
+                (3, 4, 1.0),
+            ],
+        );
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        let error = visitor.result().unwrap_err();
+        let mut cycle = error.cycle.clone();
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2, 3]);
+        assert_eq!(visitor.negative_cycle(), Some(&error));
+    }
+
+    #[test]
+    fn reports_negative_self_loop() {
+        let graph = graph(2, &[(0, 1, 1.0), (1, 1, -1.0)]);
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert_eq!(visitor.result().unwrap_err().cycle, vec![1]);
+    }
+
+    #[test]
+    fn ignores_unreachable_negative_cycle() {
+        let graph = graph(4, &[(0, 1, 1.0), (2, 3, -1.0), (3, 2, -1.0)]);
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert!(visitor.result().is_ok());
+        assert_eq!(visitor.visited_count(), 2);
+    }
+
+    #[test]
+    fn counts_nodes_missing_from_the_graph() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        graph.add_edge(WeightedEdge::new(0, 1, Some(1.0)));
+        graph.add_edge(WeightedEdge::new(1, 2, Some(-1.0)));
+        graph.add_edge(WeightedEdge::new(0, 2, Some(3.0)));
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert_eq!(visitor.result(), Ok(()));
+        assert_eq!(visitor.cost_to(2), Some(0.0));
+    }
+
+    #[test]
+    fn reports_negative_cycle_between_nodes_missing_from_the_graph() {
+        let mut graph = Graph::<EmptyNode, WeightedEdge>::new();
+        graph.add_edge(WeightedEdge::new(0, 1, Some(1.0)));
+        graph.add_edge(WeightedEdge::new(1, 2, Some(-2.0)));
+        graph.add_edge(WeightedEdge::new(2, 1, Some(1.0)));
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        let mut cycle = visitor.result().unwrap_err().cycle;
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2]);
+    }
+
+    #[test]
+    fn runs_on_implicit_graphs() {
+        // Steps of one cost 1, jumps of two cost -1, up to node 4
+        let graph = ImplicitGraph::new(|n: u32| {
+            [(n + 1, 1.0), (n + 2, -1.0)]
+                .into_iter()
+                .filter(|&(to, _)| to <= 4)
+                .map(|(to, weight)| WeightedEdge::new(n, to, Some(weight)))
+                .collect()
+        });
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert_eq!(visitor.result(), Ok(()));
+        assert_eq!(visitor.cost_to(4), Some(-2.0));
+        assert_eq!(visitor.get_parent(4), Some(2));
+    }
+
+    #[test]
+    fn reports_negative_cycle_on_implicit_graphs() {
+        let graph =
+            ImplicitGraph::new(|n: u32| vec![WeightedEdge::new(n, (n + 1) % 3, Some(-1.0))]);
+        let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::new(), &mut visitor);
+
+        let mut cycle = visitor.result().unwrap_err().cycle;
+        cycle.sort();
+        assert_eq!(cycle, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn negative_cycle_displays_its_nodes() {
+        let error = NegativeCycle {
+            cycle: vec![1, 2, 3],
+        };
+
+        assert_eq!(error.to_string(), "negative cycle detected: 1 -> 2 -> 3");
+    }
+}
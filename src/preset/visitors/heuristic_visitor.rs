@@ -0,0 +1,168 @@
+use crate::graph::Cost;
+use crate::strategy::{Heuristic, Visitor};
+
+use super::{CountVisited, TrackCost, TrackParent};
+
+/// Visitor wrapper adding a goal estimate to another visitor's priorities.
+///
+/// The frontier receives the wrapped visitor's cost plus the heuristic
+/// estimate of the explored node, while exploration decisions, visits and
+/// termination are delegated unchanged. Wrapping a `WeightedVisitor` turns
+/// Dijkstra's algorithm into A*.
+///
+/// # Typical Use Cases
+///
+/// - A* on grids with geometric distances
+/// - Goal-directed search on any weighted graph with landmark lower bounds
+///
+/// # Usage
+///
+/// Pair with a `MinHeap` frontier and a wrapped visitor stopping at the
+/// goal. Paths are optimal when the heuristic never overestimates and is
+/// consistent: `h(a) <= w(a, b) + h(b)` for every edge.
+#[derive(Debug, Default)]
+pub struct HeuristicVisitor<V, H> {
+    inner: V,
+    heuristic: H,
+}
+
+impl<V, H> HeuristicVisitor<V, H> {
+    pub fn new(inner: V, heuristic: H) -> Self {
+        HeuristicVisitor { inner, heuristic }
+    }
+
+    /// Returns the wrapped visitor.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Consumes the wrapper and returns the wrapped visitor.
+    pub fn into_inner(self) -> V {
+        self.inner
+    }
+}
+
+impl<V: CountVisited, H> CountVisited for HeuristicVisitor<V, H> {
+    fn visited_count(&self) -> usize {
+        self.inner.visited_count()
+    }
+}
+
+impl<V: TrackCost<C>, H, C: Cost> TrackCost<C> for HeuristicVisitor<V, H> {
+    fn cost_to(&self, node_id: u32) -> Option<C> {
+        self.inner.cost_to(node_id)
+    }
+}
+
+impl<V: TrackParent, H> TrackParent for HeuristicVisitor<V, H> {
+    fn get_parent(&self, node_id: u32) -> Option<u32> {
+        self.inner.get_parent(node_id)
+    }
+}
+
+impl<Ctx, C, V, H> Visitor<Ctx, C> for HeuristicVisitor<V, H>
+where
+    C: Cost,
+    V: Visitor<Ctx, C>,
+    H: Heuristic<C>,
+{
+    fn init_cost(&self, node_id: u32, context: &Ctx) -> C {
+        self.inner
+            .init_cost(node_id, context)
+            .plus(self.heuristic.estimate(node_id))
+    }
+
+    /// Adds the estimate from the target node to the wrapped visitor's cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    /// * `context` - Traversal context
+    ///
+    /// # Returns
+    ///
+    /// The priority of `to` in the frontier
+    fn exploration_cost(&self, from: u32, to: u32, context: &Ctx) -> C {
+        self.inner
+            .exploration_cost(from, to, context)
+            .plus(self.heuristic.estimate(to))
+    }
+
+    fn should_explore(&mut self, from: u32, to: u32, context: &Ctx) -> bool {
+        self.inner.should_explore(from, to, context)
+    }
+
+    fn visit(&mut self, node_id: u32, context: &Ctx) {
+        self.inner.visit(node_id, context);
+    }
+
+    fn should_stop(&self, node_id: u32, context: &Ctx) -> bool {
+        self.inner.should_stop(node_id, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::{Frontier, Instrumented, MinHeap};
+    use crate::graph::{Edge, Graph, Node};
+    use crate::preset::policies::traversal::GoalReached;
+    use crate::preset::visitors::WeightedVisitor;
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    /// Row of nodes 0..=4 with a costly direct shortcut from 0 to 4.
+    fn graph() -> Graph<EmptyNode, WeightedEdge> {
+        let mut graph = Graph::new();
+        for id in 0..6 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for id in 0..4 {
+            graph.add_edge(WeightedEdge::new(id, id + 1, Some(1.0)));
+        }
+        graph.add_edge(WeightedEdge::new(0, 4, Some(5.0)));
+        // Dead end away from the goal
+        graph.add_edge(WeightedEdge::new(0, 5, Some(0.5)));
+        graph
+    }
+
+    #[test]
+    fn adds_estimate_to_priorities() {
+        let graph = graph();
+        let mut visitor =
+            HeuristicVisitor::new(WeightedVisitor::new(GoalReached::new(4)), |id: u32| {
+                4.0 - id.min(4) as f64
+            });
+        visitor.visit(0, &graph);
+
+        assert_eq!(visitor.init_cost(0, &graph), 4.0);
+        assert!(visitor.should_explore(0, 1, &graph));
+        assert_eq!(visitor.exploration_cost(0, 1, &graph), 4.0);
+    }
+
+    #[test]
+    fn finds_shortest_path_popping_fewer_nodes() {
+        let graph = graph();
+        let mut dijkstra = WeightedVisitor::new(GoalReached::new(4));
+        let mut uninformed = Instrumented::<MinHeap>::new();
+        graph.traverse(0, &mut uninformed, &mut dijkstra);
+
+        // Node 5 cannot reach the goal
+        let estimate = |id: u32| {
+            if id == 5 {
+                f64::INFINITY
+            } else {
+                4.0 - id as f64
+            }
+        };
+        let mut astar = HeuristicVisitor::new(WeightedVisitor::new(GoalReached::new(4)), estimate);
+        let mut informed = Instrumented::<MinHeap>::new();
+        graph.traverse(0, &mut informed, &mut astar);
+
+        assert_eq!(astar.cost_to(4), Some(4.0));
+        assert_eq!(astar.get_parent(4), Some(3));
+        assert_eq!(astar.into_inner().cost_to(4), dijkstra.cost_to(4));
+        assert_eq!(informed.stats().pops, 5);
+        assert_eq!(uninformed.stats().pops, 6);
+    }
+}
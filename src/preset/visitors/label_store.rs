@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+struct Entry<L> {
+    node: u32,
+    label: L,
+    parent: Option<usize>,
+    expanded: bool,
+}
+
+/// Labels of a label-correcting search, shared by the multi-label visitors.
+///
+/// Each node keeps a set of non-dominated labels, one per distinct partial
+/// path. Discarded labels stay in the store, so the parent links of the
+/// labels extending them remain valid.
+///
+/// The dominance test `covers(a, b)` returns `true` when `a` is at least as
+/// good as `b`: a new label covered by an existing one is rejected, and
+/// existing labels covered by a new one are discarded.
+#[derive(Debug)]
+pub(crate) struct LabelStore<L, K> {
+    entries: Vec<Entry<L>>,
+    /// Maps node IDs to the indices of their non-dominated labels
+    node_labels: HashMap<u32, Vec<usize>>,
+    /// Labels of the node currently being expanded
+    expanding: Option<(u32, Vec<usize>)>,
+    /// Best new label key of each node, used as frontier priority
+    pending: HashMap<u32, K>,
+    covers: fn(&L, &L) -> bool,
+}
+
+impl<L, K> LabelStore<L, K> {
+    /// Creates an empty store.
+    ///
+    /// # Arguments
+    ///
+    /// * `covers` - Returns `true` if the first label is at least as good as the second
+    pub(crate) fn new(covers: fn(&L, &L) -> bool) -> Self {
+        LabelStore {
+            entries: Vec::new(),
+            node_labels: HashMap::new(),
+            expanding: None,
+            pending: HashMap::new(),
+            covers,
+        }
+    }
+
+    /// Returns a stored label.
+    pub(crate) fn label(&self, index: usize) -> &L {
+        &self.entries[index].label
+    }
+
+    /// Returns the indices of the non-dominated labels of a node.
+    pub(crate) fn indices(&self, node_id: u32) -> impl Iterator<Item = usize> + '_ {
+        self.node_labels
+            .get(&node_id)
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Returns the number of nodes holding labels.
+    pub(crate) fn node_count(&self) -> usize {
+        self.node_labels.len()
+    }
+
+    /// Returns the number of non-dominated labels.
+    pub(crate) fn label_count(&self) -> usize {
+        self.node_labels.values().map(Vec::len).sum()
+    }
+
+    /// Rebuilds the nodes of the partial path ending with a label.
+    pub(crate) fn path(&self, mut index: usize) -> Vec<u32> {
+        let mut nodes = vec![self.entries[index].node];
+        while let Some(parent) = self.entries[index].parent {
+            nodes.push(self.entries[parent].node);
+            index = parent;
+        }
+        nodes.reverse();
+        nodes
+    }
+
+    /// Returns the frontier priority of a node's newest labels.
+    pub(crate) fn pending(&self, node_id: u32) -> Option<K>
+    where
+        K: Copy,
+    {
+        self.pending.get(&node_id).copied()
+    }
+
+    /// Records the frontier priority of a node's newest labels.
+    pub(crate) fn set_pending(&mut self, node_id: u32, key: K) {
+        self.pending.insert(node_id, key);
+    }
+
+    /// Creates the origin label if the traversal has not produced any label yet.
+    pub(crate) fn ensure_origin(&mut self, node_id: u32, origin: impl FnOnce() -> L) {
+        if self.entries.is_empty() {
+            self.insert(node_id, origin(), None);
+        }
+    }
+
+    /// Returns the labels of `node_id` that still need to be propagated,
+    /// marking them as expanded on first request.
+    pub(crate) fn expanding_labels(&mut self, node_id: u32) -> Vec<usize> {
+        if let Some((current, indices)) = &self.expanding
+            && *current == node_id
+        {
+            return indices.clone();
+        }
+
+        let indices: Vec<usize> = self
+            .indices(node_id)
+            .filter(|&index| !self.entries[index].expanded)
+            .collect();
+
+        for &index in &indices {
+            self.entries[index].expanded = true;
+        }
+        self.expanding = Some((node_id, indices.clone()));
+        indices
+    }
+
+    /// Marks the labels of a node as expanded and ends its expansion.
+    pub(crate) fn finish_expansion(&mut self, node_id: u32) {
+        self.expanding_labels(node_id);
+        self.expanding = None;
+    }
+
+    /// Inserts a label unless it is covered, discarding the labels it covers.
+    ///
+    /// Returns `true` if the label was inserted.
+    pub(crate) fn insert(&mut self, node_id: u32, label: L, parent: Option<usize>) -> bool {
+        let existing = self.node_labels.entry(node_id).or_default();
+        let entries = &self.entries;
+        let covers = self.covers;
+
+        if existing
+            .iter()
+            .any(|&index| covers(&entries[index].label, &label))
+        {
+            return false;
+        }
+        existing.retain(|&index| !covers(&label, &entries[index].label));
+
+        existing.push(entries.len());
+        self.entries.push(Entry {
+            node: node_id,
+            label,
+            parent,
+            expanded: false,
+        });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn covers(a: &[u32; 2], b: &[u32; 2]) -> bool {
+        a[0] <= b[0] && a[1] <= b[1]
+    }
+
+    #[test]
+    fn rejects_covered_labels_and_discards_the_ones_a_label_covers() {
+        let mut store = LabelStore::<[u32; 2], u32>::new(covers);
+
+        assert!(store.insert(0, [2, 2], None));
+        assert!(!store.insert(0, [2, 2], None));
+        assert!(!store.insert(0, [3, 2], None));
+        assert!(store.insert(0, [1, 3], None));
+        assert!(store.insert(0, [1, 1], None));
+
+        let labels: Vec<[u32; 2]> = store.indices(0).map(|i| *store.label(i)).collect();
+        assert_eq!(labels, vec![[1, 1]]);
+        assert_eq!(store.label_count(), 1);
+    }
+
+    #[test]
+    fn expands_each_label_once() {
+        let mut store = LabelStore::<[u32; 2], u32>::new(covers);
+        store.ensure_origin(0, || [0, 0]);
+
+        assert_eq!(store.expanding_labels(0), vec![0]);
+        assert_eq!(store.expanding_labels(0), vec![0]);
+        store.finish_expansion(0);
+        assert!(store.expanding_labels(0).is_empty());
+    }
+
+    #[test]
+    fn rebuilds_paths_through_parents() {
+        let mut store = LabelStore::<[u32; 2], u32>::new(covers);
+        store.ensure_origin(0, || [0, 0]);
+        store.insert(1, [1, 0], Some(0));
+        store.insert(2, [2, 0], Some(1));
+
+        assert_eq!(store.path(2), vec![0, 1, 2]);
+        assert_eq!(store.node_count(), 3);
+    }
+}
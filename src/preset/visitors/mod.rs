@@ -1,9 +1,22 @@
+pub mod bellman_ford_visitor;
 pub mod count_visited;
+pub mod heuristic_visitor;
+mod label_store;
+pub mod pareto_visitor;
+pub mod resource_visitor;
+pub mod restricted_visitor;
 pub mod simple_visitor;
+pub mod track_cost;
 pub mod track_parent;
 pub mod weighted_visitor;
 
+pub use bellman_ford_visitor::{BellmanFordVisitor, NegativeCycle};
 pub use count_visited::CountVisited;
+pub use heuristic_visitor::HeuristicVisitor;
+pub use pareto_visitor::{ParetoPath, ParetoVisitor};
+pub use resource_visitor::{ResourceConstrainedVisitor, ResourceLabel};
+pub use restricted_visitor::RestrictedVisitor;
 pub use simple_visitor::SimpleVisitor;
+pub use track_cost::TrackCost;
 pub use track_parent::TrackParent;
 pub use weighted_visitor::WeightedVisitor;
@@ -0,0 +1,335 @@
+use super::CountVisited;
+use super::label_store::LabelStore;
+use crate::graph::{Cost, Edge, EdgeContext};
+use crate::policy::Policy;
+use crate::strategy::Visitor;
+use std::cmp::Ordering;
+
+/// Returns `true` if cost `a` Pareto-dominates cost `b`.
+///
+/// `a` dominates `b` when it is no worse on every criterion and strictly
+/// better on at least one.
+///
+/// # Examples
+///
+/// ```
+/// use hodos::preset::visitors::pareto_visitor::dominates;
+///
+/// assert!(dominates(&[1.0, 2.0], &[1.0, 3.0]));
+/// assert!(!dominates(&[1.0, 4.0], &[2.0, 3.0]));
+/// assert!(!dominates(&[1.0, 2.0], &[1.0, 2.0]));
+/// ```
+pub fn dominates<T: Cost, const N: usize>(a: &[T; N], b: &[T; N]) -> bool {
+    let mut strictly_better = false;
+    for (x, y) in a.iter().zip(b) {
+        match x.compare(y) {
+            Ordering::Greater => return false,
+            Ordering::Less => strictly_better = true,
+            Ordering::Equal => {}
+        }
+    }
+    strictly_better
+}
+
+/// A Pareto-optimal path and its multi-criteria cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParetoPath<T, const N: usize> {
+    /// Node IDs from the start node to the target node
+    pub nodes: Vec<u32>,
+    /// Cumulated cost of the path on every criterion
+    pub cost: [T; N],
+}
+
+/// Returns `true` if cost `a` is no worse than cost `b` on every criterion.
+fn covers<T: Cost, const N: usize>(a: &[T; N], b: &[T; N]) -> bool {
+    a.iter()
+        .zip(b)
+        .all(|(x, y)| x.compare(y) != Ordering::Greater)
+}
+
+/// Visitor for multi-criteria shortest paths.
+///
+/// Instead of a single distance per node, this visitor keeps a Pareto set of
+/// non-dominated labels per node, each label being the cost vector of a
+/// distinct path. Labels are propagated along edges carrying one weight per
+/// criterion (see `MultiWeightedEdge`) and pruned by dominance.
+///
+/// The search is label-correcting: a node is pushed back to the frontier
+/// whenever it receives new labels, and only the labels not yet propagated
+/// are expanded when it is popped again.
+///
+/// # Usage
+///
+/// Pair this visitor with a `MinHeap<[T; N]>` frontier, which orders nodes by
+/// their lexicographically smallest new label. The full Pareto front is only
+/// guaranteed once the frontier is exhausted, so a non-terminating policy such
+/// as `NoTermination` is usually used.
+#[derive(Debug)]
+pub struct ParetoVisitor<P, T, const N: usize> {
+    /// Cost of each label, the best new one of a node being its priority
+    labels: LabelStore<[T; N], [T; N]>,
+    terminate: P,
+}
+
+impl<P, T, const N: usize> ParetoVisitor<P, T, N>
+where
+    P: Policy<u32, Self>,
+    T: Cost,
+{
+    pub fn new(terminate: P) -> Self {
+        ParetoVisitor {
+            labels: LabelStore::new(covers),
+            terminate,
+        }
+    }
+}
+
+impl<P, T: Cost, const N: usize> ParetoVisitor<P, T, N> {
+    /// Returns the Pareto-optimal costs found to reach a node, sorted lexicographically.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The target node ID
+    pub fn pareto_costs(&self, node_id: u32) -> Vec<[T; N]> {
+        self.pareto_paths(node_id)
+            .into_iter()
+            .map(|path| path.cost)
+            .collect()
+    }
+
+    /// Enumerates every Pareto-optimal path found to reach a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The target node ID
+    ///
+    /// # Returns
+    ///
+    /// The non-dominated paths sorted lexicographically by cost, empty if the
+    /// node was never reached.
+    pub fn pareto_paths(&self, node_id: u32) -> Vec<ParetoPath<T, N>> {
+        let mut paths: Vec<ParetoPath<T, N>> = self
+            .labels
+            .indices(node_id)
+            .map(|index| ParetoPath {
+                nodes: self.labels.path(index),
+                cost: *self.labels.label(index),
+            })
+            .collect();
+
+        paths.sort_by(|a, b| a.cost.compare(&b.cost));
+        paths
+    }
+
+    /// Returns the total number of non-dominated labels currently stored.
+    pub fn label_count(&self) -> usize {
+        self.labels.label_count()
+    }
+}
+
+impl<P, T, const N: usize> CountVisited for ParetoVisitor<P, T, N> {
+    fn visited_count(&self) -> usize {
+        self.labels.node_count()
+    }
+}
+
+impl<Ctx, P, T, const N: usize> Visitor<Ctx, [T; N]> for ParetoVisitor<P, T, N>
+where
+    Ctx: EdgeContext,
+    Ctx::Edge: Edge<Weight = [T; N]>,
+    T: Cost,
+    P: Policy<u32, Self>,
+{
+    /// Returns the lexicographically smallest label newly added to the target node.
+    ///
+    /// # Arguments
+    ///
+    /// * `_from` - Source node ID (unused)
+    /// * `to` - Target node ID
+    /// * `_context` - The graph being traversed (unused)
+    fn exploration_cost(&self, _from: u32, to: u32, _context: &Ctx) -> [T; N] {
+        self.labels.pending(to).unwrap_or(<[T; N]>::zero())
+    }
+
+    /// Extends the unexpanded labels of the source node along every edge to the target.
+    ///
+    /// Parallel edges are all considered on the first call, as each may offer
+    /// a different trade-off between criteria.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    /// * `context` - The graph being traversed
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one non-dominated label reached the target node
+    fn should_explore(&mut self, from: u32, to: u32, context: &Ctx) -> bool {
+        self.labels.ensure_origin(from, <[T; N]>::zero);
+
+        let sources = self.labels.expanding_labels(from);
+        let weights: Vec<[T; N]> = context.with_edges_from(from, |edges| {
+            edges
+                .iter()
+                .filter(|e| e.to() == to)
+                .map(|e| e.weight())
+                .collect()
+        });
+
+        let mut best: Option<[T; N]> = None;
+        for &source in &sources {
+            for weight in &weights {
+                let cost = self.labels.label(source).plus(*weight);
+                if self.labels.insert(to, cost, Some(source))
+                    && best.is_none_or(|b| cost.compare(&b) == Ordering::Less)
+                {
+                    best = Some(cost);
+                }
+            }
+        }
+
+        match best {
+            Some(cost) => {
+                self.labels.set_pending(to, cost);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Marks the labels of a node as expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The ID of the node being visited
+    /// * `_context` - The graph being traversed (unused)
+    fn visit(&mut self, node_id: u32, _context: &Ctx) {
+        self.labels.ensure_origin(node_id, <[T; N]>::zero);
+        self.labels.finish_expansion(node_id);
+    }
+
+    fn should_stop(&self, node_id: u32, _context: &Ctx) -> bool {
+        self.terminate.is_compliant(&node_id, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::{MinHeap, Queue};
+    use crate::graph::{Graph, Node};
+    use crate::preset::policies::traversal::NoTermination;
+    use crate::preset::{EmptyNode, MultiWeightedEdge};
+
+    type Visitor2 = ParetoVisitor<NoTermination, f64, 2>;
+
+    fn graph(edges: &[(u32, u32, [f64; 2])]) -> Graph<EmptyNode, MultiWeightedEdge<2>> {
+        let mut graph = Graph::new();
+        for &(from, to, weight) in edges {
+            graph.add_node(EmptyNode::new(from, None));
+            graph.add_node(EmptyNode::new(to, None));
+            graph.add_edge(MultiWeightedEdge::new(from, to, Some(weight)));
+        }
+        graph
+    }
+
+    #[test]
+    fn dominance_requires_one_strict_improvement() {
+        assert!(dominates(&[1u64, 1], &[1, 2]));
+        assert!(dominates(&[0u64, 0], &[1, 2]));
+        assert!(!dominates(&[1u64, 2], &[1, 2]));
+        assert!(!dominates(&[0u64, 3], &[1, 2]));
+    }
+
+    #[test]
+    fn start_node_has_zero_label() {
+        let graph = graph(&[]);
+        let mut visitor = Visitor2::new(NoTermination);
+
+        visitor.visit(0, &graph);
+
+        assert_eq!(visitor.pareto_costs(0), vec![[0.0, 0.0]]);
+    }
+
+    #[test]
+    fn keeps_non_dominated_trade_offs() {
+        // Fast toll road vs slow free road
+        let graph = graph(&[
+            (0, 1, [1.0, 5.0]),
+            (0, 2, [5.0, 0.0]),
+            (1, 3, [1.0, 0.0]),
+            (2, 3, [1.0, 0.0]),
+        ]);
+        let mut visitor = Visitor2::new(NoTermination);
+
+        graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+        assert_eq!(visitor.pareto_costs(3), vec![[2.0, 5.0], [6.0, 0.0]]);
+    }
+
+    #[test]
+    fn discards_dominated_paths() {
+        let graph = graph(&[
+            (0, 1, [1.0, 1.0]),
+            (0, 2, [2.0, 2.0]),
+            (1, 3, [1.0, 1.0]),
+            (2, 3, [1.0, 1.0]),
+        ]);
+        let mut visitor = Visitor2::new(NoTermination);
+
+        graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+        let paths = visitor.pareto_paths(3);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].nodes, vec![0, 1, 3]);
+        assert_eq!(paths[0].cost, [2.0, 2.0]);
+    }
+
+    #[test]
+    fn replaces_labels_dominated_by_later_discoveries() {
+        // With a FIFO frontier, the direct edge reaches 2 first and is later dominated.
+        let graph = graph(&[(0, 2, [10.0, 10.0]), (0, 1, [1.0, 1.0]), (1, 2, [1.0, 1.0])]);
+        let mut visitor = Visitor2::new(NoTermination);
+
+        graph.traverse(0, &mut Queue::default(), &mut visitor);
+
+        let paths = visitor.pareto_paths(2);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].nodes, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn considers_every_parallel_edge() {
+        let graph = graph(&[(0, 1, [1.0, 3.0]), (0, 1, [3.0, 1.0]), (0, 1, [4.0, 4.0])]);
+        let mut visitor = Visitor2::new(NoTermination);
+
+        graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+        assert_eq!(visitor.pareto_costs(1), vec![[1.0, 3.0], [3.0, 1.0]]);
+    }
+
+    #[test]
+    fn propagates_every_label_through_shared_nodes() {
+        let graph = graph(&[(0, 1, [1.0, 3.0]), (0, 1, [3.0, 1.0]), (1, 2, [1.0, 1.0])]);
+        let mut visitor = Visitor2::new(NoTermination);
+
+        graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+        let paths = visitor.pareto_paths(2);
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|p| p.nodes == vec![0, 1, 2]));
+        assert_eq!(visitor.label_count(), 5);
+    }
+
+    #[test]
+    fn unreached_node_has_no_path() {
+        let graph = graph(&[(0, 1, [1.0, 1.0]), (2, 3, [1.0, 1.0])]);
+        let mut visitor = Visitor2::new(NoTermination);
+
+        graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+        assert!(visitor.pareto_paths(3).is_empty());
+        assert_eq!(visitor.visited_count(), 2);
+    }
+}
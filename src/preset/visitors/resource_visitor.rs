@@ -0,0 +1,403 @@
+use crate::graph::{Cost, Edge, EdgeContext};
+use crate::policy::Policy;
+use crate::preset::edges::ConsumeResources;
+use crate::strategy::Visitor;
+use std::cmp::Ordering;
+
+use super::label_store::LabelStore;
+use super::{CountVisited, TrackCost};
+
+/// A partial path reaching a node, with its cost and cumulated resource usage.
+///
+/// Labels are the entities evaluated by resource limit policies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceLabel<const R: usize> {
+    /// The node reached by the partial path
+    pub node: u32,
+    /// Cumulated cost of the partial path
+    pub cost: f64,
+    /// Cumulated consumption of each resource along the partial path
+    pub resources: [f64; R],
+}
+
+impl<const R: usize> ResourceLabel<R> {
+    /// Returns `true` if this label is at least as good as `other` on cost and
+    /// on every resource.
+    ///
+    /// Equal labels dominate each other, so only one of them is kept.
+    pub fn dominates(&self, other: &Self) -> bool {
+        self.cost.compare(&other.cost) != Ordering::Greater
+            && self
+                .resources
+                .iter()
+                .zip(&other.resources)
+                .all(|(a, b)| a.compare(b) != Ordering::Greater)
+    }
+}
+
+/// Visitor for resource-constrained shortest paths.
+///
+/// Minimizes the cumulated edge weight while the resources consumed along the
+/// path (see `ConsumeResources`) stay within limits. Each node stores a set of
+/// labels, one per non-dominated partial path: a label is discarded when
+/// another label at the same node is cheaper and consumes no more of any
+/// resource.
+///
+/// Limits are a `Policy` evaluated on every new label, so they compose with
+/// `Composite` and `Not`. Infeasible labels are pruned and never propagated.
+///
+/// # Usage
+///
+/// Pair this visitor with a `MinHeap` frontier. Nodes are prioritized by
+/// their cheapest new label, so the best feasible path to a goal is known
+/// as soon as the goal is popped, and `GoalReached` can stop the search early.
+/// Edge weights must be non-negative.
+#[derive(Debug)]
+pub struct ResourceConstrainedVisitor<P, L, const R: usize> {
+    /// Feasible labels, the cheapest new one of a node giving its priority
+    labels: LabelStore<ResourceLabel<R>, f64>,
+    limits: L,
+    terminate: P,
+}
+
+impl<P, L, const R: usize> ResourceConstrainedVisitor<P, L, R>
+where
+    P: Policy<u32, Self>,
+{
+    /// Creates a new visitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminate` - Termination policy evaluated on visited nodes
+    /// * `limits` - Feasibility policy evaluated on every new label
+    pub fn new(terminate: P, limits: L) -> Self {
+        ResourceConstrainedVisitor {
+            labels: LabelStore::new(ResourceLabel::dominates),
+            limits,
+            terminate,
+        }
+    }
+}
+
+impl<P, L, const R: usize> ResourceConstrainedVisitor<P, L, R> {
+    /// Returns the non-dominated feasible labels of a node, sorted by cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The target node ID
+    pub fn labels(&self, node_id: u32) -> Vec<ResourceLabel<R>> {
+        let mut labels: Vec<ResourceLabel<R>> = self
+            .labels
+            .indices(node_id)
+            .map(|index| *self.labels.label(index))
+            .collect();
+        labels.sort_by(|a, b| a.cost.compare(&b.cost));
+        labels
+    }
+
+    /// Returns the cheapest feasible label of a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The target node ID
+    pub fn best_label(&self, node_id: u32) -> Option<ResourceLabel<R>> {
+        self.best_index(node_id)
+            .map(|index| *self.labels.label(index))
+    }
+
+    /// Reconstructs the cheapest feasible path from the start node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The target node ID
+    ///
+    /// # Returns
+    ///
+    /// The node IDs from the start node to the target, or `None` if no
+    /// feasible path was found.
+    pub fn best_path(&self, node_id: u32) -> Option<Vec<u32>> {
+        self.best_index(node_id)
+            .map(|index| self.labels.path(index))
+    }
+
+    fn best_index(&self, node_id: u32) -> Option<usize> {
+        self.labels.indices(node_id).min_by(|&a, &b| {
+            self.labels
+                .label(a)
+                .cost
+                .compare(&self.labels.label(b).cost)
+        })
+    }
+
+    /// Creates the origin label if the traversal has not produced any label yet.
+    fn ensure_origin(&mut self, node_id: u32) {
+        self.labels.ensure_origin(node_id, || ResourceLabel {
+            node: node_id,
+            cost: 0.0,
+            resources: [0.0; R],
+        });
+    }
+}
+
+impl<P, L, const R: usize> CountVisited for ResourceConstrainedVisitor<P, L, R> {
+    fn visited_count(&self) -> usize {
+        self.labels.node_count()
+    }
+}
+
+impl<P, L, const R: usize> TrackCost for ResourceConstrainedVisitor<P, L, R> {
+    /// Returns the cost of the cheapest feasible path found to a node.
+    fn cost_to(&self, node_id: u32) -> Option<f64> {
+        self.best_label(node_id).map(|label| label.cost)
+    }
+}
+
+impl<Ctx, P, L, const R: usize> Visitor<Ctx> for ResourceConstrainedVisitor<P, L, R>
+where
+    Ctx: EdgeContext,
+    Ctx::Edge: ConsumeResources<R, Weight = f64>,
+    P: Policy<u32, Self>,
+    L: Policy<ResourceLabel<R>, Ctx>,
+{
+    /// Returns the cost of the cheapest label newly added to the target node.
+    ///
+    /// # Arguments
+    ///
+    /// * `_from` - Source node ID (unused)
+    /// * `to` - Target node ID
+    /// * `_context` - The graph being traversed (unused)
+    fn exploration_cost(&self, _from: u32, to: u32, _context: &Ctx) -> f64 {
+        self.labels.pending(to).unwrap_or(0.0)
+    }
+
+    /// Extends the unexpanded labels of the source node along every edge to the target.
+    ///
+    /// New labels are kept only if the limits policy allows them and no
+    /// existing label of the target dominates them.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    /// * `context` - The graph being traversed
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one feasible, non-dominated label reached the target node
+    fn should_explore(&mut self, from: u32, to: u32, context: &Ctx) -> bool {
+        self.ensure_origin(from);
+
+        let sources = self.labels.expanding_labels(from);
+        let mut best: Option<f64> = None;
+
+        let edges: Vec<(f64, [f64; R])> = context.with_edges_from(from, |edges| {
+            edges
+                .iter()
+                .filter(|e| e.to() == to)
+                .map(|e| (e.weight(), e.consumption()))
+                .collect()
+        });
+
+        for (weight, consumption) in edges {
+            for &source in &sources {
+                let origin = *self.labels.label(source);
+                let mut resources = origin.resources;
+                for (total, used) in resources.iter_mut().zip(consumption) {
+                    *total += used;
+                }
+                let label = ResourceLabel {
+                    node: to,
+                    cost: origin.cost.plus(weight),
+                    resources,
+                };
+
+                if self.limits.is_compliant(&label, context)
+                    && self.labels.insert(to, label, Some(source))
+                    && best.is_none_or(|b| label.cost < b)
+                {
+                    best = Some(label.cost);
+                }
+            }
+        }
+
+        match best {
+            Some(cost) => {
+                self.labels.set_pending(to, cost);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Marks the labels of a node as expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The ID of the node being visited
+    /// * `_context` - The graph being traversed (unused)
+    fn visit(&mut self, node_id: u32, _context: &Ctx) {
+        self.ensure_origin(node_id);
+        self.labels.finish_expansion(node_id);
+    }
+
+    fn should_stop(&self, node_id: u32, _context: &Ctx) -> bool {
+        self.terminate.is_compliant(&node_id, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::MinHeap;
+    use crate::graph::{Edge, Graph, Node};
+    use crate::policy::Composite;
+    use crate::preset::policies::traversal::{GoalReached, NoTermination};
+    use crate::preset::policies::value::{AllowAll, ResourceLimit};
+    use crate::preset::{EmptyNode, ResourceEdge};
+
+    type Fuel = Graph<EmptyNode, ResourceEdge<1>>;
+
+    fn graph<const R: usize>(
+        edges: &[(u32, u32, f64, [f64; R])],
+    ) -> Graph<EmptyNode, ResourceEdge<R>> {
+        let mut graph = Graph::new();
+        for &(from, to, weight, consumption) in edges {
+            graph.add_node(EmptyNode::new(from, None));
+            graph.add_node(EmptyNode::new(to, None));
+            graph.add_edge(ResourceEdge::with_consumption(
+                from,
+                to,
+                weight,
+                consumption,
+            ));
+        }
+        graph
+    }
+
+    /// Fast route 0 -> 1 -> 3 burns 10 fuel, slow route 0 -> 2 -> 3 burns 2.
+    fn fuel_graph() -> Fuel {
+        graph(&[
+            (0, 1, 1.0, [5.0]),
+            (1, 3, 1.0, [5.0]),
+            (0, 2, 4.0, [1.0]),
+            (2, 3, 4.0, [1.0]),
+        ])
+    }
+
+    #[test]
+    fn dominance_covers_cost_and_every_resource() {
+        let a = ResourceLabel {
+            node: 0,
+            cost: 1.0,
+            resources: [1.0, 1.0],
+        };
+        let b = ResourceLabel {
+            cost: 2.0,
+            resources: [1.0, 2.0],
+            ..a
+        };
+        let c = ResourceLabel {
+            cost: 0.5,
+            resources: [1.0, 3.0],
+            ..a
+        };
+
+        assert!(a.dominates(&b));
+        assert!(a.dominates(&a));
+        assert!(!b.dominates(&a));
+        assert!(!a.dominates(&c));
+        assert!(!c.dominates(&a));
+    }
+
+    #[test]
+    fn unconstrained_search_finds_shortest_path() {
+        let graph = fuel_graph();
+        let mut visitor = ResourceConstrainedVisitor::new(NoTermination, AllowAll::default());
+
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(3), Some(2.0));
+        assert_eq!(visitor.best_path(3), Some(vec![0, 1, 3]));
+        assert_eq!(visitor.labels(3).len(), 2);
+    }
+
+    #[test]
+    fn limit_forces_feasible_detour() {
+        let graph = fuel_graph();
+        let mut visitor =
+            ResourceConstrainedVisitor::new(NoTermination, ResourceLimit::new(0, 6.0));
+
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        let best = visitor.best_label(3).unwrap();
+        assert_eq!(best.cost, 8.0);
+        assert_eq!(best.resources, [2.0]);
+        assert_eq!(visitor.best_path(3), Some(vec![0, 2, 3]));
+        assert_eq!(visitor.labels(3).len(), 1);
+    }
+
+    #[test]
+    fn infeasible_target_has_no_path() {
+        let graph = fuel_graph();
+        let mut visitor =
+            ResourceConstrainedVisitor::new(NoTermination, ResourceLimit::new(0, 1.0));
+
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.best_path(3), None);
+        assert_eq!(visitor.cost_to(3), None);
+        assert_eq!(visitor.visited_count(), 2);
+    }
+
+    #[test]
+    fn goal_reached_stops_on_best_feasible_cost() {
+        let graph = fuel_graph();
+        let mut visitor =
+            ResourceConstrainedVisitor::new(GoalReached::new(3), ResourceLimit::new(0, 6.0));
+
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(3), Some(8.0));
+    }
+
+    #[test]
+    fn composite_limits_constrain_several_resources() {
+        // Resources are [fuel, transfers]
+        let graph = graph(&[
+            (0, 1, 1.0, [1.0, 1.0]),
+            (1, 2, 1.0, [1.0, 1.0]),
+            (2, 4, 1.0, [1.0, 1.0]),
+            (0, 3, 2.0, [4.0, 1.0]),
+            (3, 4, 2.0, [4.0, 1.0]),
+            (0, 4, 9.0, [2.0, 1.0]),
+        ]);
+        let limits = Composite::And(ResourceLimit::new(0, 5.0), ResourceLimit::new(1, 2.0));
+        let mut visitor = ResourceConstrainedVisitor::new(NoTermination, limits);
+
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(4), Some(9.0));
+        assert_eq!(visitor.best_path(4), Some(vec![0, 4]));
+    }
+
+    #[test]
+    fn keeps_costlier_labels_saving_resources() {
+        // The expensive label at 1 saves fuel needed later on.
+        let graph = graph(&[(0, 1, 1.0, [3.0]), (0, 1, 2.0, [0.0]), (1, 2, 1.0, [3.0])]);
+        let mut visitor =
+            ResourceConstrainedVisitor::new(NoTermination, ResourceLimit::new(0, 4.0));
+
+        graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.labels(1).len(), 2);
+        assert_eq!(visitor.cost_to(2), Some(3.0));
+    }
+
+    #[test]
+    fn edges_without_consumption_are_free() {
+        let edge = ResourceEdge::<2>::new(0, 1, None);
+
+        assert_eq!(edge.weight(), 1.0);
+        assert_eq!(edge.consumption(), [0.0, 0.0]);
+    }
+}
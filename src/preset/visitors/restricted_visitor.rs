@@ -0,0 +1,172 @@
+use crate::graph::Cost;
+use crate::strategy::Visitor;
+use std::collections::HashSet;
+
+use super::{CountVisited, TrackCost, TrackParent};
+
+/// Visitor wrapper hiding some nodes and edges from another visitor.
+///
+/// Banned nodes and edges are never explored, as if they had been removed
+/// from the graph, while every other decision is delegated to the wrapped
+/// visitor. This avoids rebuilding or mutating a graph to run a search on a
+/// restricted version of it.
+///
+/// # Typical Use Cases
+///
+/// - Deviation searches, such as Yen's k-shortest paths
+/// - What-if analysis of closed roads or failed links
+///
+/// # Usage
+///
+/// Wrap any visitor, for instance a `WeightedVisitor` paired with a
+/// `MinHeap` frontier, and ban elements before the traversal. The start node
+/// is always visited, even when banned.
+#[derive(Debug, Default)]
+pub struct RestrictedVisitor<V> {
+    inner: V,
+    banned_nodes: HashSet<u32>,
+    banned_edges: HashSet<(u32, u32)>,
+}
+
+impl<V> RestrictedVisitor<V> {
+    pub fn new(inner: V) -> Self {
+        RestrictedVisitor {
+            inner,
+            banned_nodes: HashSet::new(),
+            banned_edges: HashSet::new(),
+        }
+    }
+
+    /// Prevents the traversal from entering a node.
+    pub fn ban_node(&mut self, node_id: u32) {
+        self.banned_nodes.insert(node_id);
+    }
+
+    /// Prevents the traversal from following the edges from `from` to `to`.
+    pub fn ban_edge(&mut self, from: u32, to: u32) {
+        self.banned_edges.insert((from, to));
+    }
+
+    /// Returns the wrapped visitor.
+    pub fn inner(&self) -> &V {
+        &self.inner
+    }
+
+    /// Consumes the wrapper and returns the wrapped visitor.
+    pub fn into_inner(self) -> V {
+        self.inner
+    }
+}
+
+impl<V: CountVisited> CountVisited for RestrictedVisitor<V> {
+    fn visited_count(&self) -> usize {
+        self.inner.visited_count()
+    }
+}
+
+impl<V: TrackCost<C>, C: Cost> TrackCost<C> for RestrictedVisitor<V> {
+    fn cost_to(&self, node_id: u32) -> Option<C> {
+        self.inner.cost_to(node_id)
+    }
+}
+
+impl<V: TrackParent> TrackParent for RestrictedVisitor<V> {
+    fn get_parent(&self, node_id: u32) -> Option<u32> {
+        self.inner.get_parent(node_id)
+    }
+}
+
+impl<Ctx, C, V> Visitor<Ctx, C> for RestrictedVisitor<V>
+where
+    C: Cost,
+    V: Visitor<Ctx, C>,
+{
+    fn init_cost(&self, node_id: u32, context: &Ctx) -> C {
+        self.inner.init_cost(node_id, context)
+    }
+
+    fn exploration_cost(&self, from: u32, to: u32, context: &Ctx) -> C {
+        self.inner.exploration_cost(from, to, context)
+    }
+
+    /// Refuses banned nodes and edges, then defers to the wrapped visitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Source node ID
+    /// * `to` - Target node ID
+    /// * `context` - Traversal context
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target is allowed and the wrapped visitor explores it
+    fn should_explore(&mut self, from: u32, to: u32, context: &Ctx) -> bool {
+        if self.banned_nodes.contains(&to) || self.banned_edges.contains(&(from, to)) {
+            return false;
+        }
+        self.inner.should_explore(from, to, context)
+    }
+
+    fn visit(&mut self, node_id: u32, context: &Ctx) {
+        self.inner.visit(node_id, context);
+    }
+
+    fn should_stop(&self, node_id: u32, context: &Ctx) -> bool {
+        self.inner.should_stop(node_id, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontier::{MinHeap, Queue};
+    use crate::graph::{Edge, Graph, Node};
+    use crate::preset::policies::traversal::NoTermination;
+    use crate::preset::visitors::{SimpleVisitor, WeightedVisitor};
+    use crate::preset::{EmptyNode, WeightedEdge};
+
+    fn graph() -> Graph<EmptyNode, WeightedEdge> {
+        let mut graph = Graph::new();
+        for id in 0..4 {
+            graph.add_node(EmptyNode::new(id, None));
+        }
+        for (from, to, weight) in [(0, 1, 1.0), (1, 3, 1.0), (0, 2, 2.0), (2, 3, 2.0)] {
+            graph.add_edge(WeightedEdge::new(from, to, Some(weight)));
+        }
+        graph
+    }
+
+    #[test]
+    fn delegates_without_bans() {
+        let mut visitor = RestrictedVisitor::new(WeightedVisitor::new(NoTermination));
+
+        graph().traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(3), Some(2.0));
+        assert_eq!(visitor.get_parent(3), Some(1));
+        assert_eq!(visitor.visited_count(), 4);
+    }
+
+    #[test]
+    fn banned_edge_forces_detour() {
+        let mut visitor = RestrictedVisitor::new(WeightedVisitor::new(NoTermination));
+        visitor.ban_edge(1, 3);
+
+        graph().traverse(0, &mut MinHeap::new(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(3), Some(4.0));
+        assert_eq!(visitor.into_inner().get_parent(3), Some(2));
+    }
+
+    #[test]
+    fn banned_nodes_are_unreachable() {
+        let mut visitor = RestrictedVisitor::new(SimpleVisitor::new(NoTermination));
+        visitor.ban_node(1);
+        visitor.ban_node(2);
+
+        graph().traverse(0, &mut Queue::new(), &mut visitor);
+
+        assert_eq!(visitor.inner().visited_count(), 1);
+        assert_eq!(visitor.get_parent(3), None);
+    }
+}
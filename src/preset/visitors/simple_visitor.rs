@@ -93,7 +93,7 @@ mod tests {
     pub struct Terminate {}
 
     impl Policy<u32, SimpleVisitor<Self>> for Terminate {
-        fn is_compliant(&self, _: &u32, __: &SimpleVisitor<Self>) -> bool {
+        fn is_compliant(&self, _: &u32, _visitor: &SimpleVisitor<Self>) -> bool {
             true
         }
     }
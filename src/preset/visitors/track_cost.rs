@@ -0,0 +1,9 @@
+use crate::graph::Cost;
+
+/// Exposes the cumulative cost computed by a visitor to reach a node.
+pub trait TrackCost<C: Cost = f64> {
+    /// Returns the best known cost from the start node to `node_id`, if reached.
+    fn cost_to(&self, _node_id: u32) -> Option<C> {
+        None
+    }
+}
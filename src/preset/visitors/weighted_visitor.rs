@@ -1,9 +1,10 @@
-use crate::graph::{Edge, Graph, Node};
+use crate::graph::{Cost, Edge, EdgeContext};
 use crate::policy::Policy;
 use crate::strategy::Visitor;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
-use super::{CountVisited, TrackParent};
+use super::{CountVisited, TrackCost, TrackParent};
 
 /// Visitor for weighted graph traversal (Dijkstra's algorithm).
 ///
@@ -24,20 +25,22 @@ use super::{CountVisited, TrackParent};
 /// Pair this visitor with a `MinHeap` frontier to implement
 /// Dijkstra's algorithm. The frontier will prioritize nodes with the lowest
 /// cumulative cost.
+///
+/// Distances use the `Cost` type of the traversed edges' weights, `f64` by default.
 #[derive(Debug, Default)]
-pub struct WeightedVisitor<P> {
+pub struct WeightedVisitor<P, C = f64> {
     /// Maps node IDs to their shortest known cumulative distance from the start
-    distances: HashMap<u32, f64>,
+    distances: HashMap<u32, C>,
     parents: HashMap<u32, Option<u32>>,
     terminate: P,
 }
 
-impl<P> WeightedVisitor<P>
+impl<P, C> WeightedVisitor<P, C>
 where
     P: Policy<u32, Self>,
 {
     pub fn new(terminate: P) -> Self {
-        WeightedVisitor::<P> {
+        WeightedVisitor {
             distances: HashMap::new(),
             parents: HashMap::new(),
             terminate,
@@ -45,13 +48,19 @@ where
     }
 }
 
-impl<P> CountVisited for WeightedVisitor<P> {
+impl<P, C> CountVisited for WeightedVisitor<P, C> {
     fn visited_count(&self) -> usize {
         self.distances.len()
     }
 }
 
-impl<P> TrackParent for WeightedVisitor<P> {
+impl<P, C: Cost> TrackCost<C> for WeightedVisitor<P, C> {
+    fn cost_to(&self, node_id: u32) -> Option<C> {
+        self.distances.get(&node_id).copied()
+    }
+}
+
+impl<P, C> TrackParent for WeightedVisitor<P, C> {
     fn get_parent(&self, node_id: u32) -> Option<u32> {
         if self.parents.contains_key(&node_id) {
             return self.parents[&node_id];
@@ -60,10 +69,11 @@ impl<P> TrackParent for WeightedVisitor<P> {
     }
 }
 
-impl<TNode, TEdge, P> Visitor<Graph<TNode, TEdge>> for WeightedVisitor<P>
+impl<Ctx, P, C> Visitor<Ctx, C> for WeightedVisitor<P, C>
 where
-    TNode: Node,
-    TEdge: Edge,
+    Ctx: EdgeContext,
+    Ctx::Edge: Edge<Weight = C>,
+    C: Cost,
     P: Policy<u32, Self>,
 {
     /// Computes the cumulative cost to reach a target node via a specific edge.
@@ -81,17 +91,18 @@ where
     /// # Returns
     ///
     /// The total cumulative cost to reach `to` via `from`
-    fn exploration_cost(&self, from: u32, to: u32, context: &Graph<TNode, TEdge>) -> f64 {
-        let from_dist = self.distances.get(&from).unwrap_or(&0.0);
-
-        let edge_weight = context
-            .get_edges()
-            .iter()
-            .find(|e| e.from() == from && e.to() == to)
-            .map(|e| e.weight())
-            .unwrap_or(0.0);
-
-        from_dist + edge_weight
+    fn exploration_cost(&self, from: u32, to: u32, context: &Ctx) -> C {
+        let from_dist = self.distances.get(&from).copied().unwrap_or(C::zero());
+
+        let edge_weight = context.with_edges_from(from, |edges| {
+            edges
+                .iter()
+                .find(|e| e.to() == to)
+                .map(|e| e.weight())
+                .unwrap_or(C::zero())
+        });
+
+        from_dist.plus(edge_weight)
     }
 
     /// Determines whether to explore a path to the target node.
@@ -111,7 +122,7 @@ where
     /// # Returns
     ///
     /// `true` if the path should be explored, `false` otherwise
-    fn should_explore(&mut self, from: u32, to: u32, context: &Graph<TNode, TEdge>) -> bool {
+    fn should_explore(&mut self, from: u32, to: u32, context: &Ctx) -> bool {
         let new_dist = self.exploration_cost(from, to, context);
 
         match self.distances.get(&to) {
@@ -120,7 +131,7 @@ where
                 self.parents.insert(to, Some(from));
                 true
             }
-            Some(&current_dist) if new_dist < current_dist => {
+            Some(current_dist) if new_dist.compare(current_dist) == Ordering::Less => {
                 self.distances.insert(to, new_dist);
                 self.parents.insert(to, Some(from));
                 true
@@ -132,18 +143,18 @@ where
     /// Marks a node as visited.
     ///
     /// Ensures the node exists in the distance map. For the start node,
-    /// this initializes its distance to 0.0.
+    /// this initializes its distance to zero.
     ///
     /// # Arguments
     ///
     /// * `node_id` - The ID of the node being visited
     /// * `_context` - The graph being traversed (unused)
-    fn visit(&mut self, node_id: u32, _context: &Graph<TNode, TEdge>) {
-        self.distances.entry(node_id).or_insert(0.0);
+    fn visit(&mut self, node_id: u32, _context: &Ctx) {
+        self.distances.entry(node_id).or_insert(C::zero());
         self.parents.entry(node_id).or_insert(None);
     }
 
-    fn should_stop(&self, node_id: u32, _context: &Graph<TNode, TEdge>) -> bool {
+    fn should_stop(&self, node_id: u32, _context: &Ctx) -> bool {
         self.terminate.is_compliant(&node_id, self)
     }
 }
@@ -151,6 +162,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::{Graph, Node};
 
     pub struct MockNode;
 
@@ -169,7 +181,7 @@ mod tests {
     pub struct Terminate {}
 
     impl Policy<u32, WeightedVisitor<Self>> for Terminate {
-        fn is_compliant(&self, _: &u32, __: &WeightedVisitor<Self>) -> bool {
+        fn is_compliant(&self, _: &u32, _visitor: &WeightedVisitor<Self>) -> bool {
             true
         }
     }
@@ -182,6 +194,8 @@ mod tests {
     }
 
     impl Edge for MockWeightedEdge {
+        type Weight = f64;
+
         fn new(from: u32, to: u32, weight: Option<f64>) -> Self {
             MockWeightedEdge {
                 from,
@@ -328,4 +342,53 @@ mod tests {
 
         assert!(visitor.should_stop(0, &graph));
     }
+
+    #[test]
+    fn tracks_cost_to_visited_nodes() {
+        let mut graph = Graph::<MockNode, MockWeightedEdge>::new();
+        graph.add_edge(MockWeightedEdge::new(0, 1, Some(2.5)));
+
+        let mut visitor = WeightedVisitor::new(Terminate::default());
+        visitor.visit(0, &graph);
+        visitor.should_explore(0, 1, &graph);
+
+        assert_eq!(visitor.cost_to(0), Some(0.0));
+        assert_eq!(visitor.cost_to(1), Some(2.5));
+        assert_eq!(visitor.cost_to(2), None);
+    }
+
+    #[test]
+    fn accumulates_integer_costs_exactly() {
+        use crate::preset::WeightedEdge;
+
+        let mut graph = Graph::<MockNode, WeightedEdge<u64>>::new();
+        graph.add_edge(WeightedEdge::new(0, 1, Some(3)));
+        graph.add_edge(WeightedEdge::new(1, 2, Some(4)));
+
+        let mut visitor =
+            WeightedVisitor::<_, u64>::new(crate::preset::policies::traversal::NoTermination);
+        visitor.visit(0, &graph);
+        visitor.should_explore(0, 1, &graph);
+        visitor.should_explore(1, 2, &graph);
+
+        assert_eq!(visitor.cost_to(2), Some(7));
+    }
+
+    #[test]
+    fn saturates_integer_costs_past_max() {
+        use crate::frontier::MinHeap;
+        use crate::preset::WeightedEdge;
+
+        let mut graph = Graph::<MockNode, WeightedEdge<u64>>::new();
+        graph.add_edge(WeightedEdge::new(0, 1, Some(u64::MAX - 1)));
+        graph.add_edge(WeightedEdge::new(1, 2, Some(5)));
+        graph.add_edge(WeightedEdge::new(0, 2, Some(10)));
+
+        let mut visitor =
+            WeightedVisitor::<_, u64>::new(crate::preset::policies::traversal::NoTermination);
+        graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+        assert_eq!(visitor.cost_to(1), Some(u64::MAX - 1));
+        assert_eq!(visitor.cost_to(2), Some(10));
+    }
 }
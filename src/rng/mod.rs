@@ -0,0 +1,3 @@
+pub mod seeded_rng;
+
+pub use seeded_rng::SeededRng;
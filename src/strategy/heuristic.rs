@@ -0,0 +1,27 @@
+use crate::graph::Cost;
+
+/// A strategy estimating the remaining cost from a node to a goal.
+///
+/// Heuristics guide goal-directed searches such as A*: the frontier orders
+/// nodes by their cost so far plus the estimate. The search stays optimal
+/// as long as the estimate never exceeds the true remaining cost.
+///
+/// Any `Fn(u32) -> C` closure is a heuristic.
+///
+/// # Type Parameters
+///
+/// * `C` - Cost type of the estimate, `f64` by default
+pub trait Heuristic<C: Cost = f64> {
+    /// Estimates the cost from a node to the goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The node to estimate from
+    fn estimate(&self, node_id: u32) -> C;
+}
+
+impl<C: Cost, F: Fn(u32) -> C> Heuristic<C> for F {
+    fn estimate(&self, node_id: u32) -> C {
+        self(node_id)
+    }
+}
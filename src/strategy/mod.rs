@@ -1,5 +1,11 @@
+pub mod heuristic;
+pub mod neighbor_provider;
+pub mod pairwise_heuristic;
 pub mod sampler;
 pub mod visitor;
 
+pub use heuristic::Heuristic;
+pub use neighbor_provider::NeighborProvider;
+pub use pairwise_heuristic::PairwiseHeuristic;
 pub use sampler::Sampler;
 pub use visitor::Visitor;
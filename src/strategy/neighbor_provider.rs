@@ -0,0 +1,30 @@
+use crate::graph::Edge;
+
+/// A strategy generating the outgoing edges of a node on demand.
+///
+/// Providers describe implicit graphs, such as puzzle states or game trees,
+/// that are too large or unbounded to be built before traversal. Each state
+/// is identified by a `u32` node ID, which the provider is free to encode
+/// and decode as it sees fit.
+///
+/// Any `Fn(u32) -> Vec<E>` closure is a provider.
+pub trait NeighborProvider {
+    type Edge: Edge;
+
+    /// Generates the edges leaving a node.
+    ///
+    /// Must return the same edges every time it is called for a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The node to expand
+    fn neighbors(&self, node_id: u32) -> Vec<Self::Edge>;
+}
+
+impl<E: Edge, F: Fn(u32) -> Vec<E>> NeighborProvider for F {
+    type Edge = E;
+
+    fn neighbors(&self, node_id: u32) -> Vec<E> {
+        self(node_id)
+    }
+}
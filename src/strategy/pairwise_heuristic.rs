@@ -0,0 +1,29 @@
+use crate::graph::Cost;
+
+/// A strategy estimating the cost between any two nodes.
+///
+/// Unlike `Heuristic`, whose goal is fixed, the estimate is requested
+/// between arbitrary nodes. Planners whose reference node moves, such as
+/// D* Lite following an agent, rely on it. The estimate must never exceed
+/// the true cost between both nodes.
+///
+/// Any `Fn(u32, u32) -> C` closure is a pairwise heuristic.
+///
+/// # Type Parameters
+///
+/// * `C` - Cost type of the estimate, `f64` by default
+pub trait PairwiseHeuristic<C: Cost = f64> {
+    /// Estimates the cost from one node to another.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The node to estimate from
+    /// * `to` - The node to estimate to
+    fn estimate_between(&self, from: u32, to: u32) -> C;
+}
+
+impl<C: Cost, F: Fn(u32, u32) -> C> PairwiseHeuristic<C> for F {
+    fn estimate_between(&self, from: u32, to: u32) -> C {
+        self(from, to)
+    }
+}
@@ -1,8 +1,15 @@
+use crate::graph::Cost;
+
 /// A strategy for processing nodes during graph traversal.
 ///
 /// Visitors define custom behavior that executes when a node is encountered.
 /// They can inspect, modify, or collect information from nodes.
-pub trait Visitor<Ctx> {
+///
+/// # Type Parameters
+///
+/// * `Ctx` - Contextual information available during traversal (usually the graph)
+/// * `C`   - Cost type pushed to the frontier, `f64` by default
+pub trait Visitor<Ctx, C: Cost = f64> {
     /// Gives the initial search cost when starting the traversal.
     ///
     /// Should usually return zero but some business rule may want to override it.
@@ -11,8 +18,8 @@ pub trait Visitor<Ctx> {
     ///
     /// * `node_id` - The start node index
     /// * `context` - Contextual information available during traversal
-    fn init_cost(&self, _node_id: u32, _context: &Ctx) -> f64 {
-        0.0
+    fn init_cost(&self, _node_id: u32, _context: &Ctx) -> C {
+        C::zero()
     }
 
     /// Computes global exploration cost to reach a given node.
@@ -24,8 +31,8 @@ pub trait Visitor<Ctx> {
     /// * `from`    - The connection's source node id
     /// * `to`      - The connection's target node id
     /// * `context` - Contextual information available during traversal
-    fn exploration_cost(&self, _from: u32, _to: u32, _context: &Ctx) -> f64 {
-        1.0
+    fn exploration_cost(&self, _from: u32, _to: u32, _context: &Ctx) -> C {
+        C::one()
     }
 
     /// Determines if a connection should be explored.
@@ -0,0 +1,771 @@
+mod algorithm_integration {
+    use hodos::builder::GraphBuilder;
+    use hodos::preset::policies::structural::DenyDanglingEdge;
+    use hodos::preset::policies::value::AllowAll;
+    use hodos::preset::samplers::WeightedMatrixSampler;
+
+    mod all_pairs {
+        use super::*;
+        use hodos::algorithm::{floyd_warshall, johnson};
+        use hodos::frontier::MinHeap;
+        use hodos::preset::policies::traversal::NoTermination;
+        use hodos::preset::visitors::{TrackCost, WeightedVisitor};
+
+        fn depots() -> Vec<Vec<Option<f64>>> {
+            vec![
+                vec![None, Some(4.0), Some(1.0), None, None],
+                vec![Some(4.0), None, Some(2.0), Some(5.0), None],
+                vec![Some(1.0), Some(2.0), None, Some(8.0), Some(10.0)],
+                vec![None, Some(5.0), Some(8.0), None, Some(2.0)],
+                vec![None, None, Some(10.0), Some(2.0), None],
+            ]
+        }
+
+        #[test]
+        fn matches_one_traversal_per_source() {
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&depots());
+
+            let dense = floyd_warshall(&graph).unwrap();
+            let sparse = johnson(&graph).unwrap();
+
+            for source in 0..5 {
+                let mut visitor = WeightedVisitor::new(NoTermination);
+                graph.traverse(source, &mut MinHeap::new(), &mut visitor);
+
+                for target in 0..5 {
+                    assert_eq!(dense.distance(source, target), visitor.cost_to(target));
+                    assert_eq!(sparse.distance(source, target), visitor.cost_to(target));
+                }
+            }
+            assert_eq!(dense.path(0, 4), Some(vec![0, 2, 1, 3, 4]));
+            assert_eq!(sparse.path(0, 4), Some(vec![0, 2, 1, 3, 4]));
+        }
+    }
+
+    mod components {
+        use super::*;
+        use hodos::algorithm::{condensation, tarjan_scc, weakly_connected_components};
+        use hodos::policy::Composite;
+        use hodos::preset::policies::value::AllowWeightBelow;
+
+        fn network() -> Vec<Vec<Option<f64>>> {
+            vec![
+                vec![None, Some(1.0), None, None],
+                vec![Some(1.0), None, Some(9.0), None],
+                vec![None, None, None, Some(1.0)],
+                vec![None, None, Some(1.0), None],
+            ]
+        }
+
+        #[test]
+        fn detects_graphs_split_by_builder_policies() {
+            let full = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&network());
+            let filtered = GraphBuilder::new(
+                Composite::And(DenyDanglingEdge::default(), AllowWeightBelow::new(5.0)),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&network());
+
+            assert!(weakly_connected_components(&full).is_connected());
+            assert_eq!(weakly_connected_components(&filtered).sizes(), &[2, 2]);
+        }
+
+        #[test]
+        fn condenses_strong_components_into_a_dag() {
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&network());
+
+            let components = tarjan_scc(&graph);
+            let dag = condensation(&graph, &components);
+
+            assert_eq!(components.count(), 2);
+            assert_eq!(components.members(0), vec![0, 1]);
+            assert_eq!(dag.nodes.len(), 2);
+            assert_eq!(dag.edges[&0].len(), 1);
+        }
+    }
+
+    mod topological {
+        use super::*;
+        use hodos::algorithm::{dag_longest_paths, find_cycle, topological_order};
+        use hodos::policy::Composite;
+        use hodos::preset::policies::structural::DenyCycle;
+        use hodos::preset::samplers::WeightedAdjacencySampler;
+
+        // Task durations as edge weights; 3 -> 0 would close a cycle.
+        fn pipeline() -> Vec<Vec<(u32, f64)>> {
+            vec![
+                vec![(1, 2.0), (2, 1.0)],
+                vec![(3, 4.0)],
+                vec![(3, 1.0)],
+                vec![(0, 1.0)],
+            ]
+        }
+
+        #[test]
+        fn deny_cycle_builds_a_dag() {
+            let cyclic = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedAdjacencySampler::new(),
+            )
+            .build(&pipeline());
+            let acyclic = GraphBuilder::new(
+                Composite::And(DenyDanglingEdge::default(), DenyCycle::default()),
+                AllowAll::default(),
+                WeightedAdjacencySampler::new(),
+            )
+            .build(&pipeline());
+
+            assert!(topological_order(&cyclic).is_err());
+            assert_eq!(find_cycle(&cyclic), Some(vec![0, 1, 3]));
+
+            assert_eq!(topological_order(&acyclic), Ok(vec![0, 1, 2, 3]));
+            let critical = dag_longest_paths(&acyclic, 0).unwrap();
+            assert_eq!(critical.distance(3), Some(6.0));
+            assert_eq!(critical.path(3), Some(vec![0, 1, 3]));
+        }
+    }
+
+    mod spanning_tree {
+        use super::*;
+        use hodos::algorithm::{kruskal, prim};
+        use hodos::frontier::MinHeap;
+        use hodos::preset::policies::traversal::NoTermination;
+        use hodos::preset::visitors::{TrackCost, WeightedVisitor};
+
+        #[test]
+        fn spanning_tree_is_traversable() {
+            let context = vec![
+                vec![None, Some(1.0), Some(4.0), None],
+                vec![Some(1.0), None, Some(2.0), Some(6.0)],
+                vec![Some(4.0), Some(2.0), None, Some(3.0)],
+                vec![None, Some(6.0), Some(3.0), None],
+            ];
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&context);
+
+            let forest = kruskal(&graph);
+            assert_eq!(forest.total_weight(), 6.0);
+            assert_eq!(prim(&graph).total_weight(), 6.0);
+
+            let tree = forest.into_graph();
+            let mut visitor = WeightedVisitor::new(NoTermination);
+            tree.traverse(3, &mut MinHeap::new(), &mut visitor);
+
+            assert_eq!(visitor.cost_to(0), Some(6.0));
+        }
+    }
+
+    mod flow {
+        use super::*;
+        use hodos::algorithm::dinic;
+
+        #[test]
+        fn splits_logistics_network_along_min_cut() {
+            // Warehouse 0 ships to store 3 through hubs 1 and 2
+            let capacities = vec![
+                vec![None, Some(10.0), Some(5.0), None],
+                vec![None, None, Some(15.0), Some(4.0)],
+                vec![None, None, None, Some(10.0)],
+                vec![None, None, None, None],
+            ];
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&capacities);
+
+            let flow = dinic(&graph, 0, 3);
+
+            assert_eq!(flow.value(), 14.0);
+            assert_eq!(flow.source_side(), vec![0, 1, 2]);
+            assert_eq!(flow.cut_edges(), vec![(1, 3, 4.0), (2, 3, 10.0)]);
+        }
+    }
+
+    mod matching {
+        use super::*;
+        use hodos::algorithm::{bipartition, hopcroft_karp, hungarian};
+
+        #[test]
+        fn assigns_drivers_to_jobs() {
+            // Drivers 0..3 may serve jobs 3..6, weights are travel times
+            let mut times = vec![vec![None; 6]; 6];
+            for (driver, job, time) in [
+                (0, 3, 9.0),
+                (0, 4, 2.0),
+                (1, 3, 6.0),
+                (1, 4, 4.0),
+                (1, 5, 3.0),
+                (2, 4, 5.0),
+            ] {
+                times[driver][job] = Some(time);
+            }
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&times);
+
+            let partition = bipartition(&graph).unwrap();
+            assert_eq!(partition.left(), vec![0, 1, 2]);
+            assert_eq!(partition.right(), vec![3, 4, 5]);
+
+            assert_eq!(hopcroft_karp(&graph).unwrap().size(), 3);
+
+            let assignment = hungarian(&graph).unwrap();
+            assert_eq!(assignment.pairs(), &[(0, 3, 9.0), (1, 5, 3.0), (2, 4, 5.0)]);
+            assert_eq!(assignment.total_cost(), 17.0);
+        }
+    }
+
+    mod biconnected {
+        use super::*;
+        use hodos::algorithm::{biconnected_components, weakly_connected_components};
+        use hodos::graph::Edge;
+        use hodos::preset::samplers::SimpleAdjacencySampler;
+
+        #[test]
+        fn cutting_critical_elements_splits_network() {
+            // Ring of routers 0..4 with a single uplink 3 - 4 to a gateway pair 4 - 5
+            let links = vec![
+                vec![1, 2],
+                vec![0, 3],
+                vec![0, 3],
+                vec![1, 2, 4],
+                vec![3, 5],
+                vec![4],
+            ];
+            let build = || {
+                GraphBuilder::new(
+                    DenyDanglingEdge::default(),
+                    AllowAll::default(),
+                    SimpleAdjacencySampler::new(),
+                )
+                .build(&links)
+            };
+            let graph = build();
+
+            let analysis = biconnected_components(&graph);
+            assert_eq!(analysis.articulation_points(), &[3, 4]);
+            assert_eq!(analysis.bridges(), &[(3, 4), (4, 5)]);
+
+            // Removing a bridge disconnects the network
+            let mut degraded = build();
+            for (a, b) in [(3, 4), (4, 3)] {
+                degraded.edges.get_mut(&a).unwrap().retain(|e| e.to() != b);
+            }
+            assert_eq!(weakly_connected_components(&degraded).count(), 2);
+
+            // Blocks: the ring and both bridges, joined by the two articulation points
+            let tree = analysis.block_cut_tree();
+            assert_eq!(tree.nodes.len(), 5);
+            assert_eq!(tree.get_edges().len(), 8);
+        }
+    }
+
+    mod centrality {
+        use super::*;
+        use hodos::algorithm::{
+            PageRank, PathMetric, betweenness_centrality, closeness_centrality,
+        };
+
+        #[test]
+        fn ranks_hub_of_sampled_network() {
+            // Hub 0 links four spokes in both directions, spokes 1 and 2 are also linked
+            let mut roads = vec![vec![None; 5]; 5];
+            for (a, b) in [(0, 1), (0, 2), (0, 3), (0, 4), (1, 2)] {
+                roads[a][b] = Some(1.0);
+                roads[b][a] = Some(1.0);
+            }
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&roads);
+
+            let pagerank = PageRank::default().compute(&graph);
+            let betweenness = betweenness_centrality(&graph, PathMetric::Weighted);
+            let closeness = closeness_centrality(&graph, PathMetric::Hops);
+
+            for scores in [pagerank, betweenness, closeness] {
+                let best = scores
+                    .iter()
+                    .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+                    .map(|(&id, _)| id);
+                assert_eq!(best, Some(0));
+            }
+        }
+    }
+
+    mod k_shortest {
+        use super::*;
+        use hodos::algorithm::{AlternativeRoutes, k_shortest_paths};
+
+        fn city() -> Vec<Vec<Option<f64>>> {
+            // Two-way streets of a small district, 0 is home and 5 the office
+            let mut streets = vec![vec![None; 6]; 6];
+            for (a, b, minutes) in [
+                (0, 1, 4.0),
+                (0, 2, 3.0),
+                (1, 3, 3.0),
+                (2, 3, 5.0),
+                (2, 4, 4.0),
+                (3, 5, 2.0),
+                (4, 5, 3.0),
+            ] {
+                streets[a][b] = Some(minutes);
+                streets[b][a] = Some(minutes);
+            }
+            streets
+        }
+
+        #[test]
+        fn ranks_three_routes_to_the_office() {
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&city());
+
+            let paths = k_shortest_paths(&graph, 0, 5, 3);
+            let costs: Vec<f64> = paths.iter().map(|p| p.cost).collect();
+            assert_eq!(costs, vec![9.0, 10.0, 10.0]);
+            assert_eq!(paths[0].nodes, vec![0, 1, 3, 5]);
+
+            let routes = AlternativeRoutes::default().compute(&graph, 0, 5);
+            assert_eq!(routes[0], paths[0]);
+            assert!(routes.len() > 1);
+            assert!(routes.iter().all(|route| route.cost <= 9.0 * 1.4));
+        }
+    }
+
+    mod route_inspection {
+        use super::*;
+        use hodos::algorithm::{
+            Orientation, chinese_postman, eulerian_circuit, floyd_warshall, nearest_neighbor_tour,
+            two_opt,
+        };
+
+        fn streets() -> Vec<Vec<Option<f64>>> {
+            // Two-way streets: a block 0-1-2-3 and a dead end 3-4
+            let mut streets = vec![vec![None; 5]; 5];
+            for (a, b, length) in [
+                (0, 1, 2.0),
+                (1, 2, 2.0),
+                (2, 3, 2.0),
+                (3, 0, 2.0),
+                (3, 4, 1.0),
+            ] {
+                streets[a][b] = Some(length);
+                streets[b][a] = Some(length);
+            }
+            streets
+        }
+
+        #[test]
+        fn sweeps_every_street() {
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&streets());
+
+            // The dead end has an odd degree, so no circuit covers streets once
+            assert!(eulerian_circuit(&graph, Orientation::Undirected).is_err());
+            // Both directions form a directed circuit
+            assert!(eulerian_circuit(&graph, Orientation::Directed).is_ok());
+
+            let tour = chinese_postman(&graph).unwrap();
+            assert_eq!(tour.cost, 10.0);
+            assert_eq!(tour.repeated, vec![(3, 4, 1.0)]);
+            assert_eq!(tour.walk.first(), tour.walk.last());
+
+            let matrix = floyd_warshall(&graph).unwrap();
+            let greedy = nearest_neighbor_tour(&matrix, 0).unwrap();
+            let improved = two_opt(&matrix, &greedy);
+            assert!(improved.cost <= greedy.cost);
+            assert_eq!(improved.nodes.len(), 6);
+        }
+    }
+
+    mod coloring {
+        use super::*;
+        use hodos::algorithm::{ColoringOrder, greedy_coloring, maximal_cliques};
+        use hodos::graph::Node;
+        use hodos::preset::samplers::{AdjacencyListWithData, AdjacencyWithDataSampler};
+
+        #[test]
+        fn schedules_conflicting_exams_in_slots() {
+            // Exams sharing a student conflict; node data holds the time slot
+            let conflicts = AdjacencyListWithData {
+                data: vec![usize::MAX; 5],
+                adjacency: vec![vec![1, 2], vec![0, 2, 3], vec![0, 1], vec![1, 4], vec![3]],
+            };
+            let mut graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                AdjacencyWithDataSampler::<usize>::new(),
+            )
+            .build(&conflicts);
+
+            let coloring = greedy_coloring(&graph, ColoringOrder::Dsatur);
+            coloring.apply(&mut graph);
+
+            // Exams 0, 1 and 2 all conflict, so three slots are needed
+            assert_eq!(maximal_cliques(&graph)[0], vec![0, 1, 2]);
+            assert_eq!(coloring.count(), 3);
+            for (id, neighbors) in conflicts.adjacency.iter().enumerate() {
+                let slot = graph.nodes[&(id as u32)].data();
+                for &other in neighbors {
+                    assert_ne!(slot, graph.nodes[&other].data());
+                }
+            }
+        }
+    }
+
+    mod reachability {
+        use super::*;
+        use hodos::algorithm::{ReachabilityIndex, transitive_reduction};
+        use hodos::graph::Edge;
+        use hodos::preset::samplers::SimpleAdjacencySampler;
+
+        #[test]
+        fn answers_dependency_queries_without_traversal() {
+            // Build steps: 0 -> 1 -> 3, 0 -> 2 -> 3, 3 -> 4, plus the shortcut 0 -> 4
+            let steps = vec![vec![1, 2, 4], vec![3], vec![3], vec![4], vec![]];
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                SimpleAdjacencySampler::new(),
+            )
+            .build(&steps);
+
+            let index = ReachabilityIndex::new(&graph);
+            assert!(index.can_reach(0, 4));
+            assert!(index.can_reach(2, 4));
+            assert!(!index.can_reach(1, 2));
+            assert_eq!(index.reachable_from(1), vec![1, 3, 4]);
+
+            let reduction = transitive_reduction(&graph);
+            assert!(reduction.edges[&0].iter().all(|e| e.to() != 4));
+            let reduced = ReachabilityIndex::new(&reduction);
+            for from in 0..5 {
+                assert_eq!(reduced.reachable_from(from), index.reachable_from(from));
+            }
+        }
+    }
+
+    mod landmarks {
+        use super::*;
+        use hodos::algorithm::{LandmarkSelection, LandmarkSelector, Landmarks};
+        use hodos::frontier::MinHeap;
+        use hodos::preset::policies::traversal::GoalReached;
+        use hodos::preset::visitors::{HeuristicVisitor, TrackCost, WeightedVisitor};
+
+        #[test]
+        fn stored_tables_drive_astar_on_a_road_network() {
+            // Ring road 0..8 with a slow shortcut through the center 8
+            let mut matrix = vec![vec![None; 9]; 9];
+            for id in 0..8 {
+                matrix[id][(id + 1) % 8] = Some(2.0);
+                matrix[(id + 1) % 8][id] = Some(2.0);
+                matrix[id][8] = Some(5.0);
+                matrix[8][id] = Some(5.0);
+            }
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&matrix);
+
+            let selected = LandmarkSelector::default()
+                .count(3)
+                .strategy(LandmarkSelection::Avoid)
+                .seed(7)
+                .select(&graph);
+            let landmarks: Landmarks = selected.to_string().parse().unwrap();
+            assert_eq!(landmarks, selected);
+
+            for target in 1..8 {
+                let mut dijkstra = WeightedVisitor::new(GoalReached::new(target));
+                graph.traverse(0, &mut MinHeap::new(), &mut dijkstra);
+
+                let mut alt = HeuristicVisitor::new(
+                    WeightedVisitor::new(GoalReached::new(target)),
+                    landmarks.towards(target),
+                );
+                graph.traverse(0, &mut MinHeap::new(), &mut alt);
+
+                assert_eq!(alt.cost_to(target), dijkstra.cost_to(target));
+                assert!(landmarks.lower_bound(0, target) <= dijkstra.cost_to(target).unwrap());
+            }
+        }
+    }
+
+    mod contraction {
+        use super::*;
+        use hodos::algorithm::{HierarchyBuilder, floyd_warshall};
+        use hodos::graph::Edge;
+
+        #[test]
+        fn hierarchy_answers_every_query_like_floyd_warshall() {
+            // Ring of 12 junctions with two highways across
+            let mut matrix = vec![vec![None; 12]; 12];
+            for id in 0..12 {
+                matrix[id][(id + 1) % 12] = Some(1.0 + (id % 3) as f64);
+                matrix[(id + 1) % 12][id] = Some(1.0 + (id % 3) as f64);
+            }
+            matrix[0][6] = Some(4.0);
+            matrix[6][0] = Some(4.0);
+            matrix[3][9] = Some(4.5);
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&matrix);
+
+            let hierarchy = HierarchyBuilder::default().build(&graph);
+            let distances = floyd_warshall(&graph).unwrap();
+
+            for from in 0..12 {
+                for to in 0..12 {
+                    assert_eq!(hierarchy.distance(from, to), distances.distance(from, to));
+                    let edges = hierarchy.shortest_path_edges(from, to).unwrap();
+                    let total: f64 = edges.iter().map(|edge| edge.weight()).sum();
+                    assert_eq!(Some(total), distances.distance(from, to));
+                    assert!(edges.windows(2).all(|pair| pair[0].to() == pair[1].from()));
+                }
+            }
+        }
+    }
+
+    mod jump_point {
+        use super::*;
+        use hodos::algorithm::jump_point_search;
+        use hodos::frontier::{Frontier, Instrumented, MinHeap};
+        use hodos::preset::policies::traversal::GoalReached;
+        use hodos::preset::policies::value::DenyNodeValue;
+        use hodos::preset::samplers::{Grid2D, Grid2DSampler};
+        use hodos::preset::visitors::{TrackCost, WeightedVisitor};
+
+        #[test]
+        fn expands_far_fewer_nodes_than_dijkstra_on_open_map() {
+            // Open 40x40 map with a single wall to walk around
+            let mut terrain: Grid2D<char> = vec![vec![' '; 40]; 40];
+            for row in terrain.iter_mut().take(30).skip(5) {
+                row[20] = '#';
+            }
+            let (start, goal) = (15 * 40 + 2, 20 * 40 + 37);
+
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                DenyNodeValue::with_denied_values(vec!['#']),
+                Grid2DSampler::with_connect_eight(),
+            )
+            .build(&terrain);
+            let mut visitor = WeightedVisitor::new(GoalReached::new(goal));
+            let mut frontier = Instrumented::<MinHeap>::new();
+            graph.traverse(start, &mut frontier, &mut visitor);
+
+            let found = jump_point_search(&terrain, |&cell| cell != '#', start, goal).unwrap();
+
+            assert_eq!(Some(found.path.cost), visitor.cost_to(goal));
+            assert!(found.expanded * 10 < frontier.stats().pops);
+        }
+    }
+
+    mod incremental {
+        use super::*;
+        use hodos::algorithm::{DStarLite, LpaStar, WeightChange};
+        use hodos::frontier::MinHeap;
+        use hodos::preset::policies::traversal::GoalReached;
+        use hodos::preset::visitors::{TrackCost, WeightedVisitor};
+
+        /// Two-way roads between 6 junctions.
+        fn roads() -> Vec<Vec<Option<f64>>> {
+            let mut matrix = vec![vec![None; 6]; 6];
+            for (a, b, weight) in [
+                (0, 1, 2.0),
+                (1, 2, 2.0),
+                (2, 5, 2.0),
+                (0, 3, 3.0),
+                (3, 4, 3.0),
+                (4, 5, 3.0),
+                (1, 4, 2.0),
+            ] {
+                matrix[a][b] = Some(weight);
+                matrix[b][a] = Some(weight);
+            }
+            matrix
+        }
+
+        #[test]
+        fn planners_agree_with_a_fresh_search_after_road_closures() {
+            let mut graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&roads());
+
+            let mut lpa = LpaStar::new(&graph, 0, 5, |_| 0.0).unwrap();
+            let mut robot = DStarLite::new(&graph, 0, 5, |_, _| 0.0).unwrap();
+            assert_eq!(lpa.path().unwrap().nodes, vec![0, 1, 2, 5]);
+            assert_eq!(robot.move_to(1).unwrap().nodes, vec![1, 2, 5]);
+
+            let closures = [
+                WeightChange::new(1, 2, f64::INFINITY),
+                WeightChange::new(2, 1, f64::INFINITY),
+            ];
+            let path = lpa.update(&mut graph, &closures).unwrap();
+            let detour = robot.update(&mut graph, &closures).unwrap();
+
+            let mut visitor = WeightedVisitor::new(GoalReached::new(5));
+            graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+            assert_eq!(path.nodes, vec![0, 1, 4, 5]);
+            assert_eq!(Some(path.cost), visitor.cost_to(5));
+            assert_eq!(detour.nodes, vec![1, 4, 5]);
+            assert_eq!(detour.cost, 5.0);
+        }
+    }
+
+    mod any_angle {
+        use super::*;
+        use hodos::algorithm::{lazy_theta_star, theta_star};
+        use hodos::frontier::MinHeap;
+        use hodos::preset::policies::traversal::GoalReached;
+        use hodos::preset::policies::value::DenyNodeValue;
+        use hodos::preset::samplers::{Grid2D, Grid2DSampler};
+        use hodos::preset::visitors::{TrackParent, WeightedVisitor};
+
+        fn length(path: &[u32], width: u32) -> f64 {
+            path.windows(2)
+                .map(|pair| {
+                    let (a, b) = (pair[0], pair[1]);
+                    let dr = (a / width) as f64 - (b / width) as f64;
+                    let dc = (a % width) as f64 - (b % width) as f64;
+                    dr.hypot(dc)
+                })
+                .sum()
+        }
+
+        #[test]
+        fn shortens_paths_of_the_sampled_graph() {
+            let mut terrain: Grid2D<char> = vec![vec!['.'; 30]; 20];
+            for row in terrain.iter_mut().take(16) {
+                row[12] = '#';
+            }
+            let (start, goal) = (2 * 30 + 3, 5 * 30 + 27);
+            let policy = DenyNodeValue::with_denied_values(vec!['#']);
+
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                DenyNodeValue::with_denied_values(vec!['#']),
+                Grid2DSampler::with_connect_eight(),
+            )
+            .build(&terrain);
+            let mut visitor = WeightedVisitor::new(GoalReached::new(goal));
+            graph.traverse(start, &mut MinHeap::new(), &mut visitor);
+            let mut zigzag = vec![goal];
+            while let Some(parent) = visitor.get_parent(zigzag[zigzag.len() - 1]) {
+                zigzag.push(parent);
+            }
+
+            let eager = theta_star(&terrain, &policy, start, goal).unwrap();
+            let lazy = lazy_theta_star(&terrain, &policy, start, goal).unwrap();
+
+            assert!(eager.path.cost < length(&zigzag, 30));
+            assert!((eager.path.cost - lazy.path.cost).abs() < 1e-9);
+            assert!(
+                eager
+                    .path
+                    .nodes
+                    .iter()
+                    .all(|id| graph.nodes.contains_key(id))
+            );
+            assert!(eager.path.nodes.len() <= 4);
+        }
+    }
+
+    mod hierarchical {
+        use super::*;
+        use hodos::algorithm::HierarchicalGrid;
+        use hodos::frontier::MinHeap;
+        use hodos::graph::{Edge, Graph};
+        use hodos::preset::policies::traversal::GoalReached;
+        use hodos::preset::policies::value::DenyNodeValue;
+        use hodos::preset::samplers::{Grid2D, Grid2DSampler};
+        use hodos::preset::visitors::{TrackCost, WeightedVisitor};
+        use hodos::preset::{DataNode, UnweightedEdge};
+
+        fn sample(terrain: &Grid2D<char>) -> Graph<DataNode<char>, UnweightedEdge> {
+            GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                DenyNodeValue::with_denied_values(vec!['#']),
+                Grid2DSampler::with_connect_eight(),
+            )
+            .build(terrain)
+        }
+
+        #[test]
+        fn replans_after_a_cluster_changes() {
+            let mut terrain: Grid2D<char> = vec![vec!['.'; 48]; 48];
+            let graph = sample(&terrain);
+            let mut hierarchy = HierarchicalGrid::new(&terrain, &graph, 12);
+            let (start, goal) = (5 * 48 + 5, 42 * 48 + 42);
+
+            let before = hierarchy.find_path(&graph, start, goal).unwrap();
+            assert_eq!(before.path.cost, 37.0);
+
+            // A wall appears in the cluster of the middle of the map
+            for cell in terrain[24].iter_mut().take(30).skip(14) {
+                *cell = '#';
+            }
+            let graph = sample(&terrain);
+            let untouched: Vec<Vec<u32>> = (0..4).map(|c| hierarchy.transitions(c)).collect();
+            hierarchy.invalidate(&graph, hierarchy.cluster_of(24 * 48 + 20).unwrap());
+
+            let after = hierarchy.find_path(&graph, start, goal).unwrap();
+            for pair in after.path.nodes.windows(2) {
+                assert!(graph.edges[&pair[0]].iter().any(|e| e.to() == pair[1]));
+            }
+            let mut visitor = WeightedVisitor::new(GoalReached::new(goal));
+            graph.traverse(start, &mut MinHeap::new(), &mut visitor);
+            assert!(after.path.cost >= visitor.cost_to(goal).unwrap());
+
+            // Clusters of the first row are not adjacent to the changed one
+            for (cluster, transitions) in untouched.iter().enumerate() {
+                assert_eq!(&hierarchy.transitions(cluster), transitions);
+            }
+        }
+    }
+}
@@ -220,7 +220,7 @@ mod end_to_end {
         }
 
         #[test]
-        pub fn solves_simple_lightest_path() -> () {
+        pub fn solves_simple_lightest_path() {
             // Graph Representation
             //
             //    1.0   2.0    3.0
@@ -256,5 +256,275 @@ mod end_to_end {
 
             assert_eq!(visitor.get_parent(4), Some(3));
         }
+
+        #[test]
+        fn solves_with_integer_costs() {
+            use hodos::graph::{Edge, Graph, Node};
+            use hodos::preset::policies::traversal::NoTermination;
+            use hodos::preset::{EmptyNode, WeightedEdge};
+
+            let mut graph = Graph::<EmptyNode, WeightedEdge<u64>>::new();
+            for id in 0..3 {
+                graph.add_node(EmptyNode::new(id, None));
+            }
+            graph.add_edge(WeightedEdge::new(0, 1, Some(2)));
+            graph.add_edge(WeightedEdge::new(1, 2, Some(2)));
+            graph.add_edge(WeightedEdge::new(0, 2, Some(5)));
+
+            let mut visitor = WeightedVisitor::new(NoTermination);
+            graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+            assert_eq!(visitor.cost_to(2), Some(4));
+            assert_eq!(visitor.get_parent(2), Some(1));
+        }
+
+        #[test]
+        fn solves_with_lexicographic_costs() {
+            use hodos::graph::{Edge, Graph, Node};
+            use hodos::preset::policies::traversal::NoTermination;
+            use hodos::preset::{EmptyNode, WeightedEdge};
+
+            // Costs are (tolls, distance): avoiding tolls comes first.
+            let mut graph = Graph::<EmptyNode, WeightedEdge<(u64, f64)>>::new();
+            for id in 0..3 {
+                graph.add_node(EmptyNode::new(id, None));
+            }
+            graph.add_edge(WeightedEdge::new(0, 2, Some((1, 1.0))));
+            graph.add_edge(WeightedEdge::new(0, 1, Some((0, 5.0))));
+            graph.add_edge(WeightedEdge::new(1, 2, Some((0, 5.0))));
+
+            let mut visitor = WeightedVisitor::new(NoTermination);
+            graph.traverse(0, &mut MinHeap::default(), &mut visitor);
+
+            assert_eq!(visitor.cost_to(2), Some((0, 10.0)));
+            assert_eq!(visitor.get_parent(2), Some(1));
+        }
+
+        #[test]
+        fn enumerates_pareto_optimal_routes() {
+            use hodos::graph::{Edge, Graph, Node};
+            use hodos::preset::policies::traversal::NoTermination;
+            use hodos::preset::{EmptyNode, MultiWeightedEdge};
+
+            // Costs are [distance, toll]: a short toll road, a long free road
+            // and a dominated detour.
+            let mut graph = Graph::<EmptyNode, MultiWeightedEdge<2>>::new();
+            for id in 0..5 {
+                graph.add_node(EmptyNode::new(id, None));
+            }
+            graph.add_edge(MultiWeightedEdge::new(0, 1, Some([2.0, 4.0])));
+            graph.add_edge(MultiWeightedEdge::new(1, 4, Some([2.0, 4.0])));
+            graph.add_edge(MultiWeightedEdge::new(0, 2, Some([5.0, 0.0])));
+            graph.add_edge(MultiWeightedEdge::new(2, 4, Some([5.0, 0.0])));
+            graph.add_edge(MultiWeightedEdge::new(0, 3, Some([6.0, 1.0])));
+            graph.add_edge(MultiWeightedEdge::new(3, 4, Some([6.0, 1.0])));
+            graph.add_edge(MultiWeightedEdge::new(1, 2, Some([1.0, 0.0])));
+
+            let mut visitor = ParetoVisitor::new(NoTermination);
+            graph.traverse(0, &mut MinHeap::<[f64; 2]>::default(), &mut visitor);
+
+            let routes = visitor.pareto_paths(4);
+            assert_eq!(routes.len(), 3);
+            assert_eq!(routes[0].nodes, vec![0, 1, 4]);
+            assert_eq!(routes[0].cost, [4.0, 8.0]);
+            assert_eq!(routes[1].nodes, vec![0, 1, 2, 4]);
+            assert_eq!(routes[1].cost, [8.0, 4.0]);
+            assert_eq!(routes[2].nodes, vec![0, 2, 4]);
+            assert_eq!(routes[2].cost, [10.0, 0.0]);
+        }
+
+        #[test]
+        fn solves_under_composite_resource_limits() {
+            use hodos::graph::{Graph, Node};
+            use hodos::policy::Composite;
+            use hodos::preset::policies::value::ResourceLimit;
+            use hodos::preset::{EmptyNode, ResourceEdge};
+
+            // Resources are [fuel, transfers]
+            let mut graph = Graph::<EmptyNode, ResourceEdge<2>>::new();
+            for id in 0..4 {
+                graph.add_node(EmptyNode::new(id, None));
+            }
+            graph.add_edge(ResourceEdge::with_consumption(0, 3, 2.0, [9.0, 1.0]));
+            graph.add_edge(ResourceEdge::with_consumption(0, 1, 2.0, [1.0, 1.0]));
+            graph.add_edge(ResourceEdge::with_consumption(1, 2, 2.0, [1.0, 1.0]));
+            graph.add_edge(ResourceEdge::with_consumption(2, 3, 2.0, [1.0, 1.0]));
+            graph.add_edge(ResourceEdge::with_consumption(1, 3, 5.0, [2.0, 1.0]));
+
+            let limits = Composite::And(ResourceLimit::new(0, 5.0), ResourceLimit::new(1, 2.0));
+            let mut visitor = ResourceConstrainedVisitor::new(GoalReached::new(3), limits);
+            graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+            assert_eq!(visitor.cost_to(3), Some(7.0));
+            assert_eq!(visitor.best_path(3), Some(vec![0, 1, 3]));
+        }
+
+        #[test]
+        fn instrumented_frontier_reports_search_metrics() {
+            use hodos::frontier::Instrumented;
+
+            let context = vec![
+                vec![None, Some(1.0), Some(4.0)],
+                vec![None, None, Some(1.0)],
+                vec![None, None, None],
+            ];
+            let mut visitor = WeightedVisitor::new(GoalReached::new(2));
+            let mut frontier = Instrumented::<MinHeap>::new();
+
+            GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&context)
+            .traverse(0, &mut frontier, &mut visitor);
+
+            let stats = frontier.stats();
+            assert_eq!(stats.pushes, 4); // start, 1, 2 (cost 4.0), 2 (cost 2.0)
+            assert_eq!(stats.duplicate_pushes, 1);
+            assert_eq!(stats.pops, 3);
+            assert_eq!(stats.peak_size, 2);
+            assert_eq!(stats.popped_costs, vec![0.0, 1.0, 2.0]);
+        }
+    }
+
+    mod bellman_ford {
+        use super::*;
+        use hodos::policy::Composite;
+        use hodos::preset::policies::structural::DenyDanglingEdge;
+        use hodos::preset::policies::traversal::NoTermination;
+        use hodos::preset::policies::value::{AllowAll, DenyNegativeWeight};
+        use hodos::preset::samplers::WeightedMatrixSampler;
+
+        #[test]
+        fn solves_with_negative_weights() {
+            let context = vec![
+                vec![None, Some(4.0), Some(1.0), None],
+                vec![None, None, None, Some(1.0)],
+                vec![None, Some(-2.0), None, Some(5.0)],
+                vec![None, None, None, None],
+            ];
+            let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+            GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&context)
+            .traverse(0, &mut Queue::new(), &mut visitor);
+
+            assert!(visitor.result().is_ok());
+            assert_eq!(visitor.cost_to(3), Some(0.0));
+            assert_eq!(visitor.get_parent(3), Some(1));
+            assert_eq!(visitor.get_parent(1), Some(2));
+        }
+
+        #[test]
+        fn reports_reachable_negative_cycle() {
+            let context = vec![
+                vec![None, Some(1.0), None],
+                vec![None, None, Some(-3.0)],
+                vec![None, Some(1.0), None],
+            ];
+            let mut visitor = BellmanFordVisitor::new(NoTermination);
+
+            GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&context)
+            .traverse(0, &mut Queue::new(), &mut visitor);
+
+            let cycle = visitor.result().unwrap_err().cycle;
+            assert_eq!(cycle.len(), 2);
+            assert!(cycle.contains(&1) && cycle.contains(&2));
+        }
+
+        #[test]
+        fn guard_policy_drops_negative_edges() {
+            let context = vec![vec![None, Some(-1.0)], vec![Some(2.0), None]];
+
+            let graph = GraphBuilder::new(
+                Composite::And(DenyDanglingEdge::default(), DenyNegativeWeight::default()),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&context);
+
+            assert!(graph.edges.get(&0).is_none_or(|edges| edges.is_empty()));
+            assert_eq!(graph.edges[&1].len(), 1);
+        }
+
+        #[test]
+        fn guard_check_rejects_negative_graphs() {
+            let context = vec![vec![None, Some(-1.0)], vec![Some(2.0), None]];
+
+            let graph = GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                WeightedMatrixSampler::new(),
+            )
+            .build(&context);
+
+            let error = DenyNegativeWeight::check(&graph).unwrap_err();
+            assert_eq!(error.edges, vec![(0, 1)]);
+        }
+    }
+
+    mod randomized {
+        use super::*;
+        use hodos::frontier::{RandomFrontier, SoftmaxHeap};
+        use hodos::preset::policies::structural::DenyDanglingEdge;
+        use hodos::preset::policies::traversal::NoTermination;
+        use hodos::preset::policies::value::AllowAll;
+        use hodos::preset::samplers::SimpleAdjacencySampler;
+
+        fn run(frontier: &mut dyn Frontier) -> SimpleVisitor<NoTermination> {
+            let context = vec![
+                vec![1, 2, 3],
+                vec![0, 4],
+                vec![0, 4],
+                vec![0, 4],
+                vec![1, 2, 3],
+            ];
+            let mut visitor = SimpleVisitor::new(NoTermination);
+            GraphBuilder::new(
+                DenyDanglingEdge::default(),
+                AllowAll::default(),
+                SimpleAdjacencySampler::new(),
+            )
+            .build(&context)
+            .traverse(0, frontier, &mut visitor);
+
+            visitor
+        }
+
+        #[test]
+        fn random_frontier_reaches_every_node() {
+            let visitor = run(&mut RandomFrontier::with_seed(3));
+
+            assert_eq!(visitor.visited_count(), 5);
+        }
+
+        #[test]
+        fn same_seed_reproduces_exploration_tree() {
+            for seed in 0..10 {
+                let first = run(&mut RandomFrontier::with_seed(seed));
+                let second = run(&mut RandomFrontier::with_seed(seed));
+
+                for node in 0..5 {
+                    assert_eq!(first.get_parent(node), second.get_parent(node));
+                }
+            }
+        }
+
+        #[test]
+        fn softmax_heap_reaches_every_node() {
+            let visitor = run(&mut SoftmaxHeap::with_temperature(1.0, 3));
+
+            assert_eq!(visitor.visited_count(), 5);
+        }
     }
 }
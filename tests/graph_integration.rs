@@ -158,4 +158,62 @@ mod graph_integration {
             self.count == 3
         }
     }
+
+    mod implicit_graph {
+        use hodos::frontier::MinHeap;
+        use hodos::graph::{Edge, Graph, ImplicitGraph, Node};
+        use hodos::preset::policies::traversal::{GoalReached, NoTermination};
+        use hodos::preset::policies::value::ResourceLimit;
+        use hodos::preset::visitors::{ResourceConstrainedVisitor, TrackCost, WeightedVisitor};
+        use hodos::preset::{EmptyNode, ResourceEdge, WeightedEdge};
+
+        /// Ring of 8 nodes where moving forward costs 1 and backward costs 2.
+        fn ring(n: u32) -> Vec<WeightedEdge> {
+            vec![
+                WeightedEdge::new(n, (n + 1) % 8, Some(1.0)),
+                WeightedEdge::new(n, (n + 7) % 8, Some(2.0)),
+            ]
+        }
+
+        #[test]
+        fn matches_materialized_graph() {
+            let mut graph: Graph<EmptyNode, WeightedEdge> = Graph::default();
+            for n in 0..8 {
+                graph.add_node(EmptyNode::new(n, None));
+                for edge in ring(n) {
+                    graph.add_edge(edge);
+                }
+            }
+            let implicit = ImplicitGraph::memoized(ring);
+
+            let mut expected = WeightedVisitor::new(NoTermination);
+            graph.traverse(0, &mut MinHeap::new(), &mut expected);
+            let mut visitor = WeightedVisitor::new(NoTermination);
+            implicit.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+            for n in 0..8 {
+                assert_eq!(visitor.cost_to(n), expected.cost_to(n));
+            }
+            assert_eq!(visitor.cost_to(6), Some(4.0));
+            assert_eq!(implicit.cached_count(), 8);
+        }
+
+        #[test]
+        fn resource_limits_apply_to_generated_edges() {
+            // Forward moves are cheap but burn fuel, backward moves are free of fuel
+            let graph = ImplicitGraph::new(|n: u32| {
+                vec![
+                    ResourceEdge::with_consumption(n, (n + 1) % 8, 1.0, [1.0]),
+                    ResourceEdge::with_consumption(n, (n + 7) % 8, 2.0, [0.0]),
+                ]
+            });
+
+            let mut visitor =
+                ResourceConstrainedVisitor::new(GoalReached::new(3), ResourceLimit::new(0, 2.0));
+            graph.traverse(0, &mut MinHeap::new(), &mut visitor);
+
+            // Only two forward moves are allowed, so node 3 is reached backwards
+            assert_eq!(visitor.cost_to(3), Some(10.0));
+        }
+    }
 }